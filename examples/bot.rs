@@ -6,7 +6,7 @@ use telegram::api::types::chat_id::ChatId;
 use telegram::client::Client;
 
 fn main() -> Result<(), Box<dyn Error>> {
-    let api = Client::new();
+    let api = Client::from_args()?;
 
     let params = SendMessage {
         chat_id: ChatUId::I64(ChatId(147951145)),
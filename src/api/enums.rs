@@ -9,7 +9,10 @@ pub mod input_message_content;
 pub mod maybe_inaccessible_message;
 pub mod media_input;
 pub mod menu_button;
+pub mod message_entity_kind;
+pub mod message_or_bool;
 pub mod message_origin;
+pub mod parse_mode;
 pub mod passport_element_error;
 pub mod reaction_type;
 pub mod reply_markup;
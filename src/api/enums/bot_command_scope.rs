@@ -5,7 +5,9 @@ use crate::api::types::bot_command_scope_chat::BotCommandScopeChat;
 use crate::api::types::bot_command_scope_chat_administrators::BotCommandScopeChatAdministrators;
 use crate::api::types::bot_command_scope_chat_member::BotCommandScopeChatMember;
 use crate::api::types::bot_command_scope_default::BotCommandScopeDefault;
-use serde::{Deserialize, Serialize};
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
 
 /// https://core.telegram.org/bots/api#botcommandscope
 /// This object represents the scope to which bot commands are applied. Currently, the following 7 scopes are supported:
@@ -16,7 +18,8 @@ use serde::{Deserialize, Serialize};
 /// BotCommandScopeChat
 /// BotCommandScopeChatAdministrators
 /// BotCommandScopeChatMember
-#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(untagged)]
 pub enum BotCommandScope {
     BotCommandScopeDefault(BotCommandScopeDefault),
     BotCommandScopeAllPrivateChats(BotCommandScopeAllPrivateChats),
@@ -25,4 +28,34 @@ pub enum BotCommandScope {
     BotCommandScopeChat(BotCommandScopeChat),
     BotCommandScopeChatAdministrators(BotCommandScopeChatAdministrators),
     BotCommandScopeChatMember(BotCommandScopeChatMember),
+    /// Unknown to this version of the crate, e.g. added by a newer Bot API.
+    Unknown(Value),
+}
+
+impl<'de> Deserialize<'de> for BotCommandScope {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = Value::deserialize(deserializer)?;
+
+        match value.get("type").and_then(Value::as_str) {
+            Some("default") => serde_json::from_value(value).map(Self::BotCommandScopeDefault),
+            Some("all_private_chats") => {
+                serde_json::from_value(value).map(Self::BotCommandScopeAllPrivateChats)
+            }
+            Some("all_group_chats") => {
+                serde_json::from_value(value).map(Self::BotCommandScopeAllGroupChats)
+            }
+            Some("all_chat_administrators") => {
+                serde_json::from_value(value).map(Self::BotCommandScopeAllChatAdministrators)
+            }
+            Some("chat") => serde_json::from_value(value).map(Self::BotCommandScopeChat),
+            Some("chat_administrators") => {
+                serde_json::from_value(value).map(Self::BotCommandScopeChatAdministrators)
+            }
+            Some("chat_member") => {
+                serde_json::from_value(value).map(Self::BotCommandScopeChatMember)
+            }
+            _ => Ok(Self::Unknown(value)),
+        }
+        .map_err(D::Error::custom)
+    }
 }
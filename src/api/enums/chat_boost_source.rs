@@ -1,16 +1,35 @@
 use crate::api::types::chat_boost_source_gift_code::ChatBoostSourceGiftCode;
 use crate::api::types::chat_boost_source_giveaway::ChatBoostSourceGiveaway;
 use crate::api::types::chat_boost_source_premium::ChatBoostSourcePremium;
-use serde::{Deserialize, Serialize};
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
 
 /// https://core.telegram.org/bots/api#chatboostsource
 /// This object describes the source of a chat boost. It can be one of
 /// ChatBoostSourcePremium
 /// ChatBoostSourceGiftCode
 /// ChatBoostSourceGiveaway
-#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(untagged)]
 pub enum ChatBoostSource {
     ChatBoostSourcePremium(ChatBoostSourcePremium),
     ChatBoostSourceGiftCode(ChatBoostSourceGiftCode),
     ChatBoostSourceGiveaway(ChatBoostSourceGiveaway),
+    /// Unknown to this version of the crate, e.g. added by a newer Bot API.
+    Unknown(Value),
+}
+
+impl<'de> Deserialize<'de> for ChatBoostSource {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = Value::deserialize(deserializer)?;
+
+        match value.get("source").and_then(Value::as_str) {
+            Some("premium") => serde_json::from_value(value).map(Self::ChatBoostSourcePremium),
+            Some("gift_code") => serde_json::from_value(value).map(Self::ChatBoostSourceGiftCode),
+            Some("giveaway") => serde_json::from_value(value).map(Self::ChatBoostSourceGiveaway),
+            _ => Ok(Self::Unknown(value)),
+        }
+        .map_err(D::Error::custom)
+    }
 }
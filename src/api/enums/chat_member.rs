@@ -4,7 +4,9 @@ use crate::api::types::chat_member_left::ChatMemberLeft;
 use crate::api::types::chat_member_member::ChatMemberMember;
 use crate::api::types::chat_member_owner::ChatMemberOwner;
 use crate::api::types::chat_member_restricted::ChatMemberRestricted;
-use serde::{Deserialize, Serialize};
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
 
 /// https://core.telegram.org/bots/api#chatmember
 /// This object contains information about one member of a chat. Currently, the following 6 types of chat members are supported:
@@ -14,7 +16,8 @@ use serde::{Deserialize, Serialize};
 /// ChatMemberRestricted
 /// ChatMemberLeft
 /// ChatMemberBanned
-#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(untagged)]
 pub enum ChatMember {
     ChatMemberOwner(ChatMemberOwner),
     ChatMemberAdministrator(ChatMemberAdministrator),
@@ -22,4 +25,25 @@ pub enum ChatMember {
     ChatMemberRestricted(ChatMemberRestricted),
     ChatMemberLeft(ChatMemberLeft),
     ChatMemberBanned(ChatMemberBanned),
+    /// Unknown to this version of the crate, e.g. added by a newer Bot API.
+    Unknown(Value),
+}
+
+impl<'de> Deserialize<'de> for ChatMember {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = Value::deserialize(deserializer)?;
+
+        match value.get("status").and_then(Value::as_str) {
+            Some("creator") => serde_json::from_value(value).map(Self::ChatMemberOwner),
+            Some("administrator") => {
+                serde_json::from_value(value).map(Self::ChatMemberAdministrator)
+            }
+            Some("member") => serde_json::from_value(value).map(Self::ChatMemberMember),
+            Some("restricted") => serde_json::from_value(value).map(Self::ChatMemberRestricted),
+            Some("left") => serde_json::from_value(value).map(Self::ChatMemberLeft),
+            Some("kicked") => serde_json::from_value(value).map(Self::ChatMemberBanned),
+            _ => Ok(Self::Unknown(value)),
+        }
+        .map_err(D::Error::custom)
+    }
 }
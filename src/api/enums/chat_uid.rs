@@ -1,7 +1,7 @@
 use crate::api::types::chat_id::ChatId;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(untagged)]
 pub enum ChatUId {
     I64(ChatId),
@@ -2,6 +2,7 @@ use crate::api::types::input_file::InputFile;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[serde(untagged)]
 pub enum FileInput {
     InputFile(InputFile),
     String(String),
@@ -18,7 +18,9 @@ use crate::api::types::inline_query_result_photo::InlineQueryResultPhoto;
 use crate::api::types::inline_query_result_venue::InlineQueryResultVenue;
 use crate::api::types::inline_query_result_video::InlineQueryResultVideo;
 use crate::api::types::inline_query_result_voice::InlineQueryResultVoice;
-use serde::{Deserialize, Serialize};
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
 
 /// https://core.telegram.org/bots/api#inlinequeryresult
 /// This object represents one result of an inline query. Telegram clients currently support results of the following 20 types:
@@ -42,7 +44,8 @@ use serde::{Deserialize, Serialize};
 /// InlineQueryResultVenue
 /// InlineQueryResultVideo
 /// InlineQueryResultVoice
-#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(untagged)]
 pub enum InlineQueryResult {
     InlineQueryResultCachedAudio(InlineQueryResultCachedAudio),
     InlineQueryResultCachedDocument(InlineQueryResultCachedDocument),
@@ -64,4 +67,78 @@ pub enum InlineQueryResult {
     InlineQueryResultVenue(InlineQueryResultVenue),
     InlineQueryResultVideo(InlineQueryResultVideo),
     InlineQueryResultVoice(InlineQueryResultVoice),
+    /// Unknown to this version of the crate, e.g. added by a newer Bot API.
+    Unknown(Value),
+}
+
+impl<'de> Deserialize<'de> for InlineQueryResult {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = Value::deserialize(deserializer)?;
+        let kind = value.get("type").and_then(Value::as_str);
+        // Cached results share the `type` of their url-based counterparts and differ by the file id field.
+        let cached = match kind {
+            Some("mpeg4_gif") => value.get("mpeg4_file_id").is_some(),
+            Some(kind) => value.get(format!("{}_file_id", kind)).is_some(),
+            None => false,
+        };
+
+        match (kind, cached) {
+            (Some("audio"), true) => {
+                serde_json::from_value(value).map(Self::InlineQueryResultCachedAudio)
+            }
+            (Some("document"), true) => {
+                serde_json::from_value(value).map(Self::InlineQueryResultCachedDocument)
+            }
+            (Some("gif"), true) => {
+                serde_json::from_value(value).map(Self::InlineQueryResultCachedGif)
+            }
+            (Some("mpeg4_gif"), true) => {
+                serde_json::from_value(value).map(Self::InlineQueryResultCachedMpeg4Gif)
+            }
+            (Some("photo"), true) => {
+                serde_json::from_value(value).map(Self::InlineQueryResultCachedPhoto)
+            }
+            (Some("sticker"), _) => {
+                serde_json::from_value(value).map(Self::InlineQueryResultCachedSticker)
+            }
+            (Some("video"), true) => {
+                serde_json::from_value(value).map(Self::InlineQueryResultCachedVideo)
+            }
+            (Some("voice"), true) => {
+                serde_json::from_value(value).map(Self::InlineQueryResultCachedVoice)
+            }
+            (Some("article"), _) => {
+                serde_json::from_value(value).map(Self::InlineQueryResultArticle)
+            }
+            (Some("audio"), false) => {
+                serde_json::from_value(value).map(Self::InlineQueryResultAudio)
+            }
+            (Some("contact"), _) => {
+                serde_json::from_value(value).map(Self::InlineQueryResultContact)
+            }
+            (Some("game"), _) => serde_json::from_value(value).map(Self::InlineQueryResultGame),
+            (Some("document"), false) => {
+                serde_json::from_value(value).map(Self::InlineQueryResultDocument)
+            }
+            (Some("gif"), false) => serde_json::from_value(value).map(Self::InlineQueryResultGif),
+            (Some("location"), _) => {
+                serde_json::from_value(value).map(Self::InlineQueryResultLocation)
+            }
+            (Some("mpeg4_gif"), false) => {
+                serde_json::from_value(value).map(Self::InlineQueryResultMpeg4Gif)
+            }
+            (Some("photo"), false) => {
+                serde_json::from_value(value).map(Self::InlineQueryResultPhoto)
+            }
+            (Some("venue"), _) => serde_json::from_value(value).map(Self::InlineQueryResultVenue),
+            (Some("video"), false) => {
+                serde_json::from_value(value).map(Self::InlineQueryResultVideo)
+            }
+            (Some("voice"), false) => {
+                serde_json::from_value(value).map(Self::InlineQueryResultVoice)
+            }
+            _ => Ok(Self::Unknown(value)),
+        }
+        .map_err(D::Error::custom)
+    }
 }
@@ -3,7 +3,12 @@ use crate::api::types::input_media_audio::InputMediaAudio;
 use crate::api::types::input_media_document::InputMediaDocument;
 use crate::api::types::input_media_photo::InputMediaPhoto;
 use crate::api::types::input_media_video::InputMediaVideo;
-use serde::{Deserialize, Serialize};
+use crate::api::validate;
+use crate::api::validate::Validate;
+use crate::errors;
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
 
 /// https://core.telegram.org/bots/api#inputmedia
 /// This object represents the content of a media message to be sent. It should be one of
@@ -12,11 +17,53 @@ use serde::{Deserialize, Serialize};
 /// InputMediaAudio
 /// InputMediaPhoto
 /// InputMediaVideo
-#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(untagged)]
 pub enum InputMedia {
     InputMediaAnimation(InputMediaAnimation),
     InputMediaDocument(InputMediaDocument),
     InputMediaAudio(InputMediaAudio),
     InputMediaPhoto(InputMediaPhoto),
     InputMediaVideo(InputMediaVideo),
+    /// Unknown to this version of the crate, e.g. added by a newer Bot API.
+    Unknown(Value),
+}
+
+impl<'de> Deserialize<'de> for InputMedia {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = Value::deserialize(deserializer)?;
+
+        match value.get("type").and_then(Value::as_str) {
+            Some("animation") => serde_json::from_value(value).map(Self::InputMediaAnimation),
+            Some("document") => serde_json::from_value(value).map(Self::InputMediaDocument),
+            Some("audio") => serde_json::from_value(value).map(Self::InputMediaAudio),
+            Some("photo") => serde_json::from_value(value).map(Self::InputMediaPhoto),
+            Some("video") => serde_json::from_value(value).map(Self::InputMediaVideo),
+            _ => Ok(Self::Unknown(value)),
+        }
+        .map_err(D::Error::custom)
+    }
+}
+
+impl Validate for InputMedia {
+    fn validate(&self) -> Result<(), errors::Error> {
+        match self {
+            Self::InputMediaAnimation(media) => {
+                validate::caption("caption", &media.caption, media.parse_mode)
+            }
+            Self::InputMediaDocument(media) => {
+                validate::caption("caption", &media.caption, media.parse_mode)
+            }
+            Self::InputMediaAudio(media) => {
+                validate::caption("caption", &media.caption, media.parse_mode)
+            }
+            Self::InputMediaPhoto(media) => {
+                validate::caption("caption", &media.caption, media.parse_mode)
+            }
+            Self::InputMediaVideo(media) => {
+                validate::caption("caption", &media.caption, media.parse_mode)
+            }
+            Self::Unknown(_) => Ok(()),
+        }
+    }
 }
@@ -3,7 +3,9 @@ use crate::api::types::input_invoice_message_content::InputInvoiceMessageContent
 use crate::api::types::input_location_message_content::InputLocationMessageContent;
 use crate::api::types::input_text_message_content::InputTextMessageContent;
 use crate::api::types::input_venue_message_content::InputVenueMessageContent;
-use serde::{Deserialize, Serialize};
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
 
 /// https://core.telegram.org/bots/api#inputmessagecontent
 /// This object represents the content of a message to be sent as a result of an inline query. Telegram clients currently support the following 5 types:
@@ -12,11 +14,37 @@ use serde::{Deserialize, Serialize};
 /// InputVenueMessageContent
 /// InputContactMessageContent
 /// InputInvoiceMessageContent
-#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(untagged)]
 pub enum InputMessageContent {
     InputTextMessageContent(InputTextMessageContent),
     InputLocationMessageContent(InputLocationMessageContent),
     InputVenueMessageContent(InputVenueMessageContent),
     InputContactMessageContent(InputContactMessageContent),
     InputInvoiceMessageContent(InputInvoiceMessageContent),
+    /// Unknown to this version of the crate, e.g. added by a newer Bot API.
+    Unknown(Value),
+}
+
+impl<'de> Deserialize<'de> for InputMessageContent {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = Value::deserialize(deserializer)?;
+        // Message contents carry no type field, so they are told apart by their required fields.
+        let has = |field: &str| value.get(field).is_some();
+
+        if has("message_text") {
+            serde_json::from_value(value).map(Self::InputTextMessageContent)
+        } else if has("address") {
+            serde_json::from_value(value).map(Self::InputVenueMessageContent)
+        } else if has("latitude") {
+            serde_json::from_value(value).map(Self::InputLocationMessageContent)
+        } else if has("phone_number") {
+            serde_json::from_value(value).map(Self::InputContactMessageContent)
+        } else if has("payload") {
+            serde_json::from_value(value).map(Self::InputInvoiceMessageContent)
+        } else {
+            Ok(Self::Unknown(value))
+        }
+        .map_err(D::Error::custom)
+    }
 }
@@ -1,13 +1,32 @@
 use crate::api::types::inaccessible_message::InaccessibleMessage;
 use crate::api::types::message::Message;
-use serde::{Deserialize, Serialize};
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
 
 /// https://core.telegram.org/bots/api#maybeinaccessiblemessage
 /// This object describes a message that can be inaccessible to the bot. It can be one of
 /// Message
 /// InaccessibleMessage
-#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(untagged)]
 pub enum MaybeInaccessibleMessage {
     Message(Box<Message>),
-    InaccessibleMessage(InaccessibleMessage),
+    InaccessibleMessage(Box<InaccessibleMessage>),
+    /// Neither kind of message, e.g. a shape introduced by a newer Bot API.
+    Unknown(Value),
+}
+
+impl<'de> Deserialize<'de> for MaybeInaccessibleMessage {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = Value::deserialize(deserializer)?;
+
+        // An inaccessible message is always sent with a zero `date`.
+        match value.get("date").and_then(Value::as_i64) {
+            Some(0) => serde_json::from_value(value).map(Self::InaccessibleMessage),
+            Some(_) => serde_json::from_value(value).map(Self::Message),
+            None => Ok(Self::Unknown(value)),
+        }
+        .map_err(D::Error::custom)
+    }
 }
@@ -2,12 +2,35 @@ use crate::api::types::input_media_audio::InputMediaAudio;
 use crate::api::types::input_media_document::InputMediaDocument;
 use crate::api::types::input_media_photo::InputMediaPhoto;
 use crate::api::types::input_media_video::InputMediaVideo;
+use crate::api::validate;
+use crate::api::validate::Validate;
+use crate::errors::Error;
 use serde::Serialize;
 
 #[derive(Debug, Serialize)]
+#[serde(untagged)]
 pub enum MediaInput {
     InputMediaAudio(InputMediaAudio),
     InputMediaDocument(InputMediaDocument),
     InputMediaPhoto(InputMediaPhoto),
     InputMediaVideo(InputMediaVideo),
 }
+
+impl Validate for MediaInput {
+    fn validate(&self) -> Result<(), Error> {
+        match self {
+            Self::InputMediaAudio(media) => {
+                validate::caption("caption", &media.caption, media.parse_mode)
+            }
+            Self::InputMediaDocument(media) => {
+                validate::caption("caption", &media.caption, media.parse_mode)
+            }
+            Self::InputMediaPhoto(media) => {
+                validate::caption("caption", &media.caption, media.parse_mode)
+            }
+            Self::InputMediaVideo(media) => {
+                validate::caption("caption", &media.caption, media.parse_mode)
+            }
+        }
+    }
+}
@@ -1,7 +1,9 @@
 use crate::api::types::menu_button_commands::MenuButtonCommands;
 use crate::api::types::menu_button_default::MenuButtonDefault;
 use crate::api::types::menu_button_web_app::MenuButtonWebApp;
-use serde::{Deserialize, Serialize};
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
 
 /// https://core.telegram.org/bots/api#menubutton
 /// This object describes the bot's menu button in a private chat. It should be one of
@@ -9,9 +11,26 @@ use serde::{Deserialize, Serialize};
 /// MenuButtonWebApp
 /// MenuButtonDefault
 /// If a menu button other than MenuButtonDefault is set for a private chat, then it is applied in the chat. Otherwise the default menu button is applied. By default, the menu button opens the list of bot commands.
-#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(untagged)]
 pub enum MenuButton {
     MenuButtonCommands(MenuButtonCommands),
     MenuButtonWebApp(MenuButtonWebApp),
     MenuButtonDefault(MenuButtonDefault),
+    /// Unknown to this version of the crate, e.g. added by a newer Bot API.
+    Unknown(Value),
+}
+
+impl<'de> Deserialize<'de> for MenuButton {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = Value::deserialize(deserializer)?;
+
+        match value.get("type").and_then(Value::as_str) {
+            Some("commands") => serde_json::from_value(value).map(Self::MenuButtonCommands),
+            Some("web_app") => serde_json::from_value(value).map(Self::MenuButtonWebApp),
+            Some("default") => serde_json::from_value(value).map(Self::MenuButtonDefault),
+            _ => Ok(Self::Unknown(value)),
+        }
+        .map_err(D::Error::custom)
+    }
 }
@@ -0,0 +1,92 @@
+use serde::{Deserialize, Serialize};
+
+/// https://core.telegram.org/bots/api#messageentity
+/// Type of a message entity.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(from = "String", into = "String")]
+pub enum MessageEntityKind {
+    Mention,
+    Hashtag,
+    Cashtag,
+    BotCommand,
+    Url,
+    Email,
+    PhoneNumber,
+    Bold,
+    Italic,
+    Underline,
+    Strikethrough,
+    Spoiler,
+    Blockquote,
+    Code,
+    Pre,
+    TextLink,
+    TextMention,
+    CustomEmoji,
+    /// Unknown to this version of the crate, e.g. added by a newer Bot API.
+    Unknown(String),
+}
+
+impl MessageEntityKind {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Mention => "mention",
+            Self::Hashtag => "hashtag",
+            Self::Cashtag => "cashtag",
+            Self::BotCommand => "bot_command",
+            Self::Url => "url",
+            Self::Email => "email",
+            Self::PhoneNumber => "phone_number",
+            Self::Bold => "bold",
+            Self::Italic => "italic",
+            Self::Underline => "underline",
+            Self::Strikethrough => "strikethrough",
+            Self::Spoiler => "spoiler",
+            Self::Blockquote => "blockquote",
+            Self::Code => "code",
+            Self::Pre => "pre",
+            Self::TextLink => "text_link",
+            Self::TextMention => "text_mention",
+            Self::CustomEmoji => "custom_emoji",
+            Self::Unknown(kind) => kind,
+        }
+    }
+}
+
+impl From<&str> for MessageEntityKind {
+    fn from(kind: &str) -> Self {
+        match kind {
+            "mention" => Self::Mention,
+            "hashtag" => Self::Hashtag,
+            "cashtag" => Self::Cashtag,
+            "bot_command" => Self::BotCommand,
+            "url" => Self::Url,
+            "email" => Self::Email,
+            "phone_number" => Self::PhoneNumber,
+            "bold" => Self::Bold,
+            "italic" => Self::Italic,
+            "underline" => Self::Underline,
+            "strikethrough" => Self::Strikethrough,
+            "spoiler" => Self::Spoiler,
+            "blockquote" => Self::Blockquote,
+            "code" => Self::Code,
+            "pre" => Self::Pre,
+            "text_link" => Self::TextLink,
+            "text_mention" => Self::TextMention,
+            "custom_emoji" => Self::CustomEmoji,
+            kind => Self::Unknown(kind.to_string()),
+        }
+    }
+}
+
+impl From<String> for MessageEntityKind {
+    fn from(kind: String) -> Self {
+        Self::from(kind.as_str())
+    }
+}
+
+impl From<MessageEntityKind> for String {
+    fn from(kind: MessageEntityKind) -> Self {
+        kind.as_str().to_string()
+    }
+}
@@ -0,0 +1,10 @@
+use crate::api::types::message::Message;
+use serde::{Deserialize, Serialize};
+
+/// Result of the methods that edit a message: the edited Message, or True for inline messages.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub enum MessageOrBool {
+    Message(Box<Message>),
+    Bool(bool),
+}
@@ -2,7 +2,9 @@ use crate::api::types::message_origin_channel::MessageOriginChannel;
 use crate::api::types::message_origin_chat::MessageOriginChat;
 use crate::api::types::message_origin_hidden_user::MessageOriginHiddenUser;
 use crate::api::types::message_origin_user::MessageOriginUser;
-use serde::{Deserialize, Serialize};
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
 
 /// https://core.telegram.org/bots/api#messageorigin
 /// This object describes the origin of a message. It can be one of
@@ -10,10 +12,28 @@ use serde::{Deserialize, Serialize};
 /// MessageOriginHiddenUser
 /// MessageOriginChat
 /// MessageOriginChannel
-#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(untagged)]
 pub enum MessageOrigin {
     MessageOriginUser(MessageOriginUser),
     MessageOriginHiddenUser(MessageOriginHiddenUser),
     MessageOriginChat(MessageOriginChat),
     MessageOriginChannel(MessageOriginChannel),
+    /// Unknown to this version of the crate, e.g. added by a newer Bot API.
+    Unknown(Value),
+}
+
+impl<'de> Deserialize<'de> for MessageOrigin {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = Value::deserialize(deserializer)?;
+
+        match value.get("type").and_then(Value::as_str) {
+            Some("user") => serde_json::from_value(value).map(Self::MessageOriginUser),
+            Some("hidden_user") => serde_json::from_value(value).map(Self::MessageOriginHiddenUser),
+            Some("chat") => serde_json::from_value(value).map(Self::MessageOriginChat),
+            Some("channel") => serde_json::from_value(value).map(Self::MessageOriginChannel),
+            _ => Ok(Self::Unknown(value)),
+        }
+        .map_err(D::Error::custom)
+    }
 }
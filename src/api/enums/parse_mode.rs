@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+/// https://core.telegram.org/bots/api#formatting-options
+/// Markup of the text or caption of a message.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum ParseMode {
+    #[serde(rename = "HTML")]
+    Html,
+    MarkdownV2,
+    /// Legacy mode, kept for backward compatibility, use `MarkdownV2` instead.
+    Markdown,
+}
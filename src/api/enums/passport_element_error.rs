@@ -7,7 +7,12 @@ use crate::api::types::passport_element_error_selfie::PassportElementErrorSelfie
 use crate::api::types::passport_element_error_translation_file::PassportElementErrorTranslationFile;
 use crate::api::types::passport_element_error_translation_files::PassportElementErrorTranslationFiles;
 use crate::api::types::passport_element_error_unspecified::PassportElementErrorUnspecified;
-use serde::{Deserialize, Serialize};
+use crate::api::validate;
+use crate::api::validate::Validate;
+use crate::errors;
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
 
 /// https://core.telegram.org/bots/api#passportelementerror
 /// This object represents an error in the Telegram Passport element which was submitted that should be resolved by the user. It should be one of:
@@ -20,7 +25,8 @@ use serde::{Deserialize, Serialize};
 /// PassportElementErrorTranslationFile
 /// PassportElementErrorTranslationFiles
 /// PassportElementErrorUnspecified
-#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(untagged)]
 pub enum PassportElementError {
     PassportElementErrorDataField(PassportElementErrorDataField),
     PassportElementErrorFrontSide(PassportElementErrorFrontSide),
@@ -31,4 +37,80 @@ pub enum PassportElementError {
     PassportElementErrorTranslationFile(PassportElementErrorTranslationFile),
     PassportElementErrorTranslationFiles(PassportElementErrorTranslationFiles),
     PassportElementErrorUnspecified(PassportElementErrorUnspecified),
+    /// Unknown to this version of the crate, e.g. added by a newer Bot API.
+    Unknown(Value),
+}
+
+impl<'de> Deserialize<'de> for PassportElementError {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = Value::deserialize(deserializer)?;
+
+        match value.get("source").and_then(Value::as_str) {
+            Some("data") => serde_json::from_value(value).map(Self::PassportElementErrorDataField),
+            Some("front_side") => {
+                serde_json::from_value(value).map(Self::PassportElementErrorFrontSide)
+            }
+            Some("reverse_side") => {
+                serde_json::from_value(value).map(Self::PassportElementErrorReverseSide)
+            }
+            Some("selfie") => serde_json::from_value(value).map(Self::PassportElementErrorSelfie),
+            Some("file") => serde_json::from_value(value).map(Self::PassportElementErrorFile),
+            Some("files") => serde_json::from_value(value).map(Self::PassportElementErrorFiles),
+            Some("translation_file") => {
+                serde_json::from_value(value).map(Self::PassportElementErrorTranslationFile)
+            }
+            Some("translation_files") => {
+                serde_json::from_value(value).map(Self::PassportElementErrorTranslationFiles)
+            }
+            Some("unspecified") => {
+                serde_json::from_value(value).map(Self::PassportElementErrorUnspecified)
+            }
+            _ => Ok(Self::Unknown(value)),
+        }
+        .map_err(D::Error::custom)
+    }
+}
+
+impl PassportElementError {
+    /// `source` of the variant, the Bot API tells the errors apart by it.
+    fn expected_source(&self) -> Option<&'static str> {
+        match self {
+            Self::PassportElementErrorDataField(_) => Some("data"),
+            Self::PassportElementErrorFrontSide(_) => Some("front_side"),
+            Self::PassportElementErrorReverseSide(_) => Some("reverse_side"),
+            Self::PassportElementErrorSelfie(_) => Some("selfie"),
+            Self::PassportElementErrorFile(_) => Some("file"),
+            Self::PassportElementErrorFiles(_) => Some("files"),
+            Self::PassportElementErrorTranslationFile(_) => Some("translation_file"),
+            Self::PassportElementErrorTranslationFiles(_) => Some("translation_files"),
+            Self::PassportElementErrorUnspecified(_) => Some("unspecified"),
+            Self::Unknown(_) => None,
+        }
+    }
+
+    fn source(&self) -> Option<&str> {
+        match self {
+            Self::PassportElementErrorDataField(error) => Some(&error.source),
+            Self::PassportElementErrorFrontSide(error) => Some(&error.source),
+            Self::PassportElementErrorReverseSide(error) => Some(&error.source),
+            Self::PassportElementErrorSelfie(error) => Some(&error.source),
+            Self::PassportElementErrorFile(error) => Some(&error.source),
+            Self::PassportElementErrorFiles(error) => Some(&error.source),
+            Self::PassportElementErrorTranslationFile(error) => Some(&error.source),
+            Self::PassportElementErrorTranslationFiles(error) => Some(&error.source),
+            Self::PassportElementErrorUnspecified(error) => Some(&error.source),
+            Self::Unknown(value) => value.get("source").and_then(Value::as_str),
+        }
+    }
+}
+
+impl Validate for PassportElementError {
+    fn validate(&self) -> Result<(), errors::Error> {
+        match (self.expected_source(), self.source()) {
+            (Some(expected), Some(source)) if expected != source => {
+                validate::fail("source", format!("must be {}, got {}", expected, source))
+            }
+            _ => Ok(()),
+        }
+    }
 }
@@ -1,13 +1,33 @@
 use crate::api::types::reaction_type_custom_emoji::ReactionTypeCustomEmoji;
 use crate::api::types::reaction_type_emoji::ReactionTypeEmoji;
-use serde::{Deserialize, Serialize};
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
 
 /// https://core.telegram.org/bots/api#reactiontype
 /// This object describes the type of a reaction. Currently, it can be one of
 /// ReactionTypeEmoji
 /// ReactionTypeCustomEmoji
-#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(untagged)]
 pub enum ReactionType {
     ReactionTypeEmoji(ReactionTypeEmoji),
     ReactionTypeCustomEmoji(ReactionTypeCustomEmoji),
+    /// Unknown to this version of the crate, e.g. added by a newer Bot API.
+    Unknown(Value),
+}
+
+impl<'de> Deserialize<'de> for ReactionType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = Value::deserialize(deserializer)?;
+
+        match value.get("type").and_then(Value::as_str) {
+            Some("emoji") => serde_json::from_value(value).map(Self::ReactionTypeEmoji),
+            Some("custom_emoji") => {
+                serde_json::from_value(value).map(Self::ReactionTypeCustomEmoji)
+            }
+            _ => Ok(Self::Unknown(value)),
+        }
+        .map_err(D::Error::custom)
+    }
 }
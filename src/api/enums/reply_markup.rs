@@ -2,12 +2,27 @@ use crate::api::types::force_reply::ForceReply;
 use crate::api::types::inline_keyboard_markup::InlineKeyboardMarkup;
 use crate::api::types::reply_keyboard_markup::ReplyKeyboardMarkup;
 use crate::api::types::reply_keyboard_remove::ReplyKeyboardRemove;
-use serde::Serialize;
+use crate::api::validate::Validate;
+use crate::errors::Error;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(untagged)]
 pub enum ReplyMarkup {
     InlineKeyboardMarkup(InlineKeyboardMarkup),
     ReplyKeyboardMarkup(ReplyKeyboardMarkup),
     ReplyKeyboardRemove(ReplyKeyboardRemove),
     ForceReply(ForceReply),
+    /// Unknown to this version of the crate, e.g. added by a newer Bot API.
+    Unknown(Value),
+}
+
+impl Validate for ReplyMarkup {
+    fn validate(&self) -> Result<(), Error> {
+        match self {
+            ReplyMarkup::InlineKeyboardMarkup(markup) => markup.validate(),
+            _ => Ok(()),
+        }
+    }
 }
@@ -0,0 +1,18 @@
+use crate::api::validate::Validate;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// Bot API method: the params struct knows the name of its endpoint and the type of its result,
+/// e.g. `client.sync.execute(&SendMessage { .. })` returns a `Message`. The params are validated
+/// before the request is sent, see `Validate`.
+pub trait Method: Serialize + Validate {
+    /// Name of the endpoint, e.g. `sendMessage`.
+    const NAME: &'static str;
+
+    /// Sending the same params twice has the same effect as sending them once, true only for
+    /// read-only methods. Other methods are retried after network errors and 5xx responses only
+    /// when a budget is set for them, see `Retry`.
+    const IDEMPOTENT: bool = false;
+
+    type Output: DeserializeOwned;
+}
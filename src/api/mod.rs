@@ -1,5 +1,7 @@
 pub mod enums;
+pub mod method;
 pub mod params;
 pub mod requests;
 pub mod responses;
 pub mod types;
+pub mod validate;
@@ -7,6 +7,7 @@ pub mod answer_web_app_query;
 pub mod approve_chat_join_request;
 pub mod ban_chat_member;
 pub mod ban_chat_sender_chat;
+pub mod close;
 pub mod close_forum_topic;
 pub mod close_general_forum_topic;
 pub mod copy_message;
@@ -44,6 +45,7 @@ pub mod get_chat_menu_button;
 pub mod get_custom_emoji_stickers;
 pub mod get_file;
 pub mod get_forum_topic_icon_stickers;
+pub mod get_me;
 pub mod get_my_commands;
 pub mod get_my_default_administrator_rights;
 pub mod get_my_description;
@@ -53,8 +55,10 @@ pub mod get_sticker_set;
 pub mod get_update;
 pub mod get_user_chat_boosts;
 pub mod get_user_profile_photos;
+pub mod get_webhook_info;
 pub mod hide_general_forum_topic;
 pub mod leave_chat;
+pub mod log_out;
 pub mod pin_chat_message;
 pub mod promote_chat_member;
 pub mod reopen_forum_topic;
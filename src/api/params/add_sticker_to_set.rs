@@ -1,3 +1,4 @@
+use crate::api::method::Method;
 use crate::api::types::input_sticker::InputSticker;
 use serde::Serialize;
 
@@ -9,3 +10,8 @@ pub struct AddStickerToSet {
     pub name: String,
     pub sticker: InputSticker,
 }
+
+impl Method for AddStickerToSet {
+    const NAME: &'static str = "addStickerToSet";
+    type Output = bool;
+}
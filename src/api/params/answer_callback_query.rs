@@ -1,3 +1,4 @@
+use crate::api::method::Method;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#answercallbackquery
@@ -15,3 +16,8 @@ pub struct AnswerCallbackQuery {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cache_time: Option<i64>,
 }
+
+impl Method for AnswerCallbackQuery {
+    const NAME: &'static str = "answerCallbackQuery";
+    type Output = bool;
+}
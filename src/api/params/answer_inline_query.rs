@@ -1,4 +1,5 @@
 use crate::api::enums::inline_query_result::InlineQueryResult;
+use crate::api::method::Method;
 use crate::api::types::inline_query_results_button::InlineQueryResultsButton;
 use serde::Serialize;
 
@@ -18,3 +19,8 @@ pub struct AnswerInlineQuery {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub button: Option<InlineQueryResultsButton>,
 }
+
+impl Method for AnswerInlineQuery {
+    const NAME: &'static str = "answerInlineQuery";
+    type Output = bool;
+}
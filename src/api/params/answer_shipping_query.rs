@@ -1,3 +1,4 @@
+use crate::api::method::Method;
 use crate::api::types::shipping_option::ShippingOption;
 use serde::Serialize;
 
@@ -12,3 +13,8 @@ pub struct AnswerShippingQuery {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error_message: Option<String>,
 }
+
+impl Method for AnswerShippingQuery {
+    const NAME: &'static str = "answerShippingQuery";
+    type Output = bool;
+}
@@ -1,10 +1,17 @@
 use crate::api::enums::inline_query_result::InlineQueryResult;
+use crate::api::method::Method;
+use crate::api::types::sent_web_app_message::SentWebAppMessage;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#answerwebappquery
 /// Use this method to set the result of an interaction with a Web App and send a corresponding message on behalf of the user to the chat from which the query originated. On success, a SentWebAppMessage object is returned.
 #[derive(Debug, Serialize)]
-struct AnswerWebAppQuery {
+pub struct AnswerWebAppQuery {
     pub web_app_query_id: String,
     pub result: InlineQueryResult,
 }
+
+impl Method for AnswerWebAppQuery {
+    const NAME: &'static str = "answerWebAppQuery";
+    type Output = SentWebAppMessage;
+}
@@ -1,4 +1,5 @@
 use crate::api::enums::chat_uid::ChatUId;
+use crate::api::method::Method;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#approvechatjoinrequest
@@ -8,3 +9,8 @@ pub struct ApproveChatJoinRequest {
     pub chat_id: ChatUId,
     pub user_id: i64,
 }
+
+impl Method for ApproveChatJoinRequest {
+    const NAME: &'static str = "approveChatJoinRequest";
+    type Output = bool;
+}
@@ -1,4 +1,5 @@
 use crate::api::enums::chat_uid::ChatUId;
+use crate::api::method::Method;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#banchatmember
@@ -12,3 +13,8 @@ pub struct BanChatMember {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub revoke_messages: Option<bool>,
 }
+
+impl Method for BanChatMember {
+    const NAME: &'static str = "banChatMember";
+    type Output = bool;
+}
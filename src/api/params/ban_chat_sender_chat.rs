@@ -1,4 +1,5 @@
 use crate::api::enums::chat_uid::ChatUId;
+use crate::api::method::Method;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#banchatsenderchat
@@ -8,3 +9,8 @@ pub struct BanChatSenderChat {
     pub chat_id: ChatUId,
     pub sender_chat_id: ChatUId,
 }
+
+impl Method for BanChatSenderChat {
+    const NAME: &'static str = "banChatSenderChat";
+    type Output = bool;
+}
@@ -0,0 +1,12 @@
+use crate::api::method::Method;
+use serde::Serialize;
+
+/// https://core.telegram.org/bots/api#close
+/// Use this method to close the bot instance before moving it from one local server to another. Returns True on success. Requires no parameters.
+#[derive(Debug, Serialize, Default)]
+pub struct Close {}
+
+impl Method for Close {
+    const NAME: &'static str = "close";
+    type Output = bool;
+}
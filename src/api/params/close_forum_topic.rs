@@ -1,4 +1,5 @@
 use crate::api::enums::chat_uid::ChatUId;
+use crate::api::method::Method;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#closeforumtopic
@@ -8,3 +9,8 @@ pub struct CloseForumTopic {
     pub chat_id: ChatUId,
     pub message_thread_id: i64,
 }
+
+impl Method for CloseForumTopic {
+    const NAME: &'static str = "closeForumTopic";
+    type Output = bool;
+}
@@ -1,4 +1,5 @@
 use crate::api::enums::chat_uid::ChatUId;
+use crate::api::method::Method;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#closegeneralforumtopic
@@ -7,3 +8,8 @@ use serde::Serialize;
 pub struct CloseGeneralForumTopic {
     pub chat_id: ChatUId,
 }
+
+impl Method for CloseGeneralForumTopic {
+    const NAME: &'static str = "closeGeneralForumTopic";
+    type Output = bool;
+}
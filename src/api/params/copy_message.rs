@@ -1,8 +1,12 @@
 use crate::api::enums::chat_uid::ChatUId;
+use crate::api::enums::parse_mode::ParseMode;
 use crate::api::enums::reply_markup::ReplyMarkup;
+use crate::api::method::Method;
 use crate::api::types::message_entity::MessageEntity;
 use crate::api::types::message_id::MessageId;
 use crate::api::types::reply_parameters::ReplyParameters;
+use crate::api::validate::{self, Validate};
+use crate::errors::Error;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#copymessage
@@ -18,7 +22,7 @@ pub struct CopyMessage {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub caption: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<String>,
+    pub parse_mode: Option<ParseMode>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub caption_entities: Option<Vec<MessageEntity>>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -30,3 +34,16 @@ pub struct CopyMessage {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<ReplyMarkup>,
 }
+
+impl Method for CopyMessage {
+    const NAME: &'static str = "copyMessage";
+    type Output = MessageId;
+}
+
+impl Validate for CopyMessage {
+    fn validate(&self) -> Result<(), Error> {
+        validate::caption("caption", &self.caption, self.parse_mode)?;
+
+        validate::nested("reply_markup", &self.reply_markup)
+    }
+}
@@ -1,4 +1,5 @@
 use crate::api::enums::chat_uid::ChatUId;
+use crate::api::method::Method;
 use crate::api::types::message_id::MessageId;
 use serde::Serialize;
 
@@ -18,3 +19,8 @@ pub struct CopyMessages {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub remove_caption: Option<bool>,
 }
+
+impl Method for CopyMessages {
+    const NAME: &'static str = "copyMessages";
+    type Output = Vec<MessageId>;
+}
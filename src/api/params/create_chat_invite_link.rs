@@ -1,4 +1,6 @@
 use crate::api::enums::chat_uid::ChatUId;
+use crate::api::method::Method;
+use crate::api::types::chat_invite_link::ChatInviteLink;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#createchatinvitelink
@@ -15,3 +17,8 @@ pub struct CreateChatInviteLink {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub creates_join_request: Option<bool>,
 }
+
+impl Method for CreateChatInviteLink {
+    const NAME: &'static str = "createChatInviteLink";
+    type Output = ChatInviteLink;
+}
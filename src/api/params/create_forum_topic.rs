@@ -1,5 +1,8 @@
 use crate::api::enums::chat_uid::ChatUId;
+use crate::api::method::Method;
+use crate::api::types::forum_topic::ForumTopic;
 use serde::Serialize;
+
 /// https://core.telegram.org/bots/api#createforumtopic
 /// Use this method to create a topic in a forum supergroup chat. The bot must be an administrator in the chat for this to work and must have the can_manage_topics administrator rights. Returns information about the created topic as a ForumTopic object.
 #[derive(Debug, Serialize)]
@@ -11,3 +14,8 @@ pub struct CreateForumTopic {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub icon_custom_emoji_id: Option<String>,
 }
+
+impl Method for CreateForumTopic {
+    const NAME: &'static str = "createForumTopic";
+    type Output = ForumTopic;
+}
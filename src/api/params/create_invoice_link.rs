@@ -1,10 +1,13 @@
+use crate::api::method::Method;
 use crate::api::types::labeled_price::LabeledPrice;
+use crate::api::validate::{self, Validate};
+use crate::errors::Error;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#createinvoicelink
 /// Use this method to create a link for an invoice. Returns the created invoice link as String on success.
 #[derive(Debug, Serialize)]
-struct CreateInvoiceLink {
+pub struct CreateInvoiceLink {
     pub title: String,
     pub description: String,
     pub payload: String,
@@ -40,3 +43,17 @@ struct CreateInvoiceLink {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub is_flexible: Option<bool>,
 }
+
+impl Method for CreateInvoiceLink {
+    const NAME: &'static str = "createInvoiceLink";
+    type Output = String;
+}
+
+impl Validate for CreateInvoiceLink {
+    fn validate(&self) -> Result<(), Error> {
+        match self.prices.is_empty() {
+            true => validate::fail("prices", String::from("must not be empty")),
+            false => Ok(()),
+        }
+    }
+}
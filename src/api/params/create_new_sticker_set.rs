@@ -1,9 +1,10 @@
+use crate::api::method::Method;
 use crate::api::types::input_sticker::InputSticker;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#createnewstickerset
 #[derive(Debug, Serialize)]
-struct CreateNewStickerSet {
+pub struct CreateNewStickerSet {
     pub user_id: i64,
     pub name: String,
     pub title: String,
@@ -14,3 +15,8 @@ struct CreateNewStickerSet {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub needs_repainting: Option<bool>,
 }
+
+impl Method for CreateNewStickerSet {
+    const NAME: &'static str = "createNewStickerSet";
+    type Output = bool;
+}
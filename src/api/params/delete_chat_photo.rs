@@ -1,9 +1,15 @@
 use crate::api::enums::chat_uid::ChatUId;
+use crate::api::method::Method;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#deletechatphoto
 /// Use this method to delete a chat photo. Photos can't be changed for private chats. The bot must be an administrator in the chat for this to work and must have the appropriate administrator rights. Returns True on success.
 #[derive(Debug, Serialize)]
-struct DeleteChatPhoto {
+pub struct DeleteChatPhoto {
     pub chat_id: ChatUId,
 }
+
+impl Method for DeleteChatPhoto {
+    const NAME: &'static str = "deleteChatPhoto";
+    type Output = bool;
+}
@@ -1,9 +1,15 @@
 use crate::api::enums::chat_uid::ChatUId;
+use crate::api::method::Method;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#deletechatstickerset
 /// Use this method to delete a group sticker set from a supergroup. The bot must be an administrator in the chat for this to work and must have the appropriate administrator rights. Use the field can_set_sticker_set optionally returned in getChat requests to check if the bot can use this method. Returns True on success.
 #[derive(Debug, Serialize)]
-struct DeleteChatStickerSet {
+pub struct DeleteChatStickerSet {
     pub chat_id: ChatUId,
 }
+
+impl Method for DeleteChatStickerSet {
+    const NAME: &'static str = "deleteChatStickerSet";
+    type Output = bool;
+}
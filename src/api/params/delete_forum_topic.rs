@@ -1,9 +1,16 @@
 use crate::api::enums::chat_uid::ChatUId;
+use crate::api::method::Method;
 use serde::Serialize;
+
 /// https://core.telegram.org/bots/api#deleteforumtopic
 /// Use this method to delete a forum topic along with all its messages in a forum supergroup chat. The bot must be an administrator in the chat for this to work and must have the can_delete_messages administrator rights. Returns True on success.
 #[derive(Debug, Serialize)]
-struct DeleteForumTopic {
+pub struct DeleteForumTopic {
     pub chat_id: ChatUId,
     pub message_thread_id: i64,
 }
+
+impl Method for DeleteForumTopic {
+    const NAME: &'static str = "deleteForumTopic";
+    type Output = bool;
+}
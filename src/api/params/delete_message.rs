@@ -1,4 +1,5 @@
 use crate::api::enums::chat_uid::ChatUId;
+use crate::api::method::Method;
 use crate::api::types::message_id::MessageId;
 use serde::Serialize;
 
@@ -12,10 +13,16 @@ use serde::Serialize;
 /// - Bots granted can_post_messages permissions can delete outgoing messages in channels.
 /// - If the bot is an administrator of a group, it can delete any message there.
 /// - If the bot has can_delete_messages permission in a supergroup or a channel, it can delete any message there.
+///
 /// Returns True on success.
 #[derive(Debug, Serialize)]
-struct DeleteMessage {
+pub struct DeleteMessage {
     #[serde(flatten)]
     pub message_id: MessageId,
     pub chat_id: ChatUId,
 }
+
+impl Method for DeleteMessage {
+    const NAME: &'static str = "deleteMessage";
+    type Output = bool;
+}
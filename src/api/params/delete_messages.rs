@@ -1,10 +1,16 @@
 use crate::api::enums::chat_uid::ChatUId;
+use crate::api::method::Method;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#deletemessages
 /// Use this method to delete multiple messages simultaneously. If some of the specified messages can't be found, they are skipped. Returns True on success.
 #[derive(Debug, Serialize)]
-struct DeleteMessages {
+pub struct DeleteMessages {
     pub chat_id: ChatUId,
     pub message_ids: Vec<i64>,
 }
+
+impl Method for DeleteMessages {
+    const NAME: &'static str = "deleteMessages";
+    type Output = bool;
+}
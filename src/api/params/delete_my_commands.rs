@@ -1,4 +1,5 @@
 use crate::api::enums::bot_command_scope::BotCommandScope;
+use crate::api::method::Method;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#deletemycommands
@@ -10,3 +11,8 @@ pub struct DeleteMyCommands {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub language_code: Option<String>,
 }
+
+impl Method for DeleteMyCommands {
+    const NAME: &'static str = "deleteMyCommands";
+    type Output = bool;
+}
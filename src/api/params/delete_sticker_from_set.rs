@@ -1,8 +1,14 @@
+use crate::api::method::Method;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#deletestickerfromset
 /// Use this method to delete a sticker from a set created by the bot. Returns True on success.
 #[derive(Debug, Serialize)]
-struct DeleteStickerFromSet {
+pub struct DeleteStickerFromSet {
     pub sticker: String,
 }
+
+impl Method for DeleteStickerFromSet {
+    const NAME: &'static str = "deleteStickerFromSet";
+    type Output = bool;
+}
@@ -1,3 +1,4 @@
+use crate::api::method::Method;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#deletestickerset
@@ -6,3 +7,8 @@ use serde::Serialize;
 pub struct DeleteStickerSet {
     pub name: String,
 }
+
+impl Method for DeleteStickerSet {
+    const NAME: &'static str = "deleteStickerSet";
+    type Output = bool;
+}
@@ -1,3 +1,4 @@
+use crate::api::method::Method;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#deletewebhook
@@ -7,3 +8,8 @@ pub struct DeleteWebhook {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub drop_pending_updates: Option<bool>,
 }
+
+impl Method for DeleteWebhook {
+    const NAME: &'static str = "deleteWebhook";
+    type Output = bool;
+}
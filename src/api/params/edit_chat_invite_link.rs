@@ -1,10 +1,12 @@
 use crate::api::enums::chat_uid::ChatUId;
+use crate::api::method::Method;
+use crate::api::types::chat_invite_link::ChatInviteLink;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#editchatinvitelink
 /// Use this method to edit a non-primary invite link created by the bot. The bot must be an administrator in the chat for this to work and must have the appropriate administrator rights. Returns the edited invite link as a ChatInviteLink object.
 #[derive(Debug, Serialize)]
-struct EditChatInviteLink {
+pub struct EditChatInviteLink {
     pub chat_id: ChatUId,
     pub invite_link: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -16,3 +18,8 @@ struct EditChatInviteLink {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub creates_join_request: Option<bool>,
 }
+
+impl Method for EditChatInviteLink {
+    const NAME: &'static str = "editChatInviteLink";
+    type Output = ChatInviteLink;
+}
@@ -1,4 +1,5 @@
 use crate::api::enums::chat_uid::ChatUId;
+use crate::api::method::Method;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#editforumtopic
@@ -12,3 +13,8 @@ pub struct EditForumTopic {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub icon_custom_emoji_id: Option<String>,
 }
+
+impl Method for EditForumTopic {
+    const NAME: &'static str = "editForumTopic";
+    type Output = bool;
+}
@@ -1,4 +1,5 @@
 use crate::api::enums::chat_uid::ChatUId;
+use crate::api::method::Method;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#editgeneralforumtopic
@@ -8,3 +9,8 @@ pub struct EditGeneralForumTopic {
     pub chat_id: ChatUId,
     pub name: String,
 }
+
+impl Method for EditGeneralForumTopic {
+    const NAME: &'static str = "editGeneralForumTopic";
+    type Output = bool;
+}
@@ -1,6 +1,11 @@
 use crate::api::enums::chat_uid::ChatUId;
+use crate::api::enums::message_or_bool::MessageOrBool;
+use crate::api::enums::parse_mode::ParseMode;
+use crate::api::method::Method;
 use crate::api::types::inline_keyboard_markup::InlineKeyboardMarkup;
 use crate::api::types::message_entity::MessageEntity;
+use crate::api::validate::{self, Validate};
+use crate::errors::Error;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#editmessagecaption
@@ -16,9 +21,22 @@ pub struct EditMessageCaption {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub caption: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<String>,
+    pub parse_mode: Option<ParseMode>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub caption_entities: Option<Vec<MessageEntity>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<InlineKeyboardMarkup>,
 }
+
+impl Method for EditMessageCaption {
+    const NAME: &'static str = "editMessageCaption";
+    type Output = MessageOrBool;
+}
+
+impl Validate for EditMessageCaption {
+    fn validate(&self) -> Result<(), Error> {
+        validate::caption("caption", &self.caption, self.parse_mode)?;
+
+        validate::nested("reply_markup", &self.reply_markup)
+    }
+}
@@ -1,11 +1,15 @@
 use crate::api::enums::chat_uid::ChatUId;
+use crate::api::enums::message_or_bool::MessageOrBool;
+use crate::api::method::Method;
 use crate::api::types::inline_keyboard_markup::InlineKeyboardMarkup;
+use crate::api::validate::{self, Validate};
+use crate::errors::Error;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#editmessagelivelocation
 /// Use this method to edit live location messages. A location can be edited until its live_period expires or editing is explicitly disabled by a call to stopMessageLiveLocation. On success, if the edited message is not an inline message, the edited Message is returned, otherwise True is returned.
 #[derive(Debug, Serialize)]
-struct EditMessageLiveLocation {
+pub struct EditMessageLiveLocation {
     pub latitude: f64,
     pub longitude: f64,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -23,3 +27,14 @@ struct EditMessageLiveLocation {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<InlineKeyboardMarkup>,
 }
+
+impl Method for EditMessageLiveLocation {
+    const NAME: &'static str = "editMessageLiveLocation";
+    type Output = MessageOrBool;
+}
+
+impl Validate for EditMessageLiveLocation {
+    fn validate(&self) -> Result<(), Error> {
+        validate::nested("reply_markup", &self.reply_markup)
+    }
+}
@@ -1,6 +1,10 @@
 use crate::api::enums::chat_uid::ChatUId;
 use crate::api::enums::input_media::InputMedia;
+use crate::api::enums::message_or_bool::MessageOrBool;
+use crate::api::method::Method;
 use crate::api::types::inline_keyboard_markup::InlineKeyboardMarkup;
+use crate::api::validate::{self, Validate};
+use crate::errors::Error;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#editmessagemedia
@@ -17,3 +21,15 @@ pub struct EditMessageMedia {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<InlineKeyboardMarkup>,
 }
+
+impl Method for EditMessageMedia {
+    const NAME: &'static str = "editMessageMedia";
+    type Output = MessageOrBool;
+}
+
+impl Validate for EditMessageMedia {
+    fn validate(&self) -> Result<(), Error> {
+        validate::nested("media", &self.media)?;
+        validate::nested("reply_markup", &self.reply_markup)
+    }
+}
@@ -1,5 +1,9 @@
 use crate::api::enums::chat_uid::ChatUId;
+use crate::api::enums::message_or_bool::MessageOrBool;
+use crate::api::method::Method;
 use crate::api::types::inline_keyboard_markup::InlineKeyboardMarkup;
+use crate::api::validate::{self, Validate};
+use crate::errors::Error;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#editmessagereplymarkup
@@ -15,3 +19,14 @@ pub struct EditMessageReplyMarkup {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<InlineKeyboardMarkup>,
 }
+
+impl Method for EditMessageReplyMarkup {
+    const NAME: &'static str = "editMessageReplyMarkup";
+    type Output = MessageOrBool;
+}
+
+impl Validate for EditMessageReplyMarkup {
+    fn validate(&self) -> Result<(), Error> {
+        validate::nested("reply_markup", &self.reply_markup)
+    }
+}
@@ -1,7 +1,13 @@
 use crate::api::enums::chat_uid::ChatUId;
+use crate::api::enums::message_or_bool::MessageOrBool;
+use crate::api::enums::parse_mode::ParseMode;
+use crate::api::method::Method;
 use crate::api::types::inline_keyboard_markup::InlineKeyboardMarkup;
 use crate::api::types::link_preview_options::LinkPreviewOptions;
 use crate::api::types::message_entity::MessageEntity;
+use crate::api::validate::{self, Validate};
+use crate::errors::Error;
+use crate::format::split::TEXT_LIMIT;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#editmessagetext
@@ -16,7 +22,7 @@ pub struct EditMessageText {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub inline_message_id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<String>,
+    pub parse_mode: Option<ParseMode>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub entities: Option<Vec<MessageEntity>>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -24,3 +30,18 @@ pub struct EditMessageText {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<InlineKeyboardMarkup>,
 }
+
+impl Method for EditMessageText {
+    const NAME: &'static str = "editMessageText";
+    type Output = MessageOrBool;
+}
+
+impl Validate for EditMessageText {
+    fn validate(&self) -> Result<(), Error> {
+        if self.parse_mode.is_none() {
+            validate::length("text", &self.text, 1, TEXT_LIMIT)?;
+        }
+
+        validate::nested("reply_markup", &self.reply_markup)
+    }
+}
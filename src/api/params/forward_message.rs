@@ -1,4 +1,6 @@
 use crate::api::enums::chat_uid::ChatUId;
+use crate::api::method::Method;
+use crate::api::types::message::Message;
 use crate::api::types::message_id::MessageId;
 use serde::Serialize;
 
@@ -17,3 +19,8 @@ pub struct ForwardMessage {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub protect_content: Option<bool>,
 }
+
+impl Method for ForwardMessage {
+    const NAME: &'static str = "forwardMessage";
+    type Output = Message;
+}
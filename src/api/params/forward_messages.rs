@@ -1,4 +1,5 @@
 use crate::api::enums::chat_uid::ChatUId;
+use crate::api::method::Method;
 use crate::api::types::message_id::MessageId;
 use serde::Serialize;
 
@@ -16,3 +17,8 @@ pub struct ForwardMessages {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub protect_content: Option<bool>,
 }
+
+impl Method for ForwardMessages {
+    const NAME: &'static str = "forwardMessages";
+    type Output = Vec<MessageId>;
+}
@@ -1,4 +1,6 @@
 use crate::api::enums::chat_uid::ChatUId;
+use crate::api::method::Method;
+use crate::api::types::chat::Chat;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#getchat
@@ -7,3 +9,9 @@ use serde::Serialize;
 pub struct GetChat {
     pub chat_id: ChatUId,
 }
+
+impl Method for GetChat {
+    const NAME: &'static str = "getChat";
+    const IDEMPOTENT: bool = true;
+    type Output = Chat;
+}
@@ -1,4 +1,6 @@
+use crate::api::enums::chat_member::ChatMember;
 use crate::api::enums::chat_uid::ChatUId;
+use crate::api::method::Method;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#getchatadministrators
@@ -7,3 +9,9 @@ use serde::Serialize;
 pub struct GetChatAdministrators {
     pub chat_id: ChatUId,
 }
+
+impl Method for GetChatAdministrators {
+    const NAME: &'static str = "getChatAdministrators";
+    const IDEMPOTENT: bool = true;
+    type Output = Vec<ChatMember>;
+}
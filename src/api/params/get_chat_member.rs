@@ -1,4 +1,6 @@
+use crate::api::enums::chat_member::ChatMember;
 use crate::api::enums::chat_uid::ChatUId;
+use crate::api::method::Method;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#getchatmember
@@ -8,3 +10,9 @@ pub struct GetChatMember {
     pub chat_id: ChatUId,
     pub user_id: i64,
 }
+
+impl Method for GetChatMember {
+    const NAME: &'static str = "getChatMember";
+    const IDEMPOTENT: bool = true;
+    type Output = ChatMember;
+}
@@ -1,4 +1,5 @@
 use crate::api::enums::chat_uid::ChatUId;
+use crate::api::method::Method;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#getchatmembercount
@@ -7,3 +8,9 @@ use serde::Serialize;
 pub struct GetChatMemberCount {
     pub chat_id: ChatUId,
 }
+
+impl Method for GetChatMemberCount {
+    const NAME: &'static str = "getChatMemberCount";
+    const IDEMPOTENT: bool = true;
+    type Output = i64;
+}
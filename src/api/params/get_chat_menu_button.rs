@@ -1,4 +1,6 @@
 use crate::api::enums::chat_uid::ChatUId;
+use crate::api::enums::menu_button::MenuButton;
+use crate::api::method::Method;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#getchatmenubutton
@@ -8,3 +10,9 @@ pub struct GetChatMenuButton {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub chat_id: Option<ChatUId>,
 }
+
+impl Method for GetChatMenuButton {
+    const NAME: &'static str = "getChatMenuButton";
+    const IDEMPOTENT: bool = true;
+    type Output = MenuButton;
+}
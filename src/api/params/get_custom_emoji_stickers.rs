@@ -1,3 +1,5 @@
+use crate::api::method::Method;
+use crate::api::types::sticker::Sticker;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#getcustomemojistickers
@@ -6,3 +8,9 @@ use serde::Serialize;
 pub struct GetCustomEmojiStickers {
     pub custom_emoji_ids: Vec<String>,
 }
+
+impl Method for GetCustomEmojiStickers {
+    const NAME: &'static str = "getCustomEmojiStickers";
+    const IDEMPOTENT: bool = true;
+    type Output = Vec<Sticker>;
+}
@@ -1,3 +1,5 @@
+use crate::api::method::Method;
+use crate::api::types::file::File;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#getfile
@@ -6,3 +8,9 @@ use serde::Serialize;
 pub struct GetFile {
     pub file_id: String,
 }
+
+impl Method for GetFile {
+    const NAME: &'static str = "getFile";
+    const IDEMPOTENT: bool = true;
+    type Output = File;
+}
@@ -1,6 +1,14 @@
+use crate::api::method::Method;
+use crate::api::types::sticker::Sticker;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#getforumtopiciconstickers
 /// Use this method to get custom emoji stickers, which can be used as a forum topic icon by any user. Requires no parameters. Returns an Array of Sticker objects.
 #[derive(Debug, Serialize)]
 pub struct GetForumTopicIconStickers {}
+
+impl Method for GetForumTopicIconStickers {
+    const NAME: &'static str = "getForumTopicIconStickers";
+    const IDEMPOTENT: bool = true;
+    type Output = Vec<Sticker>;
+}
@@ -0,0 +1,14 @@
+use crate::api::method::Method;
+use crate::api::types::user::User;
+use serde::Serialize;
+
+/// https://core.telegram.org/bots/api#getme
+/// A simple method for testing your bot's authentication token. Requires no parameters. Returns basic information about the bot in form of a User object.
+#[derive(Debug, Serialize, Default)]
+pub struct GetMe {}
+
+impl Method for GetMe {
+    const NAME: &'static str = "getMe";
+    const IDEMPOTENT: bool = true;
+    type Output = User;
+}
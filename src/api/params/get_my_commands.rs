@@ -1,4 +1,6 @@
 use crate::api::enums::bot_command_scope::BotCommandScope;
+use crate::api::method::Method;
+use crate::api::types::bot_command::BotCommand;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#getmycommands
@@ -10,3 +12,9 @@ pub struct GetMyCommands {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub language_code: Option<String>,
 }
+
+impl Method for GetMyCommands {
+    const NAME: &'static str = "getMyCommands";
+    const IDEMPOTENT: bool = true;
+    type Output = Vec<BotCommand>;
+}
@@ -1,3 +1,5 @@
+use crate::api::method::Method;
+use crate::api::types::chat_administrator_rights::ChatAdministratorRights;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#getmydefaultadministratorrights
@@ -7,3 +9,9 @@ pub struct GetMyDefaultAdministratorRights {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub for_channels: Option<bool>,
 }
+
+impl Method for GetMyDefaultAdministratorRights {
+    const NAME: &'static str = "getMyDefaultAdministratorRights";
+    const IDEMPOTENT: bool = true;
+    type Output = ChatAdministratorRights;
+}
@@ -1,3 +1,5 @@
+use crate::api::method::Method;
+use crate::api::types::bot_description::BotDescription;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#getmydescription
@@ -7,3 +9,9 @@ pub struct GetMyDescription {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub language_code: Option<String>,
 }
+
+impl Method for GetMyDescription {
+    const NAME: &'static str = "getMyDescription";
+    const IDEMPOTENT: bool = true;
+    type Output = BotDescription;
+}
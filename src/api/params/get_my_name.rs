@@ -1,3 +1,5 @@
+use crate::api::method::Method;
+use crate::api::types::bot_name::BotName;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#getmyname
@@ -7,3 +9,9 @@ pub struct GetMyName {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub language_code: Option<String>,
 }
+
+impl Method for GetMyName {
+    const NAME: &'static str = "getMyName";
+    const IDEMPOTENT: bool = true;
+    type Output = BotName;
+}
@@ -1,3 +1,5 @@
+use crate::api::method::Method;
+use crate::api::types::bot_short_description::BotShortDescription;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#getmyshortdescription
@@ -7,3 +9,9 @@ pub struct GetMyShortDescription {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub language_code: Option<String>,
 }
+
+impl Method for GetMyShortDescription {
+    const NAME: &'static str = "getMyShortDescription";
+    const IDEMPOTENT: bool = true;
+    type Output = BotShortDescription;
+}
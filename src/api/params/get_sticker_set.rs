@@ -1,3 +1,5 @@
+use crate::api::method::Method;
+use crate::api::types::sticker_set::StickerSet;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#getstickerset
@@ -6,3 +8,9 @@ use serde::Serialize;
 pub struct GetStickerSet {
     pub name: String,
 }
+
+impl Method for GetStickerSet {
+    const NAME: &'static str = "getStickerSet";
+    const IDEMPOTENT: bool = true;
+    type Output = StickerSet;
+}
@@ -1,3 +1,5 @@
+use crate::api::method::Method;
+use crate::api::types::update::Update;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#getupdates
@@ -10,3 +12,9 @@ pub struct GetUpdate {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub allowed_updates: Option<Vec<String>>,
 }
+
+impl Method for GetUpdate {
+    const NAME: &'static str = "getUpdates";
+    const IDEMPOTENT: bool = true;
+    type Output = Vec<Update>;
+}
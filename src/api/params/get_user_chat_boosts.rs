@@ -1,4 +1,6 @@
 use crate::api::enums::chat_uid::ChatUId;
+use crate::api::method::Method;
+use crate::api::types::user_chat_boosts::UserChatBoosts;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#getuserchatboosts
@@ -8,3 +10,9 @@ pub struct GetUserChatBoosts {
     pub chat_id: ChatUId,
     pub user_id: i64,
 }
+
+impl Method for GetUserChatBoosts {
+    const NAME: &'static str = "getUserChatBoosts";
+    const IDEMPOTENT: bool = true;
+    type Output = UserChatBoosts;
+}
@@ -1,3 +1,5 @@
+use crate::api::method::Method;
+use crate::api::types::user_profile_photos::UserProfilePhotos;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#getuserprofilephotos
@@ -10,3 +12,9 @@ pub struct GetUserProfilePhotos {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<i64>,
 }
+
+impl Method for GetUserProfilePhotos {
+    const NAME: &'static str = "getUserProfilePhotos";
+    const IDEMPOTENT: bool = true;
+    type Output = UserProfilePhotos;
+}
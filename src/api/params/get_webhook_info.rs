@@ -0,0 +1,14 @@
+use crate::api::method::Method;
+use crate::api::types::webhook_info::WebhookInfo;
+use serde::Serialize;
+
+/// https://core.telegram.org/bots/api#getwebhookinfo
+/// Use this method to get current webhook status. Requires no parameters. On success, returns a WebhookInfo object.
+#[derive(Debug, Serialize, Default)]
+pub struct GetWebhookInfo {}
+
+impl Method for GetWebhookInfo {
+    const NAME: &'static str = "getWebhookInfo";
+    const IDEMPOTENT: bool = true;
+    type Output = WebhookInfo;
+}
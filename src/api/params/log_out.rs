@@ -0,0 +1,12 @@
+use crate::api::method::Method;
+use serde::Serialize;
+
+/// https://core.telegram.org/bots/api#logout
+/// Use this method to log out from the cloud Bot API server before launching the bot locally. Returns True on success. Requires no parameters.
+#[derive(Debug, Serialize, Default)]
+pub struct LogOut {}
+
+impl Method for LogOut {
+    const NAME: &'static str = "logOut";
+    type Output = bool;
+}
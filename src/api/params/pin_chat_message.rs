@@ -1,4 +1,5 @@
 use crate::api::enums::chat_uid::ChatUId;
+use crate::api::method::Method;
 use crate::api::types::message_id::MessageId;
 use serde::Serialize;
 
@@ -12,3 +13,8 @@ pub struct PinChatMessage {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub disable_notification: Option<bool>,
 }
+
+impl Method for PinChatMessage {
+    const NAME: &'static str = "pinChatMessage";
+    type Output = bool;
+}
@@ -1,4 +1,5 @@
 use crate::api::enums::chat_uid::ChatUId;
+use crate::api::method::Method;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#promotechatmember
@@ -38,3 +39,8 @@ pub struct PromoteChatMember {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub can_manage_topics: Option<bool>,
 }
+
+impl Method for PromoteChatMember {
+    const NAME: &'static str = "promoteChatMember";
+    type Output = bool;
+}
@@ -1,4 +1,5 @@
 use crate::api::enums::chat_uid::ChatUId;
+use crate::api::method::Method;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#reopenforumtopic
@@ -8,3 +9,8 @@ pub struct ReopenForumTopic {
     pub chat_id: ChatUId,
     pub message_thread_id: i64,
 }
+
+impl Method for ReopenForumTopic {
+    const NAME: &'static str = "reopenForumTopic";
+    type Output = bool;
+}
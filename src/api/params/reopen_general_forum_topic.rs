@@ -1,4 +1,5 @@
 use crate::api::enums::chat_uid::ChatUId;
+use crate::api::method::Method;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#reopengeneralforumtopic
@@ -7,3 +8,8 @@ use serde::Serialize;
 pub struct ReopenGeneralForumTopic {
     pub chat_id: ChatUId,
 }
+
+impl Method for ReopenGeneralForumTopic {
+    const NAME: &'static str = "reopenGeneralForumTopic";
+    type Output = bool;
+}
@@ -1,4 +1,5 @@
 use crate::api::enums::chat_uid::ChatUId;
+use crate::api::method::Method;
 use crate::api::types::chat_permissions::ChatPermissions;
 use serde::Serialize;
 
@@ -14,3 +15,8 @@ pub struct RestrictChatMember {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub until_date: Option<i64>,
 }
+
+impl Method for RestrictChatMember {
+    const NAME: &'static str = "restrictChatMember";
+    type Output = bool;
+}
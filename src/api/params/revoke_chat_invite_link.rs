@@ -1,10 +1,17 @@
 use crate::api::enums::chat_uid::ChatUId;
+use crate::api::method::Method;
+use crate::api::types::chat_invite_link::ChatInviteLink;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#revokechatinvitelink
 /// Use this method to revoke an invite link created by the bot. If the primary link is revoked, a new link is automatically generated. The bot must be an administrator in the chat for this to work and must have the appropriate administrator rights. Returns the revoked invite link as ChatInviteLink object.
 #[derive(Debug, Serialize)]
-struct RevokeChatInviteLink {
+pub struct RevokeChatInviteLink {
     pub chat_id: ChatUId,
     pub invite_link: String,
 }
+
+impl Method for RevokeChatInviteLink {
+    const NAME: &'static str = "revokeChatInviteLink";
+    type Output = ChatInviteLink;
+}
@@ -1,6 +1,10 @@
 use crate::api::enums::chat_uid::ChatUId;
 use crate::api::enums::reply_markup::ReplyMarkup;
+use crate::api::method::Method;
+use crate::api::types::message::Message;
 use crate::api::types::reply_parameters::ReplyParameters;
+use crate::api::validate::{self, Validate};
+use crate::errors::Error;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#senddice
@@ -21,3 +25,14 @@ pub struct SendDice {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<ReplyMarkup>,
 }
+
+impl Method for SendDice {
+    const NAME: &'static str = "sendDice";
+    type Output = Message;
+}
+
+impl Validate for SendDice {
+    fn validate(&self) -> Result<(), Error> {
+        validate::nested("reply_markup", &self.reply_markup)
+    }
+}
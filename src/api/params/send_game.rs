@@ -1,6 +1,10 @@
 use crate::api::enums::chat_uid::ChatUId;
+use crate::api::method::Method;
 use crate::api::types::inline_keyboard_markup::InlineKeyboardMarkup;
+use crate::api::types::message::Message;
 use crate::api::types::reply_parameters::ReplyParameters;
+use crate::api::validate::{self, Validate};
+use crate::errors::Error;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#sendgame
@@ -20,3 +24,14 @@ pub struct SendGame {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<InlineKeyboardMarkup>,
 }
+
+impl Method for SendGame {
+    const NAME: &'static str = "sendGame";
+    type Output = Message;
+}
+
+impl Validate for SendGame {
+    fn validate(&self) -> Result<(), Error> {
+        validate::nested("reply_markup", &self.reply_markup)
+    }
+}
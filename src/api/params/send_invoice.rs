@@ -1,7 +1,11 @@
 use crate::api::enums::chat_uid::ChatUId;
+use crate::api::method::Method;
 use crate::api::types::inline_keyboard_markup::InlineKeyboardMarkup;
 use crate::api::types::labeled_price::LabeledPrice;
+use crate::api::types::message::Message;
 use crate::api::types::reply_parameters::ReplyParameters;
+use crate::api::validate::{self, Validate};
+use crate::errors::Error;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#sendinvoice
@@ -56,3 +60,18 @@ pub struct SendInvoice {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<InlineKeyboardMarkup>,
 }
+
+impl Method for SendInvoice {
+    const NAME: &'static str = "sendInvoice";
+    type Output = Message;
+}
+
+impl Validate for SendInvoice {
+    fn validate(&self) -> Result<(), Error> {
+        if self.prices.is_empty() {
+            return validate::fail("prices", String::from("must not be empty"));
+        }
+
+        validate::nested("reply_markup", &self.reply_markup)
+    }
+}
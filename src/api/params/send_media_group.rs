@@ -1,6 +1,10 @@
 use crate::api::enums::chat_uid::ChatUId;
 use crate::api::enums::media_input::MediaInput;
+use crate::api::method::Method;
+use crate::api::types::message::Message;
 use crate::api::types::reply_parameters::ReplyParameters;
+use crate::api::validate::{self, Validate};
+use crate::errors::Error;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#sendmediagroup
@@ -18,3 +22,15 @@ pub struct SendMediaGroup {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_parameters: Option<ReplyParameters>,
 }
+
+impl Method for SendMediaGroup {
+    const NAME: &'static str = "sendMediaGroup";
+    type Output = Vec<Message>;
+}
+
+impl Validate for SendMediaGroup {
+    fn validate(&self) -> Result<(), Error> {
+        validate::count("media", self.media.len(), 2, 10)?;
+        validate::nested("media", &self.media)
+    }
+}
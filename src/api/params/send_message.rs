@@ -1,20 +1,26 @@
 use crate::api::enums::chat_uid::ChatUId;
+use crate::api::enums::parse_mode::ParseMode;
 use crate::api::enums::reply_markup::ReplyMarkup;
+use crate::api::method::Method;
 use crate::api::types::link_preview_options::LinkPreviewOptions;
+use crate::api::types::message::Message;
 use crate::api::types::message_entity::MessageEntity;
 use crate::api::types::reply_parameters::ReplyParameters;
+use crate::api::validate::{self, Validate};
+use crate::errors::Error;
+use crate::format::split::TEXT_LIMIT;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#sendmessage
 /// Use this method to send text messages. On success, the sent Message is returned.
-#[derive(Debug, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Default)]
 pub struct SendMessage {
     pub chat_id: ChatUId,
     pub text: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub message_thread_id: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<String>,
+    pub parse_mode: Option<ParseMode>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub entities: Option<Vec<MessageEntity>>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -28,3 +34,18 @@ pub struct SendMessage {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<ReplyMarkup>,
 }
+
+impl Method for SendMessage {
+    const NAME: &'static str = "sendMessage";
+    type Output = Message;
+}
+
+impl Validate for SendMessage {
+    fn validate(&self) -> Result<(), Error> {
+        if self.parse_mode.is_none() {
+            validate::length("text", &self.text, 1, TEXT_LIMIT)?;
+        }
+
+        validate::nested("reply_markup", &self.reply_markup)
+    }
+}
@@ -1,8 +1,13 @@
 use crate::api::enums::chat_uid::ChatUId;
 use crate::api::enums::file_input::FileInput;
+use crate::api::enums::parse_mode::ParseMode;
 use crate::api::enums::reply_markup::ReplyMarkup;
+use crate::api::method::Method;
+use crate::api::types::message::Message;
 use crate::api::types::message_entity::MessageEntity;
 use crate::api::types::reply_parameters::ReplyParameters;
+use crate::api::validate::{self, Validate};
+use crate::errors::Error;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#sendphoto
@@ -16,7 +21,7 @@ pub struct SendPhoto {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub caption: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<String>,
+    pub parse_mode: Option<ParseMode>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub caption_entities: Option<Vec<MessageEntity>>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -30,3 +35,16 @@ pub struct SendPhoto {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<ReplyMarkup>,
 }
+
+impl Method for SendPhoto {
+    const NAME: &'static str = "sendPhoto";
+    type Output = Message;
+}
+
+impl Validate for SendPhoto {
+    fn validate(&self) -> Result<(), Error> {
+        validate::caption("caption", &self.caption, self.parse_mode)?;
+
+        validate::nested("reply_markup", &self.reply_markup)
+    }
+}
@@ -1,7 +1,12 @@
 use crate::api::enums::chat_uid::ChatUId;
+use crate::api::enums::parse_mode::ParseMode;
 use crate::api::enums::reply_markup::ReplyMarkup;
+use crate::api::method::Method;
+use crate::api::types::message::Message;
 use crate::api::types::message_entity::MessageEntity;
 use crate::api::types::reply_parameters::ReplyParameters;
+use crate::api::validate::{self, Validate};
+use crate::errors::Error;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#sendpoll
@@ -24,7 +29,7 @@ pub struct SendPoll {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub explanation: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub explanation_parse_mode: Option<String>,
+    pub explanation_parse_mode: Option<ParseMode>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub explanation_entities: Option<Vec<MessageEntity>>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -42,3 +47,20 @@ pub struct SendPoll {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<ReplyMarkup>,
 }
+
+impl Method for SendPoll {
+    const NAME: &'static str = "sendPoll";
+    type Output = Message;
+}
+
+impl Validate for SendPoll {
+    fn validate(&self) -> Result<(), Error> {
+        validate::length("question", &self.question, 1, 300)?;
+        validate::count("options", self.options.len(), 2, 10)?;
+        for (index, option) in self.options.iter().enumerate() {
+            validate::length(&format!("options[{}]", index), option, 1, 100)?;
+        }
+
+        validate::nested("reply_markup", &self.reply_markup)
+    }
+}
@@ -1,4 +1,5 @@
 use crate::api::enums::chat_uid::ChatUId;
+use crate::api::method::Method;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#setchatadministratorcustomtitle
@@ -9,3 +10,8 @@ pub struct SetChatAdministratorCustomTitle {
     pub user_id: i64,
     pub custom_title: String,
 }
+
+impl Method for SetChatAdministratorCustomTitle {
+    const NAME: &'static str = "setChatAdministratorCustomTitle";
+    type Output = bool;
+}
@@ -1,4 +1,5 @@
 use crate::api::enums::chat_uid::ChatUId;
+use crate::api::method::Method;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#setchatdescription
@@ -9,3 +10,8 @@ pub struct SetChatDescription {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
 }
+
+impl Method for SetChatDescription {
+    const NAME: &'static str = "setChatDescription";
+    type Output = bool;
+}
@@ -1,5 +1,6 @@
 use crate::api::enums::chat_uid::ChatUId;
 use crate::api::enums::menu_button::MenuButton;
+use crate::api::method::Method;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#setchatmenubutton
@@ -11,3 +12,8 @@ pub struct SetChatMenuButton {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub menu_button: Option<MenuButton>,
 }
+
+impl Method for SetChatMenuButton {
+    const NAME: &'static str = "setChatMenuButton";
+    type Output = bool;
+}
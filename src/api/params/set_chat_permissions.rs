@@ -1,4 +1,5 @@
 use crate::api::enums::chat_uid::ChatUId;
+use crate::api::method::Method;
 use crate::api::types::chat_permissions::ChatPermissions;
 use serde::Serialize;
 
@@ -11,3 +12,8 @@ pub struct SetChatPermissions {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_independent_chat_permissions: Option<bool>,
 }
+
+impl Method for SetChatPermissions {
+    const NAME: &'static str = "setChatPermissions";
+    type Output = bool;
+}
@@ -1,4 +1,5 @@
 use crate::api::enums::chat_uid::ChatUId;
+use crate::api::method::Method;
 use crate::api::types::input_file::InputFile;
 use serde::Serialize;
 
@@ -9,3 +10,8 @@ pub struct SetChatPhoto {
     pub chat_id: ChatUId,
     pub photo: InputFile,
 }
+
+impl Method for SetChatPhoto {
+    const NAME: &'static str = "setChatPhoto";
+    type Output = bool;
+}
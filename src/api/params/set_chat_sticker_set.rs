@@ -1,4 +1,5 @@
 use crate::api::enums::chat_uid::ChatUId;
+use crate::api::method::Method;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#setchatstickerset
@@ -8,3 +9,8 @@ pub struct SetChatStickerSet {
     pub chat_id: ChatUId,
     pub sticker_set_name: String,
 }
+
+impl Method for SetChatStickerSet {
+    const NAME: &'static str = "setChatStickerSet";
+    type Output = bool;
+}
@@ -1,4 +1,5 @@
 use crate::api::enums::chat_uid::ChatUId;
+use crate::api::method::Method;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#setchattitle
@@ -8,3 +9,8 @@ pub struct SetChatTitle {
     pub chat_id: ChatUId,
     pub title: String,
 }
+
+impl Method for SetChatTitle {
+    const NAME: &'static str = "setChatTitle";
+    type Output = bool;
+}
@@ -1,3 +1,4 @@
+use crate::api::method::Method;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#setcustomemojistickersetthumbnail
@@ -8,3 +9,8 @@ pub struct SetCustomEmojiStickerSetThumbnail {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub custom_emoji_id: Option<String>,
 }
+
+impl Method for SetCustomEmojiStickerSetThumbnail {
+    const NAME: &'static str = "setCustomEmojiStickerSetThumbnail";
+    type Output = bool;
+}
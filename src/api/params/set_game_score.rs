@@ -1,4 +1,6 @@
 use crate::api::enums::chat_uid::ChatUId;
+use crate::api::enums::message_or_bool::MessageOrBool;
+use crate::api::method::Method;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#setgamescore
@@ -13,3 +15,8 @@ pub struct SetGameScore {
     pub message_id: Option<i64>,
     pub inline_message_id: Option<String>,
 }
+
+impl Method for SetGameScore {
+    const NAME: &'static str = "setGameScore";
+    type Output = MessageOrBool;
+}
@@ -1,5 +1,6 @@
 use crate::api::enums::chat_uid::ChatUId;
 use crate::api::enums::reaction_type::ReactionType;
+use crate::api::method::Method;
 use crate::api::types::message_id::MessageId;
 use serde::Serialize;
 
@@ -15,3 +16,8 @@ pub struct SetMessageReaction {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub is_big: Option<bool>,
 }
+
+impl Method for SetMessageReaction {
+    const NAME: &'static str = "setMessageReaction";
+    type Output = bool;
+}
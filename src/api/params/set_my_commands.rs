@@ -1,5 +1,8 @@
 use crate::api::enums::bot_command_scope::BotCommandScope;
+use crate::api::method::Method;
 use crate::api::types::bot_command::BotCommand;
+use crate::api::validate::{self, Validate};
+use crate::errors::Error;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#setmycommands
@@ -12,3 +15,16 @@ pub struct SetMyCommands {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub language_code: Option<String>,
 }
+
+impl Method for SetMyCommands {
+    const NAME: &'static str = "setMyCommands";
+    type Output = bool;
+}
+
+impl Validate for SetMyCommands {
+    fn validate(&self) -> Result<(), Error> {
+        validate::count("commands", self.commands.len(), 0, 100)?;
+
+        validate::nested("commands", &self.commands)
+    }
+}
@@ -1,3 +1,4 @@
+use crate::api::method::Method;
 use crate::api::types::chat_administrator_rights::ChatAdministratorRights;
 use serde::Serialize;
 
@@ -10,3 +11,8 @@ pub struct SetMyDefaultAdministratorRights {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub for_channels: Option<bool>,
 }
+
+impl Method for SetMyDefaultAdministratorRights {
+    const NAME: &'static str = "setMyDefaultAdministratorRights";
+    type Output = bool;
+}
@@ -1,3 +1,4 @@
+use crate::api::method::Method;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#setmyname
@@ -9,3 +10,8 @@ pub struct SetMyName {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub language_code: Option<String>,
 }
+
+impl Method for SetMyName {
+    const NAME: &'static str = "setMyName";
+    type Output = bool;
+}
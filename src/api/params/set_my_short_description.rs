@@ -1,3 +1,4 @@
+use crate::api::method::Method;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#setmyshortdescription
@@ -9,3 +10,8 @@ pub struct SetMyShortDescription {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub language_code: Option<String>,
 }
+
+impl Method for SetMyShortDescription {
+    const NAME: &'static str = "setMyShortDescription";
+    type Output = bool;
+}
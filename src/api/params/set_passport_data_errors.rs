@@ -1,4 +1,7 @@
 use crate::api::enums::passport_element_error::PassportElementError;
+use crate::api::method::Method;
+use crate::api::validate::{self, Validate};
+use crate::errors::Error;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#setpassportdataerrors
@@ -9,3 +12,14 @@ pub struct SetPassportDataErrors {
     pub user_id: i64,
     pub errors: Vec<PassportElementError>,
 }
+
+impl Method for SetPassportDataErrors {
+    const NAME: &'static str = "setPassportDataErrors";
+    type Output = bool;
+}
+
+impl Validate for SetPassportDataErrors {
+    fn validate(&self) -> Result<(), Error> {
+        validate::nested("errors", &self.errors)
+    }
+}
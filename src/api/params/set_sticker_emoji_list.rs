@@ -1,3 +1,4 @@
+use crate::api::method::Method;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#setstickeremojilist
@@ -7,3 +8,8 @@ pub struct SetStickerEmojiList {
     pub sticker: String,
     pub emoji_list: Vec<String>,
 }
+
+impl Method for SetStickerEmojiList {
+    const NAME: &'static str = "setStickerEmojiList";
+    type Output = bool;
+}
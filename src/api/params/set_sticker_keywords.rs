@@ -1,3 +1,4 @@
+use crate::api::method::Method;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#setstickerkeywords
@@ -8,3 +9,8 @@ pub struct SetStickerKeywords {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub keywords: Option<Vec<String>>,
 }
+
+impl Method for SetStickerKeywords {
+    const NAME: &'static str = "setStickerKeywords";
+    type Output = bool;
+}
@@ -1,3 +1,4 @@
+use crate::api::method::Method;
 use crate::api::types::mask_position::MaskPosition;
 use serde::Serialize;
 
@@ -9,3 +10,8 @@ pub struct SetStickerMaskPosition {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub mask_position: Option<MaskPosition>,
 }
+
+impl Method for SetStickerMaskPosition {
+    const NAME: &'static str = "setStickerMaskPosition";
+    type Output = bool;
+}
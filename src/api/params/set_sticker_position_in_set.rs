@@ -1,3 +1,4 @@
+use crate::api::method::Method;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#setstickerpositioninset
@@ -7,3 +8,8 @@ pub struct SetStickerPositionInSet {
     pub sticker: String,
     pub position: i64,
 }
+
+impl Method for SetStickerPositionInSet {
+    const NAME: &'static str = "setStickerPositionInSet";
+    type Output = bool;
+}
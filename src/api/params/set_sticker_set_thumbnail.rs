@@ -1,4 +1,5 @@
 use crate::api::enums::file_input::FileInput;
+use crate::api::method::Method;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#setstickersetthumbnail
@@ -10,3 +11,8 @@ pub struct SetStickerSetThumbnail {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub thumbnail: Option<FileInput>,
 }
+
+impl Method for SetStickerSetThumbnail {
+    const NAME: &'static str = "setStickerSetThumbnail";
+    type Output = bool;
+}
@@ -1,3 +1,4 @@
+use crate::api::method::Method;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#setstickersettitle
@@ -7,3 +8,8 @@ pub struct SetStickerSetTitle {
     pub name: String,
     pub title: String,
 }
+
+impl Method for SetStickerSetTitle {
+    const NAME: &'static str = "setStickerSetTitle";
+    type Output = bool;
+}
@@ -1,6 +1,6 @@
-use serde::Serialize;
-
+use crate::api::method::Method;
 use crate::api::types::input_file::InputFile;
+use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#setwebhook
 /// Use this method to specify a URL and receive incoming updates via an outgoing webhook. Whenever there is an update for the bot, we will send an HTTPS POST request to the specified URL, containing a JSON-serialized Update. In case of an unsuccessful request, we will give up after a reasonable amount of attempts. Returns True on success.
@@ -21,3 +21,8 @@ pub struct SetWebhook {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub secret_token: Option<String>,
 }
+
+impl Method for SetWebhook {
+    const NAME: &'static str = "setWebhook";
+    type Output = bool;
+}
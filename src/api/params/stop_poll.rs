@@ -1,6 +1,10 @@
 use crate::api::enums::chat_uid::ChatUId;
+use crate::api::method::Method;
 use crate::api::types::inline_keyboard_markup::InlineKeyboardMarkup;
 use crate::api::types::message_id::MessageId;
+use crate::api::types::poll::Poll;
+use crate::api::validate::{self, Validate};
+use crate::errors::Error;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#stoppoll
@@ -13,3 +17,14 @@ pub struct StopPoll {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<InlineKeyboardMarkup>,
 }
+
+impl Method for StopPoll {
+    const NAME: &'static str = "stopPoll";
+    type Output = Poll;
+}
+
+impl Validate for StopPoll {
+    fn validate(&self) -> Result<(), Error> {
+        validate::nested("reply_markup", &self.reply_markup)
+    }
+}
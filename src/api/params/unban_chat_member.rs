@@ -1,4 +1,5 @@
 use crate::api::enums::chat_uid::ChatUId;
+use crate::api::method::Method;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#unbanchatmember
@@ -10,3 +11,8 @@ pub struct UnbanChatMember {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub only_if_banned: Option<bool>,
 }
+
+impl Method for UnbanChatMember {
+    const NAME: &'static str = "unbanChatMember";
+    type Output = bool;
+}
@@ -1,4 +1,5 @@
 use crate::api::enums::chat_uid::ChatUId;
+use crate::api::method::Method;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#unbanchatsenderchat
@@ -8,3 +9,8 @@ pub struct UnbanChatSenderChat {
     pub chat_id: ChatUId,
     pub sender_chat_id: ChatUId,
 }
+
+impl Method for UnbanChatSenderChat {
+    const NAME: &'static str = "unbanChatSenderChat";
+    type Output = bool;
+}
@@ -1,4 +1,5 @@
 use crate::api::enums::chat_uid::ChatUId;
+use crate::api::method::Method;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#unpinallforumtopicmessages
@@ -8,3 +9,8 @@ pub struct UnpinAllForumTopicMessages {
     pub chat_id: ChatUId,
     pub message_thread_id: i64,
 }
+
+impl Method for UnpinAllForumTopicMessages {
+    const NAME: &'static str = "unpinAllForumTopicMessages";
+    type Output = bool;
+}
@@ -1,4 +1,5 @@
 use crate::api::enums::chat_uid::ChatUId;
+use crate::api::method::Method;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#unpinallgeneralforumtopicmessages
@@ -7,3 +8,8 @@ use serde::Serialize;
 pub struct UnpinAllGeneralForumTopicMessages {
     pub chat_id: ChatUId,
 }
+
+impl Method for UnpinAllGeneralForumTopicMessages {
+    const NAME: &'static str = "unpinAllGeneralForumTopicMessages";
+    type Output = bool;
+}
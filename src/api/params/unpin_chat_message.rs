@@ -1,4 +1,5 @@
 use crate::api::enums::chat_uid::ChatUId;
+use crate::api::method::Method;
 use serde::Serialize;
 
 /// https://core.telegram.org/bots/api#unpinchatmessage
@@ -9,3 +10,8 @@ pub struct UnpinChatMessage {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub message_id: Option<i64>,
 }
+
+impl Method for UnpinChatMessage {
+    const NAME: &'static str = "unpinChatMessage";
+    type Output = bool;
+}
@@ -1,3 +1,5 @@
+use crate::api::method::Method;
+use crate::api::types::file::File;
 use crate::api::types::input_file::InputFile;
 use serde::Serialize;
 
@@ -9,3 +11,8 @@ pub struct UploadStickerFile {
     pub sticker: InputFile,
     pub sticker_format: String,
 }
+
+impl Method for UploadStickerFile {
+    const NAME: &'static str = "uploadStickerFile";
+    type Output = File;
+}
@@ -3,9 +3,12 @@ use crate::api::params::copy_messages::CopyMessages;
 use crate::api::params::delete_webhook::DeleteWebhook;
 use crate::api::params::forward_message::ForwardMessage;
 use crate::api::params::forward_messages::ForwardMessages;
+use crate::api::params::get_file::GetFile;
 use crate::api::params::get_update::GetUpdate;
 use crate::api::params::send_message::SendMessage;
+use crate::api::params::set_passport_data_errors::SetPassportDataErrors;
 use crate::api::params::set_webhook::SetWebhook;
+use crate::api::types::file::File;
 use crate::api::types::message::Message;
 use crate::api::types::message_id::MessageId;
 use crate::api::types::update::Update;
@@ -49,7 +52,7 @@ pub trait Requests {
 
     /// https://core.telegram.org/bots/api#forwardmessage
     /// Use this method to forward messages of any kind. Service messages and messages with protected content can't be forwarded. On success, the sent Message is returned.
-    fn forward_message(&self, params: &ForwardMessage) -> Result<MessageId, Error>;
+    fn forward_message(&self, params: &ForwardMessage) -> Result<Message, Error>;
 
     /// https://core.telegram.org/bots/api#forwardmessages
     /// Use this method to forward multiple messages of any kind. If some of the specified messages can't be found or forwarded, they are skipped. Service messages and messages with protected content can't be forwarded. Album grouping is kept for forwarded messages. On success, an array of MessageId of the sent messages is returned.
@@ -127,9 +130,9 @@ pub trait Requests {
     // Use this method to get a list of profile pictures for a user. Returns a UserProfilePhotos object.
     // fn get_user_profile_photos(&self)
 
-    // https://core.telegram.org/bots/api#getfile
-    // Use this method to get basic information about a file and prepare it for downloading. For the moment, bots can download files of up to 20MB in size. On success, a File object is returned. The file can then be downloaded via the link https://api.telegram.org/file/bot<token>/<file_path>, where <file_path> is taken from the response. It is guaranteed that the link will be valid for at least 1 hour. When the link expires, a new one can be requested by calling getFile again.
-    // fn get_file(&self)
+    /// https://core.telegram.org/bots/api#getfile
+    /// Use this method to get basic information about a file and prepare it for downloading. For the moment, bots can download files of up to 20MB in size. On success, a File object is returned. The file can then be downloaded via the link https://api.telegram.org/file/bot<token>/<file_path>, where <file_path> is taken from the response. It is guaranteed that the link will be valid for at least 1 hour. When the link expires, a new one can be requested by calling getFile again.
+    fn get_file(&self, params: &GetFile) -> Result<File, Error>;
 
     // https://core.telegram.org/bots/api#banchatmember
     // Use this method to ban a user in a group, a supergroup or a channel. In the case of supergroups and channels, the user will not be able to return to the chat on their own using invite links, etc., unless unbanned first. The bot must be an administrator in the chat for this to work and must have the appropriate administrator rights. Returns True on success.
@@ -485,10 +488,10 @@ pub trait Requests {
     // Once the user has confirmed their payment and shipping details, the Bot API sends the final confirmation in the form of an Update with the field pre_checkout_query. Use this method to respond to such pre-checkout queries. On success, True is returned. Note: The Bot API must receive an answer within 10 seconds after the pre-checkout query was sent.
     // fn answer_pre_checkout_query(&self)
 
-    // https://core.telegram.org/bots/api#setpassportdataerrors
-    // Informs a user that some of the Telegram Passport elements they provided contains errors. The user will not be able to re-submit their Passport to you until the errors are fixed (the contents of the field for which you returned the error must change). Returns True on success.
-    // Use this if the data submitted by the user doesn't satisfy the standards your service requires for any reason. For example, if a birthday date seems invalid, a submitted document is blurry, a scan shows evidence of tampering, etc. Supply some details in the error message to make sure the user knows how to correct the issues.
-    // fn set_passport_data_errors(&self)
+    /// https://core.telegram.org/bots/api#setpassportdataerrors
+    /// Informs a user that some of the Telegram Passport elements they provided contains errors. The user will not be able to re-submit their Passport to you until the errors are fixed (the contents of the field for which you returned the error must change). Returns True on success.
+    /// Use this if the data submitted by the user doesn't satisfy the standards your service requires for any reason. For example, if a birthday date seems invalid, a submitted document is blurry, a scan shows evidence of tampering, etc. Supply some details in the error message to make sure the user knows how to correct the issues.
+    fn set_passport_data_errors(&self, params: &SetPassportDataErrors) -> Result<bool, Error>;
 
     // https://core.telegram.org/bots/api#sendgame
     // Use this method to send a game. On success, the sent Message is returned.
@@ -11,7 +11,26 @@ pub struct ResponseError {
 }
 
 impl ResponseError {
-    pub fn new(body: &str) -> Self {
-        serde_json::from_str::<ResponseError>(body).unwrap()
+    /// Builds the error from a failed response. Bodies that are not a Bot API error object
+    /// (e.g. an HTML page from a proxy) are kept as the description with the HTTP status as the code.
+    pub fn new(status: u16, body: &str) -> Self {
+        serde_json::from_str::<ResponseError>(body).unwrap_or_else(|_| Self {
+            ok: false,
+            error_code: u64::from(status),
+            description: body.trim().to_string(),
+            parameters: None,
+        })
+    }
+
+    pub fn retry_after(&self) -> Option<i64> {
+        self.parameters
+            .as_ref()
+            .and_then(|parameters| parameters.retry_after)
+    }
+
+    pub fn migrate_to_chat_id(&self) -> Option<i64> {
+        self.parameters
+            .as_ref()
+            .and_then(|parameters| parameters.migrate_to_chat_id)
     }
 }
@@ -38,12 +38,15 @@ pub mod chat_photo;
 pub mod chat_shared;
 pub mod chosen_inline_result;
 pub mod contact;
+pub mod credentials;
+pub mod data_credentials;
 pub mod dice;
 pub mod document;
 pub mod encrypted_credentials;
 pub mod encrypted_passport_element;
 pub mod external_reply_info;
 pub mod file;
+pub mod file_credentials;
 pub mod force_reply;
 pub mod forum_topic;
 pub mod forum_topic_closed;
@@ -58,6 +61,7 @@ pub mod giveaway;
 pub mod giveaway_completed;
 pub mod giveaway_created;
 pub mod giveaway_winners;
+pub mod id_document_data;
 pub mod inaccessible_message;
 pub mod inline_keyboard_button;
 pub mod inline_keyboard_markup;
@@ -103,6 +107,7 @@ pub mod keyboard_button_request_users;
 pub mod labeled_price;
 pub mod link_preview_options;
 pub mod location;
+pub mod login_data;
 pub mod login_url;
 pub mod mask_position;
 pub mod menu_button_commands;
@@ -130,6 +135,7 @@ pub mod passport_element_error_translation_file;
 pub mod passport_element_error_translation_files;
 pub mod passport_element_error_unspecified;
 pub mod passport_file;
+pub mod personal_details;
 pub mod photo_size;
 pub mod poll;
 pub mod poll_answer;
@@ -142,6 +148,9 @@ pub mod reaction_type_emoji;
 pub mod reply_keyboard_markup;
 pub mod reply_keyboard_remove;
 pub mod reply_parameters;
+pub mod residential_address;
+pub mod secure_data;
+pub mod secure_value;
 pub mod sent_web_app_message;
 pub mod shipping_address;
 pub mod shipping_option;
@@ -165,7 +174,10 @@ pub mod video_chat_scheduled;
 pub mod video_chat_started;
 pub mod video_note;
 pub mod voice;
+pub mod web_app_chat;
 pub mod web_app_data;
 pub mod web_app_info;
+pub mod web_app_init_data;
+pub mod web_app_user;
 pub mod webhook_info;
 pub mod write_access_allowed;
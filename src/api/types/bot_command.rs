@@ -1,3 +1,6 @@
+use crate::api::validate;
+use crate::api::validate::Validate;
+use crate::errors::Error;
 use serde::{Deserialize, Serialize};
 
 /// https://core.telegram.org/bots/api#botcommand
@@ -7,3 +10,21 @@ pub struct BotCommand {
     pub command: String,
     pub description: String,
 }
+
+impl Validate for BotCommand {
+    fn validate(&self) -> Result<(), Error> {
+        let valid = self
+            .command
+            .chars()
+            .all(|char| matches!(char, 'a'..='z' | '0'..='9' | '_'));
+        if !valid {
+            return validate::fail(
+                "command",
+                String::from("must contain only lowercase English letters, digits and underscores"),
+            );
+        }
+
+        validate::length("command", &self.command, 1, 32)?;
+        validate::length("description", &self.description, 1, 256)
+    }
+}
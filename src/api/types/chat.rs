@@ -4,6 +4,7 @@ use crate::api::types::chat_permissions::ChatPermissions;
 use crate::api::types::chat_photo::ChatPhoto;
 use crate::api::types::message::Message;
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
 
 /// https://core.telegram.org/bots/api#chat
 /// This object represents a chat.
@@ -78,4 +79,7 @@ pub struct Chat {
     pub linked_chat_id: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub location: Option<ChatLocation>,
+    /// Fields unknown to this version of the crate, e.g. added to the chat by a newer Bot API.
+    #[serde(flatten, skip_serializing_if = "Map::is_empty")]
+    pub extra: Map<String, Value>,
 }
@@ -0,0 +1,10 @@
+use crate::api::types::secure_data::SecureData;
+use serde::{Deserialize, Serialize};
+
+/// https://core.telegram.org/passport#credentials
+/// Credentials decrypted from EncryptedCredentials, needed to decrypt the passport elements.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Credentials {
+    pub secure_data: SecureData,
+    pub nonce: String,
+}
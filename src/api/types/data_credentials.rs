@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+/// https://core.telegram.org/passport#datacredentials
+/// These credentials can be used to decrypt encrypted data from the data field in EncryptedPassportElement.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DataCredentials {
+    pub data_hash: String,
+    pub secret: String,
+}
@@ -7,6 +7,6 @@ use serde::{Deserialize, Serialize};
 pub struct File {
     pub file_id: String,
     pub file_unique_id: String,
-    pub file_size: i64,
-    pub file_path: String,
+    pub file_size: Option<i64>,
+    pub file_path: Option<String>,
 }
@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+/// https://core.telegram.org/passport#filecredentials
+/// These credentials can be used to decrypt encrypted files from the front_side, reverse_side, selfie, files and translation fields in EncryptedPassportElement.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FileCredentials {
+    pub file_hash: String,
+    pub secret: String,
+}
@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+/// https://core.telegram.org/passport#iddocumentdata
+/// This object represents the data of an identity document.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct IdDocumentData {
+    pub document_no: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expiry_date: Option<String>,
+}
@@ -1,5 +1,4 @@
 use crate::api::types::chat::Chat;
-use crate::api::types::message_id::MessageId;
 use serde::{Deserialize, Serialize};
 
 /// https://core.telegram.org/bots/api#inaccessiblemessage
@@ -7,6 +6,6 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct InaccessibleMessage {
     pub chat: Chat,
-    pub message_id: MessageId,
+    pub message_id: i64,
     pub date: i64,
 }
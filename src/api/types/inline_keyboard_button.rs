@@ -2,11 +2,14 @@ use crate::api::types::callback_game::CallbackGame;
 use crate::api::types::login_url::LoginUrl;
 use crate::api::types::switch_inline_query_chosen_chat::SwitchInlineQueryChosenChat;
 use crate::api::types::web_app_info::WebAppInfo;
+use crate::api::validate;
+use crate::api::validate::Validate;
+use crate::errors::Error;
 use serde::{Deserialize, Serialize};
 
 /// https://core.telegram.org/bots/api#inlinekeyboardbutton
 /// This object represents one button of an inline keyboard. You must use exactly one of the optional fields.
-#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct InlineKeyboardButton {
     pub text: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -28,3 +31,12 @@ pub struct InlineKeyboardButton {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub pay: Option<bool>,
 }
+
+impl Validate for InlineKeyboardButton {
+    fn validate(&self) -> Result<(), Error> {
+        match &self.callback_data {
+            Some(data) => validate::bytes("callback_data", data, 1, 64),
+            None => Ok(()),
+        }
+    }
+}
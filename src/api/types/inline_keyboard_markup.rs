@@ -1,9 +1,18 @@
 use crate::api::types::inline_keyboard_button::InlineKeyboardButton;
+use crate::api::validate;
+use crate::api::validate::Validate;
+use crate::errors::Error;
 use serde::{Deserialize, Serialize};
 
 /// https://core.telegram.org/bots/api#inlinekeyboardmarkup
 /// This object represents an inline keyboard that appears right next to the message it belongs to.
-#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct InlineKeyboardMarkup {
-    pub inline_keyboard: Vec<InlineKeyboardButton>,
+    pub inline_keyboard: Vec<Vec<InlineKeyboardButton>>,
+}
+
+impl Validate for InlineKeyboardMarkup {
+    fn validate(&self) -> Result<(), Error> {
+        validate::nested("inline_keyboard", &self.inline_keyboard)
+    }
 }
@@ -1,4 +1,5 @@
 use crate::api::enums::input_message_content::InputMessageContent;
+use crate::api::enums::parse_mode::ParseMode;
 use crate::api::types::inline_keyboard_markup::InlineKeyboardMarkup;
 use crate::api::types::message_entity::MessageEntity;
 use serde::{Deserialize, Serialize};
@@ -15,7 +16,7 @@ pub struct InlineQueryResultAudio {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub caption: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<String>,
+    pub parse_mode: Option<ParseMode>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub caption_entities: Option<Vec<MessageEntity>>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1,3 +1,4 @@
+use crate::api::enums::parse_mode::ParseMode;
 use crate::api::types::link_preview_options::LinkPreviewOptions;
 use crate::api::types::message_entity::MessageEntity;
 use serde::{Deserialize, Serialize};
@@ -8,7 +9,7 @@ use serde::{Deserialize, Serialize};
 pub struct InputTextMessageContent {
     pub message_text: String,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<String>,
+    pub parse_mode: Option<ParseMode>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub entities: Option<Vec<MessageEntity>>,
     #[serde(skip_serializing_if = "Option::is_none")]
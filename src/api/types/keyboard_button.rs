@@ -6,7 +6,7 @@ use serde::{Deserialize, Serialize};
 
 /// https://core.telegram.org/bots/api#keyboardbutton
 /// This object represents one button of the reply keyboard. For simple text buttons, String can be used instead of this object to specify the button text. The optional fields web_app, request_users, request_chat, request_contact, request_location, and request_poll are mutually exclusive.
-#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct KeyboardButton {
     pub text: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3,7 +3,7 @@ use serde::{Deserialize, Serialize};
 
 /// https://core.telegram.org/bots/api#keyboardbuttonrequestchat
 /// This object defines the criteria used to request a suitable chat. The identifier of the selected chat will be shared with the bot when the corresponding button is pressed.
-#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct KeyboardButtonRequestChat {
     pub request_id: i64,
     pub chat_is_channel: bool,
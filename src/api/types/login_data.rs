@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+
+/// https://core.telegram.org/widgets/login#receiving-authorization-data
+/// Data of a user authorized by the Telegram Login Widget or a `LoginUrl` button.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LoginData {
+    pub id: i64,
+    pub first_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_url: Option<String>,
+    pub auth_date: i64,
+    pub hash: String,
+}
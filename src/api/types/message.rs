@@ -46,7 +46,10 @@ use crate::api::types::video_note::VideoNote;
 use crate::api::types::voice::Voice;
 use crate::api::types::web_app_data::WebAppData;
 use crate::api::types::write_access_allowed::WriteAccessAllowed;
+use crate::deep_link;
+use crate::format::{entities, Text};
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
 
 /// https://core.telegram.org/bots/api#message
 /// This object represents a message.
@@ -198,4 +201,42 @@ pub struct Message {
     pub web_app_data: Option<WebAppData>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<InlineKeyboardMarkup>,
+    /// Fields unknown to this version of the crate, e.g. a kind of content added by a newer Bot API.
+    #[serde(flatten, skip_serializing_if = "Map::is_empty")]
+    pub extra: Map<String, Value>,
+}
+
+impl Message {
+    /// Entities of the text, or of the caption of a media message, with the part each one covers.
+    pub fn parse_entities(&self) -> Vec<(&MessageEntity, &str)> {
+        match self.text_and_entities() {
+            Some((text, entities)) => entities::parse(text, entities).collect(),
+            None => vec![],
+        }
+    }
+
+    /// Text, or caption of a media message, with its formatting, to be rendered back to HTML or
+    /// MarkdownV2 with `to_html` and `to_markdown_v2`.
+    pub fn formatted_text(&self) -> Option<Text> {
+        let (text, entities) = self.text_and_entities()?;
+
+        Some(Text::from_entities(text, entities))
+    }
+
+    /// Payload of a `/start` command sent from a deep link, e.g. `ref_42` of `/start ref_42`, to
+    /// route the message to the handler of a campaign. `bot` is the username of the bot, see
+    /// `deep_link::start_payload`.
+    pub fn start_payload(&self, bot: &str) -> Option<&str> {
+        deep_link::start_payload(self.text.as_deref()?, bot)
+    }
+
+    fn text_and_entities(&self) -> Option<(&str, &[MessageEntity])> {
+        let (text, entities) = match (&self.text, &self.caption) {
+            (Some(text), _) => (text, &self.entities),
+            (None, Some(caption)) => (caption, &self.caption_entities),
+            (None, None) => return None,
+        };
+
+        Some((text, entities.as_deref().unwrap_or_default()))
+    }
 }
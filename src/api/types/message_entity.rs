@@ -1,12 +1,15 @@
+use crate::api::enums::message_entity_kind::MessageEntityKind;
 use crate::api::types::user::User;
+use crate::format::utf16;
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
 
 /// https://core.telegram.org/bots/api#messageentity
 /// This object represents one special entity in a text message. For example, hashtags, usernames, URLs, etc.
-#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct MessageEntity {
     #[serde(rename(serialize = "type", deserialize = "type"))]
-    pub kind: String,
+    pub kind: MessageEntityKind,
     pub offset: i64,
     pub length: i64,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -17,4 +20,41 @@ pub struct MessageEntity {
     pub language: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub custom_emoji_id: Option<String>,
+    /// Fields unknown to this version of the crate, e.g. added to the entity by a newer Bot API.
+    #[serde(flatten, skip_serializing_if = "Map::is_empty")]
+    pub extra: Map<String, Value>,
+}
+
+impl MessageEntity {
+    pub fn new(kind: MessageEntityKind, offset: i64, length: i64) -> Self {
+        Self {
+            kind,
+            offset,
+            length,
+            url: None,
+            user: None,
+            language: None,
+            custom_emoji_id: None,
+            extra: Map::new(),
+        }
+    }
+
+    /// UTF-16 offset right after the entity.
+    pub fn end(&self) -> i64 {
+        self.offset + self.length
+    }
+
+    /// Part of the text the entity covers, `None` if it is out of the bounds of the text.
+    pub fn text<'a>(&self, text: &'a str) -> Option<&'a str> {
+        utf16::slice(text, self.offset, self.length)
+    }
+
+    /// Whether both entities are of the same kind with the same url, user, language and emoji.
+    pub fn same_kind(&self, other: &MessageEntity) -> bool {
+        self.kind == other.kind
+            && self.url == other.url
+            && self.user == other.user
+            && self.language == other.language
+            && self.custom_emoji_id == other.custom_emoji_id
+    }
 }
@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+
+/// https://core.telegram.org/passport#personaldetails
+/// This object represents personal details.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PersonalDetails {
+    pub first_name: String,
+    pub last_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub middle_name: Option<String>,
+    pub birth_date: String,
+    pub gender: String,
+    pub country_code: String,
+    pub residence_country_code: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_name_native: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name_native: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub middle_name_native: Option<String>,
+}
@@ -1,11 +1,13 @@
+use crate::api::enums::parse_mode::ParseMode;
 use crate::api::types::message_entity::MessageEntity;
 use crate::api::types::message_id::MessageId;
 use serde::{Deserialize, Serialize};
 
 /// https://core.telegram.org/bots/api#replyparameters
 /// Describes reply parameters for the message that is being sent.
-#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ReplyParameters {
+    #[serde(flatten)]
     pub message_id: MessageId,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub chat_id: Option<i64>,
@@ -14,7 +16,7 @@ pub struct ReplyParameters {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub quote: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub quote_parse_mode: Option<String>,
+    pub quote_parse_mode: Option<ParseMode>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub quote_entities: Option<Vec<MessageEntity>>,
     #[serde(skip_serializing_if = "Option::is_none")]
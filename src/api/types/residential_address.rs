@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+/// https://core.telegram.org/passport#residentialaddress
+/// This object represents a residential address.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ResidentialAddress {
+    pub street_line1: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub street_line2: Option<String>,
+    pub city: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+    pub country_code: String,
+    pub post_code: String,
+}
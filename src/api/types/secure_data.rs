@@ -0,0 +1,50 @@
+use crate::api::types::secure_value::SecureValue;
+use serde::{Deserialize, Serialize};
+
+/// https://core.telegram.org/passport#securedata
+/// This object represents the credentials required to decrypt encrypted data. All fields are optional and depend on fields that were requested.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct SecureData {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub personal_details: Option<SecureValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub passport: Option<SecureValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub internal_passport: Option<SecureValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub driver_license: Option<SecureValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub identity_card: Option<SecureValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<SecureValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub utility_bill: Option<SecureValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bank_statement: Option<SecureValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rental_agreement: Option<SecureValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub passport_registration: Option<SecureValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temporary_registration: Option<SecureValue>,
+}
+
+impl SecureData {
+    /// Credentials of the element of the type, e.g. `driver_license`.
+    pub fn get(&self, kind: &str) -> Option<&SecureValue> {
+        match kind {
+            "personal_details" => self.personal_details.as_ref(),
+            "passport" => self.passport.as_ref(),
+            "internal_passport" => self.internal_passport.as_ref(),
+            "driver_license" => self.driver_license.as_ref(),
+            "identity_card" => self.identity_card.as_ref(),
+            "address" => self.address.as_ref(),
+            "utility_bill" => self.utility_bill.as_ref(),
+            "bank_statement" => self.bank_statement.as_ref(),
+            "rental_agreement" => self.rental_agreement.as_ref(),
+            "passport_registration" => self.passport_registration.as_ref(),
+            "temporary_registration" => self.temporary_registration.as_ref(),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use crate::api::types::data_credentials::DataCredentials;
+use crate::api::types::file_credentials::FileCredentials;
+use serde::{Deserialize, Serialize};
+
+/// https://core.telegram.org/passport#securevalue
+/// This object represents the credentials required to decrypt encrypted values. All fields are optional and depend on the type of fields that were requested.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct SecureValue {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<DataCredentials>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub front_side: Option<FileCredentials>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reverse_side: Option<FileCredentials>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selfie: Option<FileCredentials>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub translation: Option<Vec<FileCredentials>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub files: Option<Vec<FileCredentials>>,
+}
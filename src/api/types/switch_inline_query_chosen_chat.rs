@@ -2,7 +2,7 @@ use serde::{Deserialize, Serialize};
 
 /// https://core.telegram.org/bots/api#switchinlinequerychosenchat
 /// This object represents an inline button that switches the current user to inline mode in a chosen chat, with an optional default inline query.
-#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct SwitchInlineQueryChosenChat {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub query: Option<String>,
@@ -12,6 +12,7 @@ use crate::api::types::poll_answer::PollAnswer;
 use crate::api::types::pre_checkout_query::PreCheckoutQuery;
 use crate::api::types::shipping_query::ShippingQuery;
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
 
 /// https://core.telegram.org/bots/api#update
 /// This object represents an incoming update.
@@ -55,4 +56,9 @@ pub struct Update {
     pub chat_boost: Option<ChatBoostUpdated>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub removed_chat_boost: Option<ChatBoostRemoved>,
+    /// Fields unknown to this version of the crate, e.g. an update type added by a newer Bot API.
+    /// `Message`, `Chat`, `User` and `MessageEntity` keep their unknown fields too, other nested
+    /// types drop them.
+    #[serde(flatten, skip_serializing_if = "Map::is_empty")]
+    pub extra: Map<String, Value>,
 }
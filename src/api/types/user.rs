@@ -1,8 +1,9 @@
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
 
 /// https://core.telegram.org/bots/api#user
 /// This object represents a Telegram user or bot.
-#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde_with_macros::skip_serializing_none]
 pub struct User {
     pub id: i64,
@@ -24,4 +25,7 @@ pub struct User {
     pub can_read_all_group_messages: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub supports_inline_queries: Option<bool>,
+    /// Fields unknown to this version of the crate, e.g. added to the user by a newer Bot API.
+    #[serde(flatten, skip_serializing_if = "Map::is_empty")]
+    pub extra: Map<String, Value>,
 }
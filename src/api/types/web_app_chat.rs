@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+/// https://core.telegram.org/bots/webapps#webappchat
+/// This object represents a chat.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct WebAppChat {
+    pub id: i64,
+    #[serde(rename(serialize = "type", deserialize = "type"))]
+    pub kind: String,
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_url: Option<String>,
+}
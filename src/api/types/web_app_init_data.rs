@@ -0,0 +1,27 @@
+use crate::api::types::web_app_chat::WebAppChat;
+use crate::api::types::web_app_user::WebAppUser;
+use serde::{Deserialize, Serialize};
+
+/// https://core.telegram.org/bots/webapps#webappinitdata
+/// This object contains data that is transferred to the Web App when it is opened.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct WebAppInitData {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<WebAppUser>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub receiver: Option<WebAppUser>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat: Option<WebAppChat>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_instance: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_param: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_send_after: Option<i64>,
+    pub auth_date: i64,
+    pub hash: String,
+}
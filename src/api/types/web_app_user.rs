@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+
+/// https://core.telegram.org/bots/webapps#webappuser
+/// This object contains the data of the Web App user.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct WebAppUser {
+    pub id: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_bot: Option<bool>,
+    pub first_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_premium: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub added_to_attachment_menu: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allows_write_to_pm: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_url: Option<String>,
+}
@@ -0,0 +1,206 @@
+use crate::api::enums::parse_mode::ParseMode;
+use crate::api::params::*;
+use crate::errors::Error;
+use crate::format::split::CAPTION_LIMIT;
+use crate::format::utf16;
+
+/// Documented constraints of the Bot API checked before the request is sent, so a broken one
+/// fails with `Error::Validation` instead of an opaque 400. Implemented by the params of every
+/// method and by the values they hold that have constraints of their own.
+pub trait Validate {
+    fn validate(&self) -> Result<(), Error>;
+}
+
+impl<T: Validate> Validate for Option<T> {
+    fn validate(&self) -> Result<(), Error> {
+        match self {
+            Some(value) => value.validate(),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<T: Validate> Validate for Vec<T> {
+    fn validate(&self) -> Result<(), Error> {
+        for (index, value) in self.iter().enumerate() {
+            nested(&format!("[{}]", index), value)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Params of methods without documented constraints beyond the types of their fields.
+macro_rules! unconstrained {
+    ($($params:ty),* $(,)?) => {$(
+        impl Validate for $params {
+            fn validate(&self) -> Result<(), Error> {
+                Ok(())
+            }
+        }
+    )*};
+}
+
+unconstrained!(
+    add_sticker_to_set::AddStickerToSet,
+    answer_callback_query::AnswerCallbackQuery,
+    answer_inline_query::AnswerInlineQuery,
+    answer_pre_checkout_query::AnswerPreCheckoutQuery,
+    answer_shipping_query::AnswerShippingQuery,
+    answer_web_app_query::AnswerWebAppQuery,
+    approve_chat_join_request::ApproveChatJoinRequest,
+    ban_chat_member::BanChatMember,
+    ban_chat_sender_chat::BanChatSenderChat,
+    close::Close,
+    close_forum_topic::CloseForumTopic,
+    close_general_forum_topic::CloseGeneralForumTopic,
+    copy_messages::CopyMessages,
+    create_chat_invite_link::CreateChatInviteLink,
+    create_forum_topic::CreateForumTopic,
+    create_new_sticker_set::CreateNewStickerSet,
+    decline_chat_join_request::DeclineChatJoinRequest,
+    delete_chat_photo::DeleteChatPhoto,
+    delete_chat_sticker_set::DeleteChatStickerSet,
+    delete_forum_topic::DeleteForumTopic,
+    delete_message::DeleteMessage,
+    delete_messages::DeleteMessages,
+    delete_my_commands::DeleteMyCommands,
+    delete_sticker_from_set::DeleteStickerFromSet,
+    delete_sticker_set::DeleteStickerSet,
+    delete_webhook::DeleteWebhook,
+    edit_chat_invite_link::EditChatInviteLink,
+    edit_forum_topic::EditForumTopic,
+    edit_general_forum_topic::EditGeneralForumTopic,
+    export_chat_invite_link::ExportChatInviteLink,
+    forward_message::ForwardMessage,
+    forward_messages::ForwardMessages,
+    get_chat::GetChat,
+    get_chat_administrators::GetChatAdministrators,
+    get_chat_member::GetChatMember,
+    get_chat_member_count::GetChatMemberCount,
+    get_chat_menu_button::GetChatMenuButton,
+    get_custom_emoji_stickers::GetCustomEmojiStickers,
+    get_file::GetFile,
+    get_forum_topic_icon_stickers::GetForumTopicIconStickers,
+    get_me::GetMe,
+    get_my_commands::GetMyCommands,
+    get_my_default_administrator_rights::GetMyDefaultAdministratorRights,
+    get_my_description::GetMyDescription,
+    get_my_name::GetMyName,
+    get_my_short_description::GetMyShortDescription,
+    get_sticker_set::GetStickerSet,
+    get_update::GetUpdate,
+    get_user_chat_boosts::GetUserChatBoosts,
+    get_user_profile_photos::GetUserProfilePhotos,
+    get_webhook_info::GetWebhookInfo,
+    hide_general_forum_topic::HideGeneralForumTopic,
+    leave_chat::LeaveChat,
+    log_out::LogOut,
+    pin_chat_message::PinChatMessage,
+    promote_chat_member::PromoteChatMember,
+    reopen_forum_topic::ReopenForumTopic,
+    reopen_general_forum_topic::ReopenGeneralForumTopic,
+    restrict_chat_member::RestrictChatMember,
+    revoke_chat_invite_link::RevokeChatInviteLink,
+    set_chat_administrator_custom_title::SetChatAdministratorCustomTitle,
+    set_chat_description::SetChatDescription,
+    set_chat_menu_button::SetChatMenuButton,
+    set_chat_permissions::SetChatPermissions,
+    set_chat_photo::SetChatPhoto,
+    set_chat_sticker_set::SetChatStickerSet,
+    set_chat_title::SetChatTitle,
+    set_custom_emoji_sticker_set_thumbnail::SetCustomEmojiStickerSetThumbnail,
+    set_game_score::SetGameScore,
+    set_message_reaction::SetMessageReaction,
+    set_my_default_administrator_rights::SetMyDefaultAdministratorRights,
+    set_my_description::SetMyDescription,
+    set_my_name::SetMyName,
+    set_my_short_description::SetMyShortDescription,
+    set_sticker_emoji_list::SetStickerEmojiList,
+    set_sticker_keywords::SetStickerKeywords,
+    set_sticker_mask_position::SetStickerMaskPosition,
+    set_sticker_position_in_set::SetStickerPositionInSet,
+    set_sticker_set_thumbnail::SetStickerSetThumbnail,
+    set_sticker_set_title::SetStickerSetTitle,
+    set_webhook::SetWebhook,
+    unban_chat_member::UnbanChatMember,
+    unban_chat_sender_chat::UnbanChatSenderChat,
+    unhide_general_forum_topic::UnhideGeneralForumTopic,
+    unpin_all_chat_messages::UnpinAllChatMessages,
+    unpin_all_forum_topic_messages::UnpinAllForumTopicMessages,
+    unpin_all_general_forum_topic_messages::UnpinAllGeneralForumTopicMessages,
+    unpin_chat_message::UnpinChatMessage,
+    upload_sticker_file::UploadStickerFile,
+);
+
+/// Validates a field holding another value, prefixing the field of its error, e.g.
+/// `reply_markup.inline_keyboard[0][1].callback_data`.
+pub(crate) fn nested(field: &str, value: &impl Validate) -> Result<(), Error> {
+    value.validate().map_err(|error| match error {
+        Error::Validation {
+            field: inner,
+            reason,
+        } => Error::Validation {
+            field: match inner.starts_with('[') {
+                true => format!("{}{}", field, inner),
+                false => format!("{}.{}", field, inner),
+            },
+            reason,
+        },
+        error => error,
+    })
+}
+
+pub(crate) fn fail(field: &str, reason: String) -> Result<(), Error> {
+    Err(Error::Validation {
+        field: field.to_string(),
+        reason,
+    })
+}
+
+/// Number of items of a list.
+pub(crate) fn count(field: &str, count: usize, min: usize, max: usize) -> Result<(), Error> {
+    match (min..=max).contains(&count) {
+        true => Ok(()),
+        false => fail(
+            field,
+            format!("must have {} to {} items, got {}", min, max, count),
+        ),
+    }
+}
+
+/// Length of a text in characters, counted in UTF-16 code units like Telegram does.
+pub(crate) fn length(field: &str, text: &str, min: i64, max: i64) -> Result<(), Error> {
+    let length = utf16::len(text);
+
+    match (min..=max).contains(&length) {
+        true => Ok(()),
+        false => fail(
+            field,
+            format!("must be {} to {} characters long, got {}", min, max, length),
+        ),
+    }
+}
+
+/// Length of a text in bytes of UTF-8.
+pub(crate) fn bytes(field: &str, text: &str, min: usize, max: usize) -> Result<(), Error> {
+    match (min..=max).contains(&text.len()) {
+        true => Ok(()),
+        false => fail(
+            field,
+            format!("must be {} to {} bytes long, got {}", min, max, text.len()),
+        ),
+    }
+}
+
+/// Length of a caption, only checked without a parse mode as the markup doesn't count.
+pub(crate) fn caption(
+    field: &str,
+    caption: &Option<String>,
+    parse_mode: Option<ParseMode>,
+) -> Result<(), Error> {
+    match (caption, parse_mode) {
+        (Some(caption), None) => length(field, caption, 0, CAPTION_LIMIT),
+        _ => Ok(()),
+    }
+}
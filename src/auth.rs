@@ -0,0 +1,95 @@
+use crate::errors::Error;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+pub mod login;
+pub mod web_app;
+
+/// Time an `auth_date` may be ahead of the local clock, as the clocks of Telegram and the server
+/// differ slightly.
+const CLOCK_SKEW: i64 = 60;
+
+/// HMAC-SHA256 of the data with the key.
+pub(crate) fn hmac_sha256(key: &[u8], data: &[u8]) -> Vec<u8> {
+    mac(key, data).finalize().into_bytes().to_vec()
+}
+
+fn mac(key: &[u8], data: &[u8]) -> Hmac<Sha256> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(data);
+
+    mac
+}
+
+/// Fields of the query string, checked to be signed by Telegram: the hex `hash` field is the
+/// HMAC-SHA256 with the secret of the other fields as `key=value` lines sorted by key.
+pub(crate) fn signed_fields(query: &str, secret: &[u8]) -> Result<Vec<(String, String)>, Error> {
+    let fields: Vec<(String, String)> = url::form_urlencoded::parse(query.as_bytes())
+        .into_owned()
+        .collect();
+
+    let hash = match fields.iter().find(|(key, _)| key == "hash") {
+        Some((_, hash)) => {
+            hex::decode(hash).map_err(|_| rejected("hash", "must be hex encoded"))?
+        }
+        None => return Err(rejected("hash", "is missing")),
+    };
+
+    let mut lines: Vec<String> = fields
+        .iter()
+        .filter(|(key, _)| key != "hash")
+        .map(|(key, value)| format!("{}={}", key, value))
+        .collect();
+    lines.sort();
+
+    // Compared in constant time.
+    mac(secret, lines.join("\n").as_bytes())
+        .verify_slice(&hash)
+        .map_err(|_| {
+            rejected(
+                "hash",
+                "doesn't match the data, it wasn't signed for this bot",
+            )
+        })?;
+
+    Ok(fields)
+}
+
+/// Rejects an `auth_date` in unix seconds older than `max_age`, e.g. a replayed login, or ahead
+/// of the local clock by more than `CLOCK_SKEW`, which would make it valid for longer.
+pub(crate) fn check_age(auth_date: i64, max_age: Option<Duration>) -> Result<(), Error> {
+    let max_age = match max_age {
+        Some(max_age) => max_age.as_secs() as i64,
+        None => return Ok(()),
+    };
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs() as i64;
+
+    if auth_date - now > CLOCK_SKEW {
+        return Err(rejected("auth_date", "is in the future"));
+    }
+
+    match now - auth_date <= max_age {
+        true => Ok(()),
+        false => Err(rejected(
+            "auth_date",
+            &format!("is older than {} seconds", max_age),
+        )),
+    }
+}
+
+pub(crate) fn number(field: &str, value: &str) -> Result<i64, Error> {
+    value
+        .parse()
+        .map_err(|_| rejected(field, "must be a number"))
+}
+
+pub(crate) fn rejected(field: &str, reason: &str) -> Error {
+    Error::Auth {
+        field: field.to_string(),
+        reason: reason.to_string(),
+    }
+}
@@ -0,0 +1,36 @@
+use crate::api::types::login_data::LoginData;
+use crate::auth;
+use crate::config::token::BotToken;
+use crate::errors::Error;
+use serde_json::{Map, Value};
+use sha2::{Digest, Sha256};
+use std::time::Duration;
+
+/// Parses the query string Telegram redirects to after a Login Widget or `LoginUrl` authorization,
+/// e.g. `id=42&first_name=Ann&auth_date=…&hash=…`, and checks it was signed for the bot of the
+/// token and, with `max_age`, is fresh enough.
+pub fn validate_login(
+    query: &str,
+    token: &BotToken,
+    max_age: Option<Duration>,
+) -> Result<LoginData, Error> {
+    let secret = Sha256::digest(token.expose().as_bytes());
+    let fields = auth::signed_fields(query, &secret)?;
+
+    let mut object = Map::new();
+    for (key, value) in fields {
+        let value = match key.as_str() {
+            "id" | "auth_date" => Value::from(auth::number(&key, &value)?),
+            _ => Value::String(value),
+        };
+        object.insert(key, value);
+    }
+
+    let auth_date = match object.get("auth_date") {
+        Some(auth_date) => auth_date.as_i64().unwrap_or_default(),
+        None => return Err(auth::rejected("auth_date", "is missing")),
+    };
+    auth::check_age(auth_date, max_age)?;
+
+    serde_json::from_value(Value::Object(object)).map_err(Error::Decode)
+}
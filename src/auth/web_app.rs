@@ -0,0 +1,35 @@
+use crate::api::types::web_app_init_data::WebAppInitData;
+use crate::auth;
+use crate::config::token::BotToken;
+use crate::errors::Error;
+use serde_json::{Map, Value};
+use std::time::Duration;
+
+/// Parses the `initData` a Web App frontend posts to the backend, `Telegram.WebApp.initData`, and
+/// checks it was signed by Telegram for the bot of the token and, with `max_age`, is fresh enough.
+pub fn validate_init_data(
+    init_data: &str,
+    token: &BotToken,
+    max_age: Option<Duration>,
+) -> Result<WebAppInitData, Error> {
+    let secret = auth::hmac_sha256(b"WebAppData", token.expose().as_bytes());
+    let fields = auth::signed_fields(init_data, &secret)?;
+
+    let mut object = Map::new();
+    for (key, value) in fields {
+        let value = match key.as_str() {
+            "user" | "receiver" | "chat" => serde_json::from_str(&value).map_err(Error::Decode)?,
+            "auth_date" | "can_send_after" => Value::from(auth::number(&key, &value)?),
+            _ => Value::String(value),
+        };
+        object.insert(key, value);
+    }
+
+    let auth_date = match object.get("auth_date") {
+        Some(auth_date) => auth_date.as_i64().unwrap_or_default(),
+        None => return Err(auth::rejected("auth_date", "is missing")),
+    };
+    auth::check_age(auth_date, max_age)?;
+
+    serde_json::from_value(Value::Object(object)).map_err(Error::Decode)
+}
@@ -1,6 +1,18 @@
+use crate::api::method::Method;
+use crate::clients::limiter::Limiter;
 use crate::clients::r#async::Async;
+use crate::clients::retry::Retry;
 use crate::clients::sync::Sync;
-use crate::config::Config;
+use crate::clients::traits::{AsyncTransport, Transport};
+use crate::config::token::BotToken;
+use crate::config::{Config, Mode, API_URL};
+use crate::errors::Error;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
 
 #[derive(Debug)]
 pub struct Client {
@@ -10,15 +22,247 @@ pub struct Client {
 }
 
 impl Client {
-    pub fn new() -> Self {
-        let config = Config::new();
-        let sync = Sync::new(&config);
-        let r#async = Async::new(&config);
+    /// Client for the cloud Bot API with default settings, fails on a malformed token.
+    pub fn new(token: &str) -> Result<Self, Error> {
+        Self::builder(token).build()
+    }
+
+    pub fn builder(token: &str) -> ClientBuilder {
+        ClientBuilder::new(token)
+    }
+
+    /// Client configured from the config file, environment and command line arguments of the process,
+    /// see `Config::load`.
+    pub fn from_args() -> Result<Self, Error> {
+        Self::from_config(Config::load()?)
+    }
+
+    pub fn from_config(config: Config) -> Result<Self, Error> {
+        ClientBuilder::from_config(config).build()
+    }
+
+    /// Calls the endpoint of the params with the sync client, see `Sync::execute`.
+    pub fn execute<M: Method>(&self, method: &M) -> Result<M::Output, Error> {
+        self.sync.execute(method)
+    }
+
+    /// Calls any method by name with the sync client, see `Sync::call`.
+    pub fn call<P: Serialize, R: DeserializeOwned>(
+        &self,
+        method: &str,
+        params: &P,
+    ) -> Result<R, Error> {
+        self.sync.call(method, params)
+    }
+
+    /// Calls any method by name with plain JSON, see `Sync::call_raw`.
+    pub fn call_raw(&self, method: &str, body: Value) -> Result<Value, Error> {
+        self.sync.call_raw(method, body)
+    }
+}
+
+/// Builds a `Client` without touching the command line arguments.
+#[derive(Debug)]
+pub struct ClientBuilder {
+    config: Config,
+    /// Timeouts are kept in whole seconds by the config, they are checked when the client is built.
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    updates_timeout: Option<Duration>,
+    sync_client: Option<reqwest::blocking::Client>,
+    async_client: Option<reqwest::Client>,
+    retry: Option<Retry>,
+    limiter: Option<Arc<Limiter>>,
+    transport: Option<Arc<dyn Transport>>,
+    async_transport: Option<Arc<dyn AsyncTransport>>,
+}
+
+impl ClientBuilder {
+    pub fn new(token: &str) -> Self {
+        Self::from_config(Config {
+            token: BotToken::from(token),
+            url: API_URL.to_string(),
+            ..Default::default()
+        })
+    }
 
+    pub fn from_config(config: Config) -> Self {
         Self {
             config,
-            r#async,
-            sync,
+            timeout: None,
+            connect_timeout: None,
+            updates_timeout: None,
+            sync_client: None,
+            async_client: None,
+            retry: None,
+            limiter: None,
+            transport: None,
+            async_transport: None,
+        }
+    }
+
+    /// Bot API server url, `https://api.telegram.org` by default.
+    pub fn url(mut self, url: &str) -> Self {
+        self.config.url = url.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Kind of the Bot API server at the url, `Mode::Cloud` by default.
+    pub fn mode(mut self, mode: Mode) -> Self {
+        self.config.mode = mode;
+        self
+    }
+
+    /// Timeout from the start of connecting until the response body has finished, in whole seconds.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Timeout of the connect phase, in whole seconds.
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Timeout of long polling in getUpdates, in whole seconds.
+    pub fn updates_timeout(mut self, timeout: Duration) -> Self {
+        self.updates_timeout = Some(timeout);
+        self
+    }
+
+    pub fn proxy(mut self, proxy: &str) -> Self {
+        self.config.proxy = Some(proxy.to_string());
+        self
+    }
+
+    /// Hosts reached without the proxy, comma separated, e.g. `localhost,10.0.0.0/8,.internal`.
+    pub fn no_proxy(mut self, no_proxy: &str) -> Self {
+        self.config.no_proxy = Some(no_proxy.to_string());
+        self
+    }
+
+    /// Trusts the PEM certificates of the file in addition to the system ones.
+    pub fn root_certificate<P: AsRef<Path>>(mut self, path: P) -> Self {
+        self.config
+            .root_certificates
+            .push(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Presents the PEM certificate with its PKCS#8 PEM key to the server.
+    pub fn client_certificate<P: AsRef<Path>>(mut self, certificate: P, key: P) -> Self {
+        self.config.client_certificate = Some(certificate.as_ref().to_path_buf());
+        self.config.client_key = Some(key.as_ref().to_path_buf());
+        self
+    }
+
+    /// Only uses HTTP/1, replaces `http2_prior_knowledge`.
+    pub fn http1_only(mut self) -> Self {
+        self.config.http1_only = true;
+        self.config.http2_prior_knowledge = false;
+        self
+    }
+
+    /// Uses HTTP/2 without negotiating it first, replaces `http1_only`.
+    pub fn http2_prior_knowledge(mut self) -> Self {
+        self.config.http2_prior_knowledge = true;
+        self.config.http1_only = false;
+        self
+    }
+
+    pub fn user_agent(mut self, user_agent: &str) -> Self {
+        self.config.user_agent = Some(user_agent.to_string());
+        self
+    }
+
+    /// Uses the given blocking HTTP client, timeouts, proxy and user agent are then up to it.
+    pub fn sync_client(mut self, client: reqwest::blocking::Client) -> Self {
+        self.sync_client = Some(client);
+        self
+    }
+
+    /// Uses the given async HTTP client, timeouts, proxy and user agent are then up to it.
+    pub fn async_client(mut self, client: reqwest::Client) -> Self {
+        self.async_client = Some(client);
+        self
+    }
+
+    /// Sends the requests of both clients through the transport instead of the HTTP clients.
+    pub fn transport<T: Transport + AsyncTransport + 'static>(self, transport: Arc<T>) -> Self {
+        self.sync_transport(transport.clone())
+            .async_transport(transport)
+    }
+
+    /// Sends the requests of the sync client through the transport, e.g. `UreqTransport` which
+    /// has no async counterpart.
+    pub fn sync_transport(mut self, transport: Arc<dyn Transport>) -> Self {
+        self.transport = Some(transport);
+        self
+    }
+
+    /// Sends the requests of the async client through the transport.
+    pub fn async_transport(mut self, transport: Arc<dyn AsyncTransport>) -> Self {
+        self.async_transport = Some(transport);
+        self
+    }
+
+    pub fn retry(mut self, retry: Retry) -> Self {
+        self.retry = Some(retry);
+        self
+    }
+
+    pub fn limiter(mut self, limiter: Arc<Limiter>) -> Self {
+        self.limiter = Some(limiter);
+        self
+    }
+
+    pub fn build(self) -> Result<Client, Error> {
+        let mut config = self.config;
+        if let Some(timeout) = self.timeout {
+            config.timeout = whole_seconds("timeout", timeout)?;
+        }
+        if let Some(timeout) = self.connect_timeout {
+            config.connect_timeout = whole_seconds("connect_timeout", timeout)?;
+        }
+        if let Some(timeout) = self.updates_timeout {
+            config.updates_timeout = whole_seconds("updates_timeout", timeout)?;
+        }
+        config.validate()?;
+
+        let mut sync = match (self.transport, self.sync_client) {
+            (Some(transport), _) => Sync::with_transport(&config, transport),
+            (None, Some(client)) => Sync::with_client(&config, client),
+            (None, None) => Sync::with_client(&config, config.build_sync_client()?),
+        };
+        let mut r#async = match (self.async_transport, self.async_client) {
+            (Some(transport), _) => Async::with_transport(&config, transport),
+            (None, Some(client)) => Async::with_client(&config, client),
+            (None, None) => Async::with_client(&config, config.build_async_client()?),
+        };
+        if let Some(retry) = self.retry {
+            sync.set_retry(retry.clone());
+            r#async.set_retry(retry);
+        }
+        if let Some(limiter) = self.limiter {
+            sync.set_limiter(limiter.clone());
+            r#async.set_limiter(limiter);
         }
+
+        Ok(Client {
+            sync,
+            r#async,
+            config,
+        })
+    }
+}
+
+fn whole_seconds(field: &str, timeout: Duration) -> Result<u64, Error> {
+    match timeout.subsec_nanos() {
+        0 => Ok(timeout.as_secs()),
+        _ => Err(Error::Config {
+            field: field.to_string(),
+            reason: format!("must be whole seconds, got {:?}", timeout),
+        }),
     }
 }
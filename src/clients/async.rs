@@ -1,34 +1,171 @@
-use crate::config::Config;
-use std::time::Duration;
+use crate::api::method::Method;
+use crate::api::validate::Validate;
+use crate::clients::limiter::Limiter;
+use crate::clients::policy::{Attempt, Policy};
+use crate::clients::retry::Retry;
+use crate::clients::traits::{AsyncTransport, Decoder};
+use crate::clients::transport::http::AsyncReqwestTransport;
+use crate::clients::transport::{Body, Part};
+use crate::config::{Config, Mode};
+use crate::errors::Error;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
 
-#[derive(Debug)]
 pub struct Async {
-    client: reqwest::Client,
+    transport: Arc<dyn AsyncTransport>,
     offset: i64,
     limit: i64,
     timeout: u64,
-    url: String,
+    mode: Mode,
+    policy: Policy,
 }
 
 impl Async {
-    pub fn new(config: &Config) -> Self {
-        let client = reqwest::Client::builder()
-            .timeout(Duration::from_secs(config.timeout))
-            .connect_timeout(Duration::from_secs(config.connect_timeout))
-            .build()
-            .unwrap();
+    /// Builds the HTTP client from the config, fails on a broken proxy or certificate.
+    pub fn new(config: &Config) -> Result<Self, Error> {
+        Ok(Self::with_client(config, config.build_async_client()?))
+    }
+
+    /// Uses the given HTTP client instead of building one from the config.
+    pub fn with_client(config: &Config, client: reqwest::Client) -> Self {
+        Self::with_transport(config, Arc::new(AsyncReqwestTransport::new(client, config)))
+    }
 
+    /// Sends the requests through the given transport, e.g. `MemoryTransport`.
+    pub fn with_transport(config: &Config, transport: Arc<dyn AsyncTransport>) -> Self {
         let offset = config.updates_offset;
         let limit = config.updates_limit;
         let timeout = config.updates_timeout;
-        let url = config.build_url();
 
         Self {
-            client,
+            transport,
             offset,
             limit,
             timeout,
-            url,
+            mode: config.mode,
+            policy: Policy::for_token(&config.token),
+        }
+    }
+
+    pub fn set_retry(&mut self, retry: Retry) {
+        self.policy.retry = retry;
+    }
+
+    /// Throttles the messages sent by this client, the limiter may be shared with other clients.
+    /// The delays it reserves are awaited with tokio instead of sleeping on the clock of the limiter.
+    pub fn set_limiter(&mut self, limiter: Arc<Limiter>) {
+        self.policy.limiter = Some(limiter);
+    }
+
+    pub fn limiter(&self) -> Option<&Arc<Limiter>> {
+        self.policy.limiter.as_ref()
+    }
+
+    pub fn mode(&self) -> Mode {
+        self.mode
+    }
+
+    /// Overrides the mode taken from the config.
+    pub fn set_mode(&mut self, mode: Mode) {
+        self.mode = mode;
+    }
+
+    /// Calls the endpoint of the params and returns its result, also for endpoints without a
+    /// convenience method. The params are validated first, see `Validate`.
+    pub async fn execute<M: Method + Sync>(&self, method: &M) -> Result<M::Output, Error> {
+        let body = serde_json::to_value(method).map_err(Error::Decode)?;
+
+        self.execute_body(M::NAME, M::IDEMPOTENT, Some(method), Body::Json(body))
+            .await
+    }
+
+    /// Calls any method by name, e.g. one added by a newer Bot API version than this crate knows.
+    /// The request is retried the same way as by `Sync::call`.
+    pub async fn call<P: Serialize, R: DeserializeOwned>(
+        &self,
+        method: &str,
+        params: &P,
+    ) -> Result<R, Error> {
+        let body = serde_json::to_value(params).map_err(Error::Decode)?;
+
+        self.execute_body(method, false, None, Body::Json(body))
+            .await
+    }
+
+    /// Untyped `call`: the params and the result are plain JSON.
+    pub async fn call_raw(&self, method: &str, body: Value) -> Result<Value, Error> {
+        self.execute_body(method, false, None, Body::Json(body))
+            .await
+    }
+
+    /// Untyped `call` uploading files as `multipart/form-data`.
+    pub async fn call_multipart<R: DeserializeOwned>(
+        &self,
+        method: &str,
+        parts: Vec<Part>,
+    ) -> Result<R, Error> {
+        self.execute_body(method, false, None, Body::Multipart(parts))
+            .await
+    }
+
+    /// Sends the body to the method and retries the request according to the retry policy.
+    /// The typed params are validated first, an untyped body is checked by `Body::validate`.
+    pub(crate) async fn execute_body<T: DeserializeOwned>(
+        &self,
+        method: &str,
+        idempotent: bool,
+        params: Option<&(dyn Validate + Sync)>,
+        mut body: Body,
+    ) -> Result<T, Error> {
+        match params {
+            Some(params) => params.validate()?,
+            None => body.validate(method)?,
+        }
+        if let Body::Multipart(parts) = &body {
+            self.check_parts(parts)?;
         }
+        let mut attempt = Attempt::new(method, idempotent);
+
+        loop {
+            let delay = self.policy.reserve(&mut attempt, &body);
+            if !delay.is_zero() {
+                tokio::time::sleep(delay).await;
+            }
+
+            let error = match self.send(method, &body).await {
+                Ok(result) => return Ok(result),
+                Err(error) => error,
+            };
+
+            let delay = self.policy.recover(&mut attempt, &mut body, error)?;
+            if !delay.is_zero() {
+                tokio::time::sleep(delay).await;
+            }
+        }
+    }
+
+    async fn send<T: DeserializeOwned>(&self, method: &str, body: &Body) -> Result<T, Error> {
+        let reply = self.transport.send(method, body).await?;
+
+        self.decode(reply)
     }
 }
+
+impl Debug for Async {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Async")
+            .field("offset", &self.offset)
+            .field("limit", &self.limit)
+            .field("timeout", &self.timeout)
+            .field("transport", &self.transport)
+            .field("mode", &self.mode)
+            .field("retry", &self.policy.retry)
+            .field("limiter", &self.policy.limiter)
+            .finish()
+    }
+}
+
+impl Decoder for Async {}
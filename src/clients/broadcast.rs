@@ -0,0 +1,233 @@
+use crate::api::enums::chat_uid::ChatUId;
+use crate::api::params::copy_message::CopyMessage;
+use crate::api::params::send_animation::SendAnimation;
+use crate::api::params::send_document::SendDocument;
+use crate::api::params::send_message::SendMessage;
+use crate::api::params::send_photo::SendPhoto;
+use crate::api::params::send_video::SendVideo;
+use crate::api::params::send_voice::SendVoice;
+use crate::api::validate::Validate;
+use crate::clients::limiter::{Limiter, Limits};
+use crate::clients::sync::Sync;
+use crate::clients::transport::Body;
+use crate::errors::Error;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Message sent to every chat of a broadcast, its `chat_id` is replaced with the recipient.
+/// The template is sent as JSON, so photos, videos and other media must be given as a `file_id` or
+/// an HTTP URL: uploading a file for every recipient is not supported.
+pub enum Template {
+    Text(SendMessage),
+    Photo(SendPhoto),
+    Video(SendVideo),
+    Animation(SendAnimation),
+    Document(SendDocument),
+    Voice(SendVoice),
+    /// Copy of an existing message, e.g. a post in the announcements channel.
+    Copy(CopyMessage),
+}
+
+impl Template {
+    fn method(&self) -> &'static str {
+        match self {
+            Template::Text(_) => "sendMessage",
+            Template::Photo(_) => "sendPhoto",
+            Template::Video(_) => "sendVideo",
+            Template::Animation(_) => "sendAnimation",
+            Template::Document(_) => "sendDocument",
+            Template::Voice(_) => "sendVoice",
+            Template::Copy(_) => "copyMessage",
+        }
+    }
+
+    fn body(&self) -> Result<Value, Error> {
+        match self {
+            Template::Text(params) => serde_json::to_value(params),
+            Template::Photo(params) => serde_json::to_value(params),
+            Template::Video(params) => serde_json::to_value(params),
+            Template::Animation(params) => serde_json::to_value(params),
+            Template::Document(params) => serde_json::to_value(params),
+            Template::Voice(params) => serde_json::to_value(params),
+            Template::Copy(params) => serde_json::to_value(params),
+        }
+        .map_err(Error::Decode)
+    }
+}
+
+impl Validate for Template {
+    fn validate(&self) -> Result<(), Error> {
+        match self {
+            Template::Text(params) => params.validate(),
+            Template::Photo(params) => params.validate(),
+            Template::Video(params) => params.validate(),
+            Template::Animation(params) => params.validate(),
+            Template::Document(params) => params.validate(),
+            Template::Voice(params) => params.validate(),
+            Template::Copy(params) => params.validate(),
+        }
+    }
+}
+
+/// Chat that didn't receive the message for a reason other than the well-known ones.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct Failure {
+    pub chat_id: ChatUId,
+    pub error_code: Option<u64>,
+    pub description: String,
+}
+
+/// Outcome of a broadcast, also used as its checkpoint.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct Report {
+    /// Number of recipients already handled, successfully or not.
+    pub processed: u64,
+    pub sent: u64,
+    /// Users that blocked the bot.
+    pub blocked: Vec<ChatUId>,
+    /// Deleted user accounts.
+    pub deactivated: Vec<ChatUId>,
+    /// Groups and channels the bot was removed from.
+    pub kicked: Vec<ChatUId>,
+    pub not_found: Vec<ChatUId>,
+    pub failed: Vec<Failure>,
+}
+
+impl Report {
+    fn record(&mut self, chat_id: ChatUId, result: Result<Value, Error>) {
+        self.processed += 1;
+
+        match result {
+            Ok(_) => self.sent += 1,
+            Err(Error::BotBlocked(_)) => self.blocked.push(chat_id),
+            Err(Error::UserDeactivated(_)) => self.deactivated.push(chat_id),
+            Err(Error::BotKicked(_)) => self.kicked.push(chat_id),
+            Err(Error::ChatNotFound(_)) => self.not_found.push(chat_id),
+            Err(error) => self.failed.push(Failure {
+                chat_id,
+                error_code: error.error_code(),
+                description: error.to_string(),
+            }),
+        }
+    }
+}
+
+/// Sends one message to many chats under the flood limits. Flood control errors are retried by the
+/// `Retry` policy of the client, a chat that is still flooded after it is reported as failed.
+pub struct Broadcast<'a> {
+    sync: &'a Sync,
+    bot_id: u64,
+    limiter: Option<Arc<Limiter>>,
+    checkpoint: Option<PathBuf>,
+    checkpoint_every: u64,
+}
+
+impl<'a> Broadcast<'a> {
+    /// Uses the limiter of the client, or the default Telegram limits if the client has none.
+    pub fn new(sync: &'a Sync) -> Self {
+        let limiter = match sync.limiter() {
+            Some(_) => None,
+            None => Some(Arc::new(Limiter::new(Limits::default()))),
+        };
+
+        Self {
+            sync,
+            bot_id: sync.token().bot_id().unwrap_or_default(),
+            limiter,
+            checkpoint: None,
+            checkpoint_every: 1,
+        }
+    }
+
+    /// Saves the report to the file every `every` recipients. When the file already exists, the
+    /// broadcast resumes after the recipients it lists as processed, so after a crash up to
+    /// `every - 1` recipients may get the message twice: pass 1 to save after every recipient.
+    pub fn checkpoint<P: AsRef<Path>>(mut self, path: P, every: u64) -> Self {
+        self.checkpoint = Some(path.as_ref().to_path_buf());
+        self.checkpoint_every = every.max(1);
+        self
+    }
+
+    /// Sends the template to every chat, calling `progress` after each of them.
+    /// Recipients must be passed in the same order when a broadcast is resumed.
+    pub fn run<I, F>(
+        &self,
+        chat_ids: I,
+        template: &Template,
+        mut progress: F,
+    ) -> Result<Report, Error>
+    where
+        I: IntoIterator<Item = ChatUId>,
+        F: FnMut(&Report),
+    {
+        let mut report = self.restore()?;
+        let body = template.body()?;
+
+        for chat_id in chat_ids.into_iter().skip(report.processed as usize) {
+            // Broken params fail the same way for every chat, so the first one ends the broadcast.
+            let result = match self.send(template, &body, &chat_id) {
+                Err(error @ Error::Validation { .. }) => return Err(error),
+                result => result,
+            };
+            report.record(chat_id, result);
+            progress(&report);
+
+            if report.processed % self.checkpoint_every == 0 {
+                self.save(&report)?;
+            }
+        }
+
+        self.save(&report)?;
+
+        Ok(report)
+    }
+
+    fn send(&self, template: &Template, body: &Value, chat_id: &ChatUId) -> Result<Value, Error> {
+        let mut body = body.clone();
+        body["chat_id"] = serde_json::to_value(chat_id).map_err(Error::Decode)?;
+        let send = |body| {
+            self.sync
+                .execute_body(template.method(), false, Some(template), Body::Json(body))
+        };
+
+        let Some(limiter) = &self.limiter else {
+            return send(body);
+        };
+
+        limiter.acquire(self.bot_id, chat_id);
+        let result = send(body);
+        // The client only penalizes its own limiter, the next recipients wait the flood out.
+        if let Err(Error::TooManyRequests { retry_after, .. }) = &result {
+            limiter.penalize(self.bot_id, chat_id, *retry_after);
+        }
+
+        result
+    }
+
+    fn restore(&self) -> Result<Report, Error> {
+        match &self.checkpoint {
+            Some(path) if path.exists() => {
+                let checkpoint = fs::read_to_string(path).map_err(Error::Io)?;
+                serde_json::from_str::<Report>(&checkpoint).map_err(Error::Decode)
+            }
+            _ => Ok(Report::default()),
+        }
+    }
+
+    /// Writes the checkpoint next to the target first, so a crash never leaves a truncated file.
+    fn save(&self, report: &Report) -> Result<(), Error> {
+        if let Some(path) = &self.checkpoint {
+            let checkpoint = serde_json::to_string(report).map_err(Error::Decode)?;
+            let mut temporary = path.clone().into_os_string();
+            temporary.push(".tmp");
+
+            fs::write(&temporary, checkpoint).map_err(Error::Io)?;
+            fs::rename(&temporary, path).map_err(Error::Io)?;
+        }
+
+        Ok(())
+    }
+}
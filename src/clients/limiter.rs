@@ -0,0 +1,371 @@
+use crate::api::enums::chat_uid::ChatUId;
+use crate::api::types::chat_id::ChatId;
+use std::collections::{BTreeSet, HashMap, VecDeque};
+use std::fmt::{Debug, Formatter};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// Source of time for the limiter, replaced with `VirtualClock` in tests.
+pub trait Clock: Send + std::marker::Sync {
+    /// Time elapsed since the clock was started.
+    fn now(&self) -> Duration;
+
+    /// Wall clock time, to compare with timestamps saved outside of the process.
+    fn system_time(&self) -> SystemTime;
+
+    fn sleep(&self, duration: Duration);
+}
+
+#[derive(Debug)]
+pub struct SystemClock {
+    start: Instant,
+}
+
+impl SystemClock {
+    pub fn new() -> Self {
+        Self {
+            start: Instant::now(),
+        }
+    }
+}
+
+impl Clock for SystemClock {
+    fn now(&self) -> Duration {
+        self.start.elapsed()
+    }
+
+    fn system_time(&self) -> SystemTime {
+        SystemTime::now()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        thread::sleep(duration)
+    }
+}
+
+/// Clock that only moves when somebody sleeps on it, its wall clock starts at the unix epoch.
+#[derive(Debug, Default)]
+pub struct VirtualClock {
+    now: Mutex<Duration>,
+}
+
+impl VirtualClock {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn advance(&self, duration: Duration) {
+        *self.now.lock().unwrap() += duration;
+    }
+}
+
+impl Clock for VirtualClock {
+    fn now(&self) -> Duration {
+        *self.now.lock().unwrap()
+    }
+
+    fn system_time(&self) -> SystemTime {
+        UNIX_EPOCH + self.now()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        self.advance(duration)
+    }
+}
+
+/// No more than `count` messages per `period`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Limit {
+    pub count: u32,
+    pub period: Duration,
+}
+
+impl Limit {
+    pub fn new(count: u32, period: Duration) -> Self {
+        Self { count, period }
+    }
+}
+
+/// https://core.telegram.org/bots/faq#my-bot-is-hitting-limits-how-do-i-avoid-this
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Limits {
+    /// All chats together, about 30 messages per second.
+    pub global: Limit,
+    /// A single private chat, 1 message per second.
+    pub private: Limit,
+    /// A single group or channel, 20 messages per minute.
+    pub group: Limit,
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Self {
+            global: Limit::new(30, Duration::from_secs(1)),
+            private: Limit::new(1, Duration::from_secs(1)),
+            group: Limit::new(20, Duration::from_secs(60)),
+        }
+    }
+}
+
+/// Flood control is never slowed down more than this.
+const MAX_SLOWDOWN: u32 = 8;
+
+/// Time without flood control errors after which the slowdown of a chat is halved, at least the
+/// slowed down period of the chat.
+const QUIET: Duration = Duration::from_secs(60);
+
+#[derive(Debug)]
+struct Queue {
+    limit: Limit,
+    slowdown: u32,
+    sent: VecDeque<Duration>,
+    paused_until: Duration,
+    /// Start of the quiet period the slowdown is decayed from.
+    relaxed_at: Duration,
+}
+
+impl Queue {
+    fn new(limit: Limit) -> Self {
+        Self {
+            limit,
+            slowdown: 1,
+            sent: VecDeque::new(),
+            paused_until: Duration::ZERO,
+            relaxed_at: Duration::ZERO,
+        }
+    }
+
+    /// Halves the slowdown for every quiet period since the last flood control error.
+    fn relax(&mut self, now: Duration) {
+        while self.slowdown > 1 {
+            let quiet = QUIET.max(self.limit.period * self.slowdown);
+            if now < self.relaxed_at + quiet {
+                break;
+            }
+            self.relaxed_at += quiet;
+            self.slowdown /= 2;
+        }
+    }
+
+    /// Nothing in the queue limits the next message anymore, so it can be dropped.
+    fn idle(&self, now: Duration) -> bool {
+        let sent = match self.sent.back() {
+            Some(last) => now >= *last + self.limit.period,
+            None => true,
+        };
+
+        sent && self.slowdown == 1 && now >= self.paused_until
+    }
+
+    /// Earliest time the chat may receive the next message.
+    fn ready_at(&self, now: Duration) -> Duration {
+        let period = self.limit.period * self.slowdown;
+        let count = self.limit.count as usize;
+        let mut ready = now.max(self.paused_until);
+
+        if let Some(last) = self.sent.back() {
+            ready = ready.max(*last);
+        }
+        if self.sent.len() >= count {
+            ready = ready.max(self.sent[self.sent.len() - count] + period);
+        }
+
+        ready
+    }
+
+    fn push(&mut self, at: Duration) {
+        self.sent.push_back(at);
+        while self.sent.len() > self.limit.count as usize {
+            self.sent.pop_front();
+        }
+    }
+}
+
+#[derive(Debug)]
+struct State {
+    /// Reserved slots of the global limit of every bot, numbered by `global.period / global.count`
+    /// intervals.
+    slots: HashMap<u64, BTreeSet<u64>>,
+    /// Queues of the chats by bot id and chat.
+    chats: HashMap<(u64, String), Queue>,
+    /// End of the flood control pause of every bot.
+    paused: HashMap<u64, Duration>,
+    /// Last time idle queues were dropped.
+    swept_at: Duration,
+}
+
+impl State {
+    /// Drops the queues and pauses that no longer limit anything, at most once per the longest
+    /// period of the limits, so that the state doesn't grow with every chat ever messaged.
+    fn sweep(&mut self, now: Duration, every: Duration) {
+        if now < self.swept_at + every {
+            return;
+        }
+        self.swept_at = now;
+
+        self.chats.retain(|_, queue| {
+            queue.relax(now);
+            !queue.idle(now)
+        });
+        self.paused.retain(|_, until| *until > now);
+    }
+}
+
+/// Client side throttling of the messages sent by a bot.
+/// Every message reserves the earliest moment allowed both by its chat and by the global limit, so a
+/// chat with a long queue only delays itself and the other chats keep their share of the global rate.
+/// Telegram limits every bot on its own, so bots sharing a limiter are told apart by their bot id,
+/// see `BotToken::bot_id`: each of them gets its own global budget and its own queue for a chat.
+pub struct Limiter {
+    limits: Limits,
+    clock: Arc<dyn Clock>,
+    state: Mutex<State>,
+}
+
+impl Limiter {
+    pub fn new(limits: Limits) -> Self {
+        Self::with_clock(limits, Arc::new(SystemClock::new()))
+    }
+
+    pub fn with_clock(limits: Limits, clock: Arc<dyn Clock>) -> Self {
+        Self {
+            limits,
+            clock,
+            state: Mutex::new(State {
+                slots: HashMap::new(),
+                chats: HashMap::new(),
+                paused: HashMap::new(),
+                swept_at: Duration::ZERO,
+            }),
+        }
+    }
+
+    pub fn limits(&self) -> Limits {
+        self.limits
+    }
+
+    /// Reserves a slot for a message of the bot to the chat and returns how long to wait before
+    /// sending it.
+    pub fn reserve(&self, bot_id: u64, chat_id: &ChatUId) -> Duration {
+        let now = self.clock.now();
+        let interval = self.interval();
+        let key = Self::key_of(bot_id, chat_id);
+        let mut state = self.state.lock().unwrap();
+        state.sweep(
+            now,
+            self.limits.private.period.max(self.limits.group.period),
+        );
+
+        let limit = self.limit_for(chat_id);
+        let paused_until = state.paused.get(&bot_id).copied().unwrap_or_default();
+        let queue = state
+            .chats
+            .entry(key.clone())
+            .or_insert_with(|| Queue::new(limit));
+        queue.relax(now);
+        let ready = queue.ready_at(now).max(paused_until);
+
+        let oldest = now.saturating_sub(self.limits.global.period);
+        let oldest = Self::slot_of(oldest, interval);
+        let slots = state.slots.entry(bot_id).or_default();
+        *slots = slots.split_off(&oldest);
+
+        let mut slot = Self::slot_of(ready, interval);
+        if Self::time_of(slot, interval) < ready {
+            slot += 1;
+        }
+        while slots.contains(&slot) {
+            slot += 1;
+        }
+        slots.insert(slot);
+
+        let at = Self::time_of(slot, interval);
+        if let Some(queue) = state.chats.get_mut(&key) {
+            queue.push(at);
+        }
+
+        at.saturating_sub(now)
+    }
+
+    /// Blocks until a message of the bot to the chat may be sent.
+    pub fn acquire(&self, bot_id: u64, chat_id: &ChatUId) {
+        let delay = self.reserve(bot_id, chat_id);
+
+        if !delay.is_zero() {
+            self.clock.sleep(delay);
+        }
+    }
+
+    /// Adapts the limiter to a flood control error of the bot: the chat is paused for
+    /// `retry_after` seconds and its rate is halved, down to 1/8 of the configured limit, until a
+    /// quiet period passes without errors. Telegram doesn't tell which limit was hit, so the other
+    /// chats of the bot wait out `retry_after` as well.
+    pub fn penalize(&self, bot_id: u64, chat_id: &ChatUId, retry_after: i64) {
+        let now = self.clock.now();
+        let until = now + Duration::from_secs(retry_after.max(0) as u64);
+        let limit = self.limit_for(chat_id);
+        let mut state = self.state.lock().unwrap();
+
+        let paused = state.paused.entry(bot_id).or_default();
+        *paused = (*paused).max(until);
+
+        let queue = state
+            .chats
+            .entry(Self::key_of(bot_id, chat_id))
+            .or_insert_with(|| Queue::new(limit));
+        queue.relax(now);
+        queue.paused_until = until;
+        queue.relaxed_at = until;
+        queue.slowdown = (queue.slowdown * 2).min(MAX_SLOWDOWN);
+        queue.sent.clear();
+    }
+
+    /// Number of chat queues the limiter keeps.
+    pub(crate) fn queues(&self) -> usize {
+        self.state.lock().unwrap().chats.len()
+    }
+
+    /// Length of one global slot, rounded up so that a period never fits more than `count` slots.
+    fn interval(&self) -> Duration {
+        let global = self.limits.global;
+        let nanos = global
+            .period
+            .as_nanos()
+            .div_ceil(u128::from(global.count.max(1)));
+
+        Duration::from_nanos(nanos as u64)
+    }
+
+    fn limit_for(&self, chat_id: &ChatUId) -> Limit {
+        match chat_id {
+            ChatUId::I64(ChatId(id)) if *id > 0 => self.limits.private,
+            _ => self.limits.group,
+        }
+    }
+
+    fn key_of(bot_id: u64, chat_id: &ChatUId) -> (u64, String) {
+        let chat = match chat_id {
+            ChatUId::I64(ChatId(id)) => id.to_string(),
+            ChatUId::Username(username) => username.to_lowercase(),
+        };
+
+        (bot_id, chat)
+    }
+
+    fn slot_of(time: Duration, interval: Duration) -> u64 {
+        (time.as_nanos() / interval.as_nanos().max(1)) as u64
+    }
+
+    fn time_of(slot: u64, interval: Duration) -> Duration {
+        Duration::from_nanos((interval.as_nanos() as u64).saturating_mul(slot))
+    }
+}
+
+impl Debug for Limiter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Limiter")
+            .field("limits", &self.limits)
+            .finish()
+    }
+}
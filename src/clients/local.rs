@@ -0,0 +1,224 @@
+use crate::api::enums::file_input::FileInput;
+use crate::api::params::delete_webhook::DeleteWebhook;
+use crate::api::params::get_file::GetFile;
+use crate::api::requests::sync::Requests;
+use crate::api::types::file::File;
+use crate::api::types::user::User;
+use crate::clients::limiter::{Clock, SystemClock};
+use crate::clients::r#async::Async;
+use crate::clients::sync::Sync;
+use crate::clients::transport::Part;
+use crate::config::Mode;
+use crate::errors::Error;
+use std::cell::Cell;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+use url::Url;
+
+/// After logOut the bot can't log in to the cloud again, and close fails with 429 right after launch,
+/// for this long.
+pub const LOCKOUT: Duration = Duration::from_secs(600);
+
+/// Where the content of a file returned by getFile can be read from.
+#[derive(Debug, PartialEq)]
+pub enum FileLocation {
+    /// Absolute path on the disk of the local Bot API server.
+    Path(PathBuf),
+    /// Download link of the cloud Bot API, it contains the bot token.
+    Url(String),
+}
+
+impl Sync {
+    /// File on the disk of a local Bot API server, passed as a percent-encoded `file://` uri without
+    /// a size limit.
+    pub fn local_file<P: AsRef<Path>>(&self, path: P) -> Result<FileInput, Error> {
+        let path = path.as_ref();
+        let invalid = |reason: &str| Error::File {
+            path: path.display().to_string(),
+            reason: reason.to_string(),
+        };
+
+        if self.mode() != Mode::Local {
+            return Err(invalid(
+                "can be sent by path to a local Bot API server only",
+            ));
+        }
+        match Url::from_file_path(path) {
+            Ok(uri) => Ok(FileInput::String(uri.to_string())),
+            Err(_) => Err(invalid("must be an absolute path")),
+        }
+    }
+
+    /// Checks the size of a file before uploading it, only the cloud Bot API limits it.
+    pub fn check_upload<P: AsRef<Path>>(&self, path: P, size: u64) -> Result<(), Error> {
+        check_upload(self.mode(), path.as_ref(), size)
+    }
+
+    /// Checks the size of every file of a multipart body before it is uploaded.
+    pub(crate) fn check_parts(&self, parts: &[Part]) -> Result<(), Error> {
+        check_parts(self.mode(), parts)
+    }
+
+    /// Resolves a file returned by getFile: a path on a local server, a download link in the cloud.
+    pub fn file_location(&self, file: &File) -> Result<FileLocation, Error> {
+        let path = file.file_path.as_ref().ok_or_else(|| Error::File {
+            path: file.file_id.clone(),
+            reason: String::from("has no file_path, it is not available for download"),
+        })?;
+
+        if let (Some(limit), Some(size)) = (self.mode().download_limit(), file.file_size) {
+            if size as u64 > limit {
+                return Err(Error::File {
+                    path: path.clone(),
+                    reason: format!("is {} bytes, the download limit is {} bytes", size, limit),
+                });
+            }
+        }
+
+        match self.mode() {
+            Mode::Local => Ok(FileLocation::Path(PathBuf::from(path))),
+            Mode::Cloud => Ok(FileLocation::Url(format!(
+                "{}/file/bot{}/{}",
+                self.server(),
+                self.token().expose(),
+                path
+            ))),
+        }
+    }
+
+    /// Calls getFile and resolves the result with `file_location`.
+    pub fn get_file_location(&self, file_id: &str) -> Result<FileLocation, Error> {
+        let file = self.get_file(&GetFile {
+            file_id: file_id.to_string(),
+        })?;
+
+        self.file_location(&file)
+    }
+}
+
+impl Async {
+    /// Checks the size of a file before uploading it, only the cloud Bot API limits it.
+    pub fn check_upload<P: AsRef<Path>>(&self, path: P, size: u64) -> Result<(), Error> {
+        check_upload(self.mode(), path.as_ref(), size)
+    }
+
+    /// Checks the size of every file of a multipart body before it is uploaded.
+    pub(crate) fn check_parts(&self, parts: &[Part]) -> Result<(), Error> {
+        check_parts(self.mode(), parts)
+    }
+}
+
+fn check_upload(mode: Mode, path: &Path, size: u64) -> Result<(), Error> {
+    match mode.upload_limit() {
+        Some(limit) if size > limit => Err(Error::File {
+            path: path.display().to_string(),
+            reason: format!("is {} bytes, the upload limit is {} bytes", size, limit),
+        }),
+        _ => Ok(()),
+    }
+}
+
+fn check_parts(mode: Mode, parts: &[Part]) -> Result<(), Error> {
+    for part in parts {
+        if let Part::File {
+            file_name, content, ..
+        } = part
+        {
+            check_upload(mode, Path::new(file_name), content.len() as u64)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Moves a bot between the cloud Bot API and a local server, or between two local servers.
+/// Leaving the cloud logs the bot out of it, leaving a local server deletes the webhook and closes the
+/// bot instance there. The bot is then logged in to the target with getMe.
+pub struct Migration<'a> {
+    source: &'a Sync,
+    target: &'a Sync,
+    clock: Arc<dyn Clock>,
+    logged_out_at: Cell<Option<SystemTime>>,
+}
+
+impl<'a> Migration<'a> {
+    pub fn new(source: &'a Sync, target: &'a Sync) -> Self {
+        Self {
+            source,
+            target,
+            clock: Arc::new(SystemClock::new()),
+            logged_out_at: Cell::new(None),
+        }
+    }
+
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Time of the last logOut from the cloud, moving back to the cloud waits until 10 minutes passed.
+    /// The time is compared with the wall clock of `Clock`.
+    pub fn logged_out_at(self, at: SystemTime) -> Self {
+        self.logged_out_at.set(Some(at));
+        self
+    }
+
+    /// Time of the last logOut from the cloud, recorded by `run` when the bot leaves it. Pass it to
+    /// `logged_out_at` of the migration back to the cloud.
+    pub fn last_log_out(&self) -> Option<SystemTime> {
+        self.logged_out_at.get()
+    }
+
+    /// Runs the migration and returns the bot as seen by the target server.
+    pub fn run(&self) -> Result<User, Error> {
+        if self.source.mode() == Mode::Cloud && self.target.mode() == Mode::Cloud {
+            return Err(Error::Config {
+                field: String::from("url"),
+                reason: String::from("can't migrate from the cloud Bot API to itself"),
+            });
+        }
+
+        match self.source.mode() {
+            Mode::Cloud => {
+                self.source.log_out()?;
+                self.logged_out_at.set(Some(self.clock.system_time()));
+            }
+            Mode::Local => {
+                self.source.delete_webhook(&DeleteWebhook::default())?;
+                self.wait_out(|| self.source.close())?;
+            }
+        }
+
+        if let (Mode::Cloud, Some(at)) = (self.target.mode(), self.logged_out_at.get()) {
+            let elapsed = self
+                .clock
+                .system_time()
+                .duration_since(at)
+                .unwrap_or_default();
+            let locked_for = LOCKOUT.saturating_sub(elapsed);
+
+            if !locked_for.is_zero() {
+                self.clock.sleep(locked_for);
+            }
+        }
+
+        self.wait_out(|| self.target.get_me())
+    }
+
+    /// Repeats the call after every 429 response until the lockout is over.
+    fn wait_out<T, F: Fn() -> Result<T, Error>>(&self, call: F) -> Result<T, Error> {
+        let mut waited = Duration::ZERO;
+
+        loop {
+            match call() {
+                Err(Error::TooManyRequests { retry_after, .. }) if waited < LOCKOUT => {
+                    let delay = Duration::from_secs(retry_after.max(1) as u64);
+                    self.clock.sleep(delay);
+                    waited += delay;
+                }
+                result => return result,
+            }
+        }
+    }
+}
@@ -0,0 +1,199 @@
+use crate::api::enums::parse_mode::ParseMode;
+use crate::api::method::Method;
+use crate::api::params::send_animation::SendAnimation;
+use crate::api::params::send_document::SendDocument;
+use crate::api::params::send_message::SendMessage;
+use crate::api::params::send_photo::SendPhoto;
+use crate::api::params::send_video::SendVideo;
+use crate::api::params::send_voice::SendVoice;
+use crate::api::types::message::Message;
+use crate::api::types::message_entity::MessageEntity;
+use crate::api::types::message_id::MessageId;
+use crate::api::types::reply_parameters::ReplyParameters;
+use crate::clients::sync::Sync;
+use crate::errors::Error;
+use crate::format::split::{split, split_caption, split_html, split_html_caption, TEXT_LIMIT};
+use crate::format::Text;
+
+/// Text of one message with its parse mode or entities.
+type Part = (String, Option<ParseMode>, Option<Vec<MessageEntity>>);
+
+/// Media params with a caption, see `Sync::send_with_long_caption`.
+pub trait Captioned: Method<Output = Message> {
+    /// Takes the caption out of the params with its parse mode and entities, if there is one.
+    fn take_caption(&mut self) -> Option<Part>;
+
+    fn set_caption(&mut self, caption: Part);
+
+    /// Text message to the same chat and thread, with the same notification settings.
+    fn follow_up(&self) -> SendMessage;
+}
+
+macro_rules! captioned {
+    ($($params:ty),*) => {$(
+        impl Captioned for $params {
+            fn take_caption(&mut self) -> Option<Part> {
+                let caption = self.caption.take()?;
+                Some((caption, self.parse_mode.take(), self.caption_entities.take()))
+            }
+
+            fn set_caption(&mut self, (caption, parse_mode, entities): Part) {
+                self.caption = Some(caption);
+                self.parse_mode = parse_mode;
+                self.caption_entities = entities.filter(|entities| !entities.is_empty());
+            }
+
+            fn follow_up(&self) -> SendMessage {
+                SendMessage {
+                    chat_id: self.chat_id.clone(),
+                    message_thread_id: self.message_thread_id,
+                    disable_notification: self.disable_notification,
+                    protect_content: self.protect_content,
+                    ..Default::default()
+                }
+            }
+        }
+    )*};
+}
+
+captioned!(SendPhoto, SendVideo, SendAnimation, SendDocument, SendVoice);
+
+impl Sync {
+    /// Sends a text longer than Telegram allows as several messages, see `format::split`. Plain text
+    /// with entities and HTML are split as they are, Markdown is parsed with `format::Text` and sent
+    /// as plain text with entities.
+    /// The reply markup is attached to the last message only. With `reply_chain` every message
+    /// replies to the previous one. When a part fails after others were sent, the error is
+    /// `Error::PartiallySent` with the messages sent so far.
+    pub fn send_long_message(
+        &self,
+        params: &SendMessage,
+        reply_chain: bool,
+    ) -> Result<Vec<Message>, Error> {
+        let parts = split_parts(
+            &params.text,
+            params.parse_mode,
+            params.entities.as_deref().unwrap_or_default(),
+            false,
+        )?;
+
+        if parts.len() < 2 {
+            return self.execute(params).map(|message| vec![message]);
+        }
+
+        self.send_parts(params, parts, vec![], reply_chain)
+    }
+
+    /// Sends media with a caption longer than Telegram allows: the caption gets the first part, see
+    /// `format::split_caption`, and the rest follows in text messages, replying to the media with
+    /// `reply_chain`. The reply markup stays on the media message. When a text message fails, the
+    /// error is `Error::PartiallySent` with the messages sent so far.
+    pub fn send_with_long_caption<M: Captioned>(
+        &self,
+        mut media: M,
+        reply_chain: bool,
+    ) -> Result<Vec<Message>, Error> {
+        let Some((caption, parse_mode, entities)) = media.take_caption() else {
+            return self.execute(&media).map(|message| vec![message]);
+        };
+        let mut parts = split_parts(
+            &caption,
+            parse_mode,
+            entities.as_deref().unwrap_or_default(),
+            true,
+        )?;
+
+        if parts.len() < 2 {
+            media.set_caption((caption, parse_mode, entities));
+            return self.execute(&media).map(|message| vec![message]);
+        }
+
+        media.set_caption(parts.remove(0));
+        let message = self.execute(&media)?;
+
+        self.send_parts(&media.follow_up(), parts, vec![message], reply_chain)
+    }
+
+    /// Sends the parts as messages after the ones already sent.
+    fn send_parts(
+        &self,
+        params: &SendMessage,
+        parts: Vec<Part>,
+        mut messages: Vec<Message>,
+        reply_chain: bool,
+    ) -> Result<Vec<Message>, Error> {
+        let last = parts.len().saturating_sub(1);
+
+        for (index, (text, parse_mode, entities)) in parts.into_iter().enumerate() {
+            let reply_parameters = match messages.last().filter(|_| reply_chain) {
+                Some(previous) => Some(ReplyParameters {
+                    message_id: MessageId::from(previous.message_id.message_id),
+                    chat_id: None,
+                    allow_sending_without_reply: Some(true),
+                    quote: None,
+                    quote_parse_mode: None,
+                    quote_entities: None,
+                    quote_position: None,
+                }),
+                None => params.reply_parameters.clone(),
+            };
+            let part = SendMessage {
+                text,
+                parse_mode,
+                entities: entities.filter(|entities| !entities.is_empty()),
+                reply_parameters,
+                reply_markup: params.reply_markup.clone().filter(|_| index == last),
+                ..params.clone()
+            };
+
+            match self.execute(&part) {
+                Ok(message) => messages.push(message),
+                Err(error) if messages.is_empty() => return Err(error),
+                Err(error) => {
+                    return Err(Error::PartiallySent {
+                        sent: messages,
+                        error: Box::new(error),
+                    })
+                }
+            }
+        }
+
+        Ok(messages)
+    }
+}
+
+/// Splits a text in its parse mode into messages, the first one fitting in a caption if `caption`.
+fn split_parts(
+    text: &str,
+    parse_mode: Option<ParseMode>,
+    entities: &[MessageEntity],
+    caption: bool,
+) -> Result<Vec<Part>, Error> {
+    let entities_of = |text: &str, entities: &[MessageEntity]| match caption {
+        true => split_caption(text, entities),
+        false => split(text, entities, TEXT_LIMIT),
+    };
+    let parsed = |text: Text| {
+        let (text, entities) = text.to_entities();
+        entities_of(&text, &entities)
+    };
+    let with_entities = |parts: Vec<(String, Vec<MessageEntity>)>| {
+        parts
+            .into_iter()
+            .map(|(text, entities)| (text, None, Some(entities)))
+            .collect()
+    };
+
+    Ok(match parse_mode {
+        None => with_entities(entities_of(text, entities)),
+        Some(ParseMode::Html) => match caption {
+            true => split_html_caption(text),
+            false => split_html(text, TEXT_LIMIT),
+        }
+        .into_iter()
+        .map(|text| (text, Some(ParseMode::Html), None))
+        .collect(),
+        Some(ParseMode::MarkdownV2) => with_entities(parsed(Text::from_markdown_v2(text)?)),
+        Some(ParseMode::Markdown) => with_entities(parsed(Text::from_markdown(text)?)),
+    })
+}
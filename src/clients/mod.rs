@@ -1,3 +1,11 @@
 pub mod r#async;
+pub mod broadcast;
+pub mod limiter;
+pub mod local;
+pub mod long_message;
+pub(crate) mod policy;
+pub mod retry;
 pub mod sync;
 pub mod traits;
+pub mod transport;
+pub mod updates;
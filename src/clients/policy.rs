@@ -0,0 +1,124 @@
+use crate::api::enums::chat_uid::ChatUId;
+use crate::clients::limiter::Limiter;
+use crate::clients::retry::{Decision, Retry};
+use crate::clients::transport::Body;
+use crate::config::token::BotToken;
+use crate::errors::Error;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Methods that post messages to a chat and therefore count towards the flood limits.
+const THROTTLED: [&str; 3] = ["send", "forward", "copy"];
+
+/// Retry policy and limiter of a client, shared by `Sync` and `Async` which only differ in how they
+/// send the request and sleep.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Policy {
+    /// Bot the messages are throttled for when the limiter is shared.
+    pub(crate) bot_id: u64,
+    pub(crate) retry: Retry,
+    pub(crate) limiter: Option<Arc<Limiter>>,
+}
+
+/// Request of a method followed across its retries.
+#[derive(Debug)]
+pub(crate) struct Attempt<'a> {
+    method: &'a str,
+    idempotent: bool,
+    /// Backoff retries after network errors and 5xx responses so far.
+    retries: u32,
+    /// Retries after 429 responses so far, they don't use up the backoff budget.
+    floods: u32,
+    migrated: bool,
+    /// Chat the message is sent to, if the limiter throttles it.
+    chat_id: Option<ChatUId>,
+}
+
+impl<'a> Attempt<'a> {
+    pub(crate) fn new(method: &'a str, idempotent: bool) -> Self {
+        Self {
+            method,
+            idempotent,
+            retries: 0,
+            floods: 0,
+            migrated: false,
+            chat_id: None,
+        }
+    }
+}
+
+impl Policy {
+    /// No retries and no limiter for the bot of the token.
+    pub(crate) fn for_token(token: &BotToken) -> Self {
+        Self {
+            bot_id: token.bot_id().unwrap_or_default(),
+            ..Default::default()
+        }
+    }
+
+    /// Blocks until the limiter lets the message of the request through.
+    pub(crate) fn acquire(&self, attempt: &mut Attempt, body: &Body) {
+        attempt.chat_id = self.throttled_chat(attempt.method, body);
+
+        if let (Some(limiter), Some(chat_id)) = (&self.limiter, &attempt.chat_id) {
+            limiter.acquire(self.bot_id, chat_id);
+        }
+    }
+
+    /// Reserves a slot of the limiter for the message of the request and returns how long to wait
+    /// before sending it, for clients that can't block.
+    pub(crate) fn reserve(&self, attempt: &mut Attempt, body: &Body) -> Duration {
+        attempt.chat_id = self.throttled_chat(attempt.method, body);
+
+        match (&self.limiter, &attempt.chat_id) {
+            (Some(limiter), Some(chat_id)) => limiter.reserve(self.bot_id, chat_id),
+            _ => Duration::ZERO,
+        }
+    }
+
+    /// Handles a failed request: returns the delay before sending it again, with `chat_id` of the
+    /// body replaced after a migration, or the error when the request should not be repeated.
+    pub(crate) fn recover(
+        &self,
+        attempt: &mut Attempt,
+        body: &mut Body,
+        error: Error,
+    ) -> Result<Duration, Error> {
+        if let (Some(limiter), Some(chat_id), Error::TooManyRequests { retry_after, .. }) =
+            (&self.limiter, &attempt.chat_id, &error)
+        {
+            limiter.penalize(self.bot_id, chat_id, *retry_after);
+        }
+
+        let retries = match error {
+            Error::TooManyRequests { .. } => &mut attempt.floods,
+            _ => &mut attempt.retries,
+        };
+
+        match self
+            .retry
+            .decide(attempt.method, attempt.idempotent, *retries, &error)
+        {
+            Decision::Retry(delay) => {
+                *retries += 1;
+                Ok(delay)
+            }
+            Decision::Migrate(chat_id) if !attempt.migrated && body.chat_id().is_some() => {
+                body.set_chat_id(chat_id);
+                attempt.migrated = true;
+                Ok(Duration::ZERO)
+            }
+            _ => Err(error),
+        }
+    }
+
+    fn throttled_chat(&self, method: &str, body: &Body) -> Option<ChatUId> {
+        self.limiter.as_ref()?;
+
+        if !THROTTLED.iter().any(|prefix| method.starts_with(prefix)) {
+            return None;
+        }
+
+        serde_json::from_value::<ChatUId>(body.chat_id()?).ok()
+    }
+}
@@ -0,0 +1,109 @@
+use crate::errors::Error;
+use rand::Rng;
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// What the client should do with a failed request.
+#[derive(Debug, PartialEq)]
+pub enum Decision {
+    /// Give up and return the error.
+    Fail,
+    /// Send the same request again after the delay.
+    Retry(Duration),
+    /// Send the request again with `chat_id` replaced by the new identifier.
+    Migrate(i64),
+}
+
+/// Retry policy of a client.
+/// Flood control (429) and chat migration errors mean the request was rejected, so they are safe to
+/// retry for any method. Network errors and 5xx responses are retried with exponential backoff and
+/// jitter only within the per method budget.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Retry {
+    /// Retries on network errors and 5xx responses for idempotent methods.
+    pub attempts: u32,
+    /// Retries after a 429 response, each one sleeps exactly `retry_after` seconds.
+    pub flood_attempts: u32,
+    /// Delay before the first backoff retry, doubled on every next one.
+    pub base_delay: Duration,
+    /// Upper bound of the backoff delay.
+    pub max_delay: Duration,
+    /// Re-send to the new supergroup after `migrate_to_chat_id`.
+    pub follow_migration: bool,
+    budgets: HashMap<String, u32>,
+}
+
+impl Retry {
+    /// Recommended policy: 3 backoff retries starting at 500ms, 3 flood retries and migration following.
+    pub fn new() -> Self {
+        Self {
+            attempts: 3,
+            flood_attempts: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            follow_migration: true,
+            budgets: HashMap::new(),
+        }
+    }
+
+    /// Overrides the backoff budget of a method, e.g. `budget("sendMessage", 2)` to opt in to
+    /// possible duplicates of a send.
+    pub fn budget(mut self, method: &str, attempts: u32) -> Self {
+        self.budgets.insert(method.to_string(), attempts);
+        self
+    }
+
+    /// Backoff budget for the method, see `Method::IDEMPOTENT`. Methods called by name without
+    /// params struct are not known to be idempotent.
+    pub fn attempts_for(&self, method: &str, idempotent: bool) -> u32 {
+        match self.budgets.get(method) {
+            Some(attempts) => *attempts,
+            None if idempotent => self.attempts,
+            None => 0,
+        }
+    }
+
+    /// Decides what to do with a failed request of the method that was already retried `attempt`
+    /// times for the same kind of error: flood waits and backoff retries have separate budgets, a
+    /// redirect to a migrated chat doesn't count as an attempt.
+    pub fn decide(&self, method: &str, idempotent: bool, attempt: u32, error: &Error) -> Decision {
+        match error {
+            Error::TooManyRequests { retry_after, .. } if attempt < self.flood_attempts => {
+                Decision::Retry(Duration::from_secs(*retry_after as u64))
+            }
+            Error::MigrateToChat { id, .. } if self.follow_migration => Decision::Migrate(*id),
+            Error::Request(_) | Error::Transport(_) | Error::Server(_)
+                if attempt < self.attempts_for(method, idempotent) =>
+            {
+                Decision::Retry(self.backoff(attempt))
+            }
+            _ => Decision::Fail,
+        }
+    }
+
+    /// Exponential backoff with jitter: a random delay between half and the whole of
+    /// `base_delay * 2^attempt`, capped with `max_delay`.
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let delay = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.max_delay);
+        let half = delay / 2;
+
+        half + rand::thread_rng().gen_range(Duration::ZERO..=half)
+    }
+}
+
+impl Default for Retry {
+    /// No retries at all, every error is returned as is.
+    fn default() -> Self {
+        Self {
+            attempts: 0,
+            flood_attempts: 0,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            follow_migration: false,
+            budgets: HashMap::new(),
+        }
+    }
+}
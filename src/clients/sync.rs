@@ -1,136 +1,256 @@
+use crate::api::method::Method;
+use crate::api::params::close::Close;
 use crate::api::params::copy_message::CopyMessage;
 use crate::api::params::copy_messages::CopyMessages;
 use crate::api::params::delete_webhook::DeleteWebhook;
 use crate::api::params::forward_message::ForwardMessage;
 use crate::api::params::forward_messages::ForwardMessages;
+use crate::api::params::get_file::GetFile;
+use crate::api::params::get_me::GetMe;
 use crate::api::params::get_update::GetUpdate;
+use crate::api::params::get_webhook_info::GetWebhookInfo;
+use crate::api::params::log_out::LogOut;
 use crate::api::params::send_message::SendMessage;
+use crate::api::params::set_passport_data_errors::SetPassportDataErrors;
 use crate::api::params::set_webhook::SetWebhook;
 use crate::api::requests::sync::Requests;
-use crate::api::responses::error::ResponseError;
-use crate::api::responses::result::ResponseResult;
+use crate::api::types::file::File;
 use crate::api::types::message::Message;
 use crate::api::types::message_id::MessageId;
 use crate::api::types::update::Update;
 use crate::api::types::user::User;
 use crate::api::types::webhook_info::WebhookInfo;
-use crate::clients::traits::{Decoder, Requester, Responder};
-use crate::config::Config;
+use crate::api::validate::Validate;
+use crate::clients::limiter::Limiter;
+use crate::clients::policy::{Attempt, Policy};
+use crate::clients::retry::Retry;
+use crate::clients::traits::{Decoder, Transport};
+use crate::clients::transport::http::ReqwestTransport;
+use crate::clients::transport::{Body, Part};
+use crate::config::token::BotToken;
+use crate::config::{Config, Mode};
 use crate::errors::Error;
-use reqwest::blocking::{ClientBuilder, RequestBuilder, Response};
 use serde::de::DeserializeOwned;
-use std::time::Duration;
+use serde::Serialize;
+use serde_json::Value;
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+use std::thread;
 
-#[derive(Debug)]
 pub struct Sync {
-    client: reqwest::blocking::Client,
+    transport: Arc<dyn Transport>,
     offset: i64,
     limit: i64,
     timeout: u64,
-    url: String,
+    server: String,
+    token: BotToken,
+    mode: Mode,
+    policy: Policy,
 }
 
 impl Sync {
-    pub fn new(config: &Config) -> Self {
-        let client = ClientBuilder::new()
-            .timeout(Duration::from_secs(config.timeout))
-            .connect_timeout(Duration::from_secs(config.connect_timeout))
-            .build()
-            .unwrap();
+    /// Builds the HTTP client from the config, fails on a broken proxy or certificate.
+    pub fn new(config: &Config) -> Result<Self, Error> {
+        Ok(Self::with_client(config, config.build_sync_client()?))
+    }
+
+    /// Uses the given HTTP client instead of building one from the config.
+    pub fn with_client(config: &Config, client: reqwest::blocking::Client) -> Self {
+        Self::with_transport(config, Arc::new(ReqwestTransport::new(client, config)))
+    }
 
+    /// Sends the requests through the given transport, e.g. `UreqTransport` or `MemoryTransport`.
+    pub fn with_transport(config: &Config, transport: Arc<dyn Transport>) -> Self {
         let offset = config.updates_offset;
         let limit = config.updates_limit;
         let timeout = config.updates_timeout;
-        let url = config.build_url();
+        let server = config.url.clone();
+        let token = config.token.clone();
+        let mode = config.mode;
 
         Self {
-            client,
+            transport,
             offset,
             limit,
             timeout,
-            url,
+            server,
+            token,
+            mode,
+            policy: Policy::for_token(&config.token),
         }
     }
-}
 
-impl Decoder for Sync {
-    fn decode<T: DeserializeOwned>(&self, response: Response) -> Result<T, Error> {
-        match serde_json::from_str::<ResponseResult<T>>(&response.text().unwrap()) {
-            Ok(success) => Ok(success.result),
-            Err(error) => Err(Error::Decode(error)),
-        }
+    pub fn set_retry(&mut self, retry: Retry) {
+        self.policy.retry = retry;
     }
-}
 
-impl Requester for Sync {
-    fn request_for(&self, method: &str) -> RequestBuilder {
-        self.client.post(format!("{}{}", self.url, method))
+    /// Throttles the messages sent by this client, the limiter may be shared with other clients.
+    pub fn set_limiter(&mut self, limiter: Arc<Limiter>) {
+        self.policy.limiter = Some(limiter);
+    }
+
+    pub fn limiter(&self) -> Option<&Arc<Limiter>> {
+        self.policy.limiter.as_ref()
+    }
+
+    pub fn mode(&self) -> Mode {
+        self.mode
     }
-}
 
-impl Responder for Sync {
-    fn respond_with<T: DeserializeOwned>(
+    /// Overrides the mode taken from the config.
+    pub fn set_mode(&mut self, mode: Mode) {
+        self.mode = mode;
+    }
+
+    /// Url of the Bot API server without the token.
+    pub(crate) fn server(&self) -> &str {
+        &self.server
+    }
+
+    pub(crate) fn token(&self) -> &BotToken {
+        &self.token
+    }
+
+    /// Calls the endpoint of the params and returns its result, also for endpoints without a
+    /// convenience method in `Requests`. The params are validated first, see `Validate`.
+    pub fn execute<M: Method>(&self, method: &M) -> Result<M::Output, Error> {
+        let body = serde_json::to_value(method).map_err(Error::Decode)?;
+
+        self.execute_body(M::NAME, M::IDEMPOTENT, Some(method), Body::Json(body))
+    }
+
+    /// Calls any method by name, e.g. one added by a newer Bot API version than this crate knows.
+    /// The request is retried and its errors are decoded the same way as for the built-in methods,
+    /// except that network errors and 5xx responses are only retried with a budget for the method.
+    pub fn call<P: Serialize, R: DeserializeOwned>(
         &self,
-        response: Result<Response, reqwest::Error>,
+        method: &str,
+        params: &P,
+    ) -> Result<R, Error> {
+        let body = serde_json::to_value(params).map_err(Error::Decode)?;
+
+        self.execute_body(method, false, None, Body::Json(body))
+    }
+
+    /// Untyped `call`: the params and the result are plain JSON. Untyped bodies are checked for
+    /// what can be told without their params type, the text, captions and reply markup.
+    pub fn call_raw(&self, method: &str, body: Value) -> Result<Value, Error> {
+        self.execute_body(method, false, None, Body::Json(body))
+    }
+
+    /// Untyped `call` uploading files as `multipart/form-data`.
+    pub fn call_multipart<R: DeserializeOwned>(
+        &self,
+        method: &str,
+        parts: Vec<Part>,
+    ) -> Result<R, Error> {
+        self.execute_body(method, false, None, Body::Multipart(parts))
+    }
+
+    /// Sends the body to the method and retries the request according to the retry policy.
+    /// The typed params are validated first, an untyped body is checked by `Body::validate`.
+    pub(crate) fn execute_body<T: DeserializeOwned>(
+        &self,
+        method: &str,
+        idempotent: bool,
+        params: Option<&dyn Validate>,
+        mut body: Body,
     ) -> Result<T, Error> {
-        match response {
-            Ok(response) => match response.status().as_u16() {
-                200 => self.decode::<T>(response),
-                _ => Err(Error::Response(ResponseError::new(
-                    &response.text().unwrap(),
-                ))),
-            },
-            Err(error) => Err(Error::Request(error)),
+        match params {
+            Some(params) => params.validate()?,
+            None => body.validate(method)?,
+        }
+        if let Body::Multipart(parts) = &body {
+            self.check_parts(parts)?;
+        }
+        let mut attempt = Attempt::new(method, idempotent);
+
+        loop {
+            self.policy.acquire(&mut attempt, &body);
+            let reply = self.transport.send(method, &body);
+            let error = match reply.and_then(|reply| self.decode::<T>(reply)) {
+                Ok(result) => return Ok(result),
+                Err(error) => error,
+            };
+
+            let delay = self.policy.recover(&mut attempt, &mut body, error)?;
+            if !delay.is_zero() {
+                thread::sleep(delay);
+            }
         }
     }
 }
 
+impl Debug for Sync {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Sync")
+            .field("offset", &self.offset)
+            .field("limit", &self.limit)
+            .field("timeout", &self.timeout)
+            .field("transport", &self.transport)
+            .field("mode", &self.mode)
+            .field("retry", &self.policy.retry)
+            .field("limiter", &self.policy.limiter)
+            .finish()
+    }
+}
+
+impl Decoder for Sync {}
+
 impl Requests for Sync {
     fn get_updates(&self, params: &GetUpdate) -> Result<Vec<Update>, Error> {
-        self.respond_with::<Vec<Update>>(self.request_for("getUpdates").json(params).send())
+        self.execute(params)
     }
 
     fn set_webhook(&self, params: &SetWebhook) -> Result<bool, Error> {
-        self.respond_with::<bool>(self.request_for("setWebhook").json(params).send())
+        self.execute(params)
     }
 
     fn delete_webhook(&self, params: &DeleteWebhook) -> Result<bool, Error> {
-        self.respond_with::<bool>(self.request_for("deleteWebhook").json(params).send())
+        self.execute(params)
     }
 
     fn get_webhook_info(&self) -> Result<WebhookInfo, Error> {
-        self.respond_with::<WebhookInfo>(self.request_for("getWebhookInfo").json(&{}).send())
+        self.execute(&GetWebhookInfo {})
     }
 
     fn get_me(&self) -> Result<User, Error> {
-        self.respond_with::<User>(self.request_for("getMe").json(&{}).send())
+        self.execute(&GetMe {})
     }
 
     fn log_out(&self) -> Result<bool, Error> {
-        self.respond_with::<bool>(self.request_for("logOut").json(&{}).send())
+        self.execute(&LogOut {})
     }
 
     fn close(&self) -> Result<bool, Error> {
-        self.respond_with::<bool>(self.request_for("close").json(&{}).send())
+        self.execute(&Close {})
     }
 
     fn send_message(&self, params: &SendMessage) -> Result<Message, Error> {
-        self.respond_with::<Message>(self.request_for("sendMessage").json(params).send())
+        self.execute(params)
     }
 
-    fn forward_message(&self, params: &ForwardMessage) -> Result<MessageId, Error> {
-        self.respond_with::<MessageId>(self.request_for("forwardMessage").json(params).send())
+    fn forward_message(&self, params: &ForwardMessage) -> Result<Message, Error> {
+        self.execute(params)
     }
 
     fn forward_messages(&self, params: &ForwardMessages) -> Result<Vec<MessageId>, Error> {
-        self.respond_with::<Vec<MessageId>>(self.request_for("forwardMessages").json(params).send())
+        self.execute(params)
     }
 
     fn copy_message(&self, params: &CopyMessage) -> Result<MessageId, Error> {
-        self.respond_with::<MessageId>(self.request_for("copyMessage").json(params).send())
+        self.execute(params)
     }
 
     fn copy_messages(&self, params: &CopyMessages) -> Result<Vec<MessageId>, Error> {
-        self.respond_with::<Vec<MessageId>>(self.request_for("copyMessages").json(params).send())
+        self.execute(params)
+    }
+
+    fn get_file(&self, params: &GetFile) -> Result<File, Error> {
+        self.execute(params)
+    }
+
+    fn set_passport_data_errors(&self, params: &SetPassportDataErrors) -> Result<bool, Error> {
+        self.execute(params)
     }
 }
@@ -1,22 +1,39 @@
+use crate::api::responses::error::ResponseError;
+use crate::api::responses::result::ResponseResult;
+use crate::clients::transport::{Body, Reply};
 use crate::errors::Error;
-use reqwest::blocking::{RequestBuilder, Response};
 use serde::de::DeserializeOwned;
+use std::fmt::Debug;
+use std::future::Future;
+use std::pin::Pin;
 
 pub trait Encoder {
     fn encode<P>(params: &P) -> Result<String, Error>;
 }
 
+/// Turns a reply of the Bot API server into the result of the method, whatever transport carried it.
 pub trait Decoder {
-    fn decode<T: DeserializeOwned>(&self, response: Response) -> Result<T, Error>;
+    fn decode<T: DeserializeOwned>(&self, reply: Reply) -> Result<T, Error> {
+        match reply.status {
+            200 => match serde_json::from_slice::<ResponseResult<T>>(&reply.body) {
+                Ok(success) => Ok(success.result),
+                Err(error) => Err(Error::Decode(error)),
+            },
+            status => Err(Error::from(ResponseError::new(status, &reply.text()))),
+        }
+    }
 }
 
-pub trait Requester {
-    fn request_for(&self, method: &str) -> RequestBuilder;
+/// Sends the request of a Bot API method and returns the raw reply, non-200 statuses included.
+/// The transport knows the server url and the token, its `Debug` output must not reveal the token.
+pub trait Transport: Debug + Send + std::marker::Sync {
+    fn send(&self, method: &str, body: &Body) -> Result<Reply, Error>;
 }
 
-pub trait Responder: Decoder {
-    fn respond_with<T: DeserializeOwned>(
-        &self,
-        response: Result<Response, reqwest::Error>,
-    ) -> Result<T, Error>;
+/// Reply of `AsyncTransport::send`, boxed so that the transport can be used as a trait object.
+pub type SendFuture<'a> = Pin<Box<dyn Future<Output = Result<Reply, Error>> + Send + 'a>>;
+
+/// Async counterpart of `Transport`, used by `Async`.
+pub trait AsyncTransport: Debug + Send + std::marker::Sync {
+    fn send<'a>(&'a self, method: &'a str, body: &'a Body) -> SendFuture<'a>;
 }
@@ -0,0 +1,181 @@
+use crate::api::enums::input_media::InputMedia;
+use crate::api::enums::reply_markup::ReplyMarkup;
+use crate::api::validate;
+use crate::errors::Error;
+use crate::format::split::{CAPTION_LIMIT, TEXT_LIMIT};
+use serde_json::Value;
+use std::fmt::Write;
+
+pub mod http;
+pub mod memory;
+#[cfg(feature = "ureq")]
+pub mod ureq;
+
+/// Body of a Bot API request.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Body {
+    Json(Value),
+    /// `multipart/form-data`, used to upload files.
+    Multipart(Vec<Part>),
+}
+
+/// Methods whose `text` is the text of a message.
+const TEXT_METHODS: [&str; 2] = ["sendMessage", "editMessageText"];
+
+/// Field of a multipart body.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Part {
+    Text {
+        name: String,
+        value: String,
+    },
+    File {
+        name: String,
+        file_name: String,
+        content: Vec<u8>,
+    },
+}
+
+impl Part {
+    pub fn name(&self) -> &str {
+        match self {
+            Part::Text { name, .. } => name,
+            Part::File { name, .. } => name,
+        }
+    }
+}
+
+impl Body {
+    /// Value of the `chat_id` field, if the request has one.
+    pub fn chat_id(&self) -> Option<Value> {
+        match self {
+            Body::Json(value) => value.get("chat_id").cloned(),
+            Body::Multipart(parts) => parts.iter().find_map(|part| match part {
+                Part::Text { name, value } if name == "chat_id" => Some(
+                    serde_json::from_str(value).unwrap_or_else(|_| Value::from(value.as_str())),
+                ),
+                _ => None,
+            }),
+        }
+    }
+
+    /// Checks what the body of an untyped request tells about the documented constraints: the
+    /// length of the text and caption without a parse mode, the captions of the media and the
+    /// reply markup. Typed params are checked by their `Validate` impl instead.
+    pub(crate) fn validate(&self, method: &str) -> Result<(), Error> {
+        let text = |field: &str| match self {
+            Body::Json(value) => value.get(field).and_then(Value::as_str).map(str::to_string),
+            Body::Multipart(parts) => parts.iter().find_map(|part| match part {
+                Part::Text { name, value } if name == field => Some(value.clone()),
+                _ => None,
+            }),
+        };
+        let json = |field: &str| match self {
+            Body::Json(value) => value.get(field).cloned(),
+            Body::Multipart(_) => serde_json::from_str::<Value>(&text(field)?).ok(),
+        };
+
+        if text("parse_mode").is_none() {
+            if let Some(text) = text("text").filter(|_| TEXT_METHODS.contains(&method)) {
+                validate::length("text", &text, 1, TEXT_LIMIT)?;
+            }
+            if let Some(caption) = text("caption") {
+                validate::length("caption", &caption, 0, CAPTION_LIMIT)?;
+            }
+        }
+
+        match json("media") {
+            Some(media @ Value::Array(_)) => {
+                if let Ok(media) = serde_json::from_value::<Vec<InputMedia>>(media) {
+                    validate::nested("media", &media)?;
+                }
+            }
+            Some(media @ Value::Object(_)) => {
+                if let Ok(media) = serde_json::from_value::<InputMedia>(media) {
+                    validate::nested("media", &media)?;
+                }
+            }
+            _ => {}
+        }
+
+        match json("reply_markup").map(serde_json::from_value::<ReplyMarkup>) {
+            Some(Ok(markup)) => validate::nested("reply_markup", &markup),
+            _ => Ok(()),
+        }
+    }
+
+    /// Replaces the `chat_id` field, e.g. after a group was migrated to a supergroup.
+    pub fn set_chat_id(&mut self, chat_id: i64) {
+        match self {
+            Body::Json(value) => value["chat_id"] = Value::from(chat_id),
+            Body::Multipart(parts) => {
+                for part in parts.iter_mut() {
+                    if let Part::Text { name, value } = part {
+                        if name == "chat_id" {
+                            *value = chat_id.to_string();
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Encodes the parts as `multipart/form-data` with the boundary, for transports without their own
+    /// multipart support.
+    pub fn encode_multipart(parts: &[Part], boundary: &str) -> Vec<u8> {
+        let mut body = vec![];
+
+        for part in parts {
+            let mut header = format!("--{}\r\n", boundary);
+            match part {
+                Part::Text { name, .. } => {
+                    let _ = write!(
+                        header,
+                        "Content-Disposition: form-data; name=\"{}\"\r\n\r\n",
+                        name
+                    );
+                }
+                Part::File {
+                    name, file_name, ..
+                } => {
+                    let _ = write!(
+                        header,
+                        "Content-Disposition: form-data; name=\"{}\"; filename=\"{}\"\r\n\
+                         Content-Type: application/octet-stream\r\n\r\n",
+                        name, file_name
+                    );
+                }
+            }
+
+            body.extend_from_slice(header.as_bytes());
+            match part {
+                Part::Text { value, .. } => body.extend_from_slice(value.as_bytes()),
+                Part::File { content, .. } => body.extend_from_slice(content),
+            }
+            body.extend_from_slice(b"\r\n");
+        }
+        body.extend_from_slice(format!("--{}--\r\n", boundary).as_bytes());
+
+        body
+    }
+}
+
+/// Raw response of the Bot API server.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Reply {
+    pub status: u16,
+    pub body: Vec<u8>,
+}
+
+impl Reply {
+    pub fn new(status: u16, body: &str) -> Self {
+        Self {
+            status,
+            body: body.as_bytes().to_vec(),
+        }
+    }
+
+    pub fn text(&self) -> String {
+        String::from_utf8_lossy(&self.body).into_owned()
+    }
+}
@@ -0,0 +1,144 @@
+use crate::clients::traits::{AsyncTransport, SendFuture, Transport};
+use crate::clients::transport::{Body, Part, Reply};
+use crate::config::token::BotToken;
+use crate::config::Config;
+use crate::errors::Error;
+use reqwest::blocking::multipart::{Form, Part as FormPart};
+use reqwest::multipart::{Form as AsyncForm, Part as AsyncFormPart};
+use std::fmt::{Debug, Formatter};
+
+/// Default transport on top of the blocking reqwest client.
+pub struct ReqwestTransport {
+    client: reqwest::blocking::Client,
+    url: String,
+    token: BotToken,
+}
+
+impl ReqwestTransport {
+    pub fn new(client: reqwest::blocking::Client, config: &Config) -> Self {
+        Self {
+            client,
+            url: config.build_url(),
+            token: config.token.clone(),
+        }
+    }
+
+    fn form(parts: &[Part]) -> Form {
+        parts.iter().fold(Form::new(), |form, part| match part {
+            Part::Text { name, value } => form.text(name.clone(), value.clone()),
+            Part::File {
+                name,
+                file_name,
+                content,
+            } => form.part(
+                name.clone(),
+                FormPart::bytes(content.clone()).file_name(file_name.clone()),
+            ),
+        })
+    }
+
+    fn request_error(&self, error: reqwest::Error) -> Error {
+        Error::Request(self.token.redact_error(error))
+    }
+}
+
+impl Transport for ReqwestTransport {
+    fn send(&self, method: &str, body: &Body) -> Result<Reply, Error> {
+        let request = self.client.post(format!("{}{}", self.url, method));
+        let request = match body {
+            Body::Json(value) => request.json(value),
+            Body::Multipart(parts) => request.multipart(Self::form(parts)),
+        };
+
+        let response = request.send().map_err(|error| self.request_error(error))?;
+        let status = response.status().as_u16();
+        let body = response
+            .bytes()
+            .map_err(|error| self.request_error(error))?;
+
+        Ok(Reply {
+            status,
+            body: body.to_vec(),
+        })
+    }
+}
+
+impl Debug for ReqwestTransport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ReqwestTransport")
+            .field("url", &self.token.redact(&self.url))
+            .finish()
+    }
+}
+
+/// Default transport of `Async`, on top of the async reqwest client.
+pub struct AsyncReqwestTransport {
+    client: reqwest::Client,
+    url: String,
+    token: BotToken,
+}
+
+impl AsyncReqwestTransport {
+    pub fn new(client: reqwest::Client, config: &Config) -> Self {
+        Self {
+            client,
+            url: config.build_url(),
+            token: config.token.clone(),
+        }
+    }
+
+    fn form(parts: &[Part]) -> AsyncForm {
+        parts
+            .iter()
+            .fold(AsyncForm::new(), |form, part| match part {
+                Part::Text { name, value } => form.text(name.clone(), value.clone()),
+                Part::File {
+                    name,
+                    file_name,
+                    content,
+                } => form.part(
+                    name.clone(),
+                    AsyncFormPart::bytes(content.clone()).file_name(file_name.clone()),
+                ),
+            })
+    }
+
+    fn request_error(&self, error: reqwest::Error) -> Error {
+        Error::Request(self.token.redact_error(error))
+    }
+}
+
+impl AsyncTransport for AsyncReqwestTransport {
+    fn send<'a>(&'a self, method: &'a str, body: &'a Body) -> SendFuture<'a> {
+        Box::pin(async move {
+            let request = self.client.post(format!("{}{}", self.url, method));
+            let request = match body {
+                Body::Json(value) => request.json(value),
+                Body::Multipart(parts) => request.multipart(Self::form(parts)),
+            };
+
+            let response = request
+                .send()
+                .await
+                .map_err(|error| self.request_error(error))?;
+            let status = response.status().as_u16();
+            let body = response
+                .bytes()
+                .await
+                .map_err(|error| self.request_error(error))?;
+
+            Ok(Reply {
+                status,
+                body: body.to_vec(),
+            })
+        })
+    }
+}
+
+impl Debug for AsyncReqwestTransport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AsyncReqwestTransport")
+            .field("url", &self.token.redact(&self.url))
+            .finish()
+    }
+}
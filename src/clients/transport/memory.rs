@@ -0,0 +1,65 @@
+use crate::clients::traits::{AsyncTransport, SendFuture, Transport};
+use crate::clients::transport::{Body, Reply};
+use crate::errors::Error;
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+
+/// Request received by `MemoryTransport`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Request {
+    pub method: String,
+    pub body: Body,
+}
+
+/// Transport that never leaves the process: it answers with queued replies and records the requests.
+#[derive(Debug, Default)]
+pub struct MemoryTransport {
+    replies: Mutex<HashMap<String, VecDeque<Reply>>>,
+    requests: Mutex<Vec<Request>>,
+}
+
+impl MemoryTransport {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queues a reply to the method. Replies are used in order and the last one is repeated.
+    pub fn reply(&self, method: &str, status: u16, body: &str) -> &Self {
+        let mut replies = self.replies.lock().unwrap();
+        replies
+            .entry(method.to_string())
+            .or_default()
+            .push_back(Reply::new(status, body));
+        self
+    }
+
+    /// Requests received so far, oldest first.
+    pub fn requests(&self) -> Vec<Request> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+impl Transport for MemoryTransport {
+    fn send(&self, method: &str, body: &Body) -> Result<Reply, Error> {
+        self.requests.lock().unwrap().push(Request {
+            method: method.to_string(),
+            body: body.clone(),
+        });
+
+        let mut replies = self.replies.lock().unwrap();
+        let queue = replies.get_mut(method);
+        let reply = match queue {
+            Some(queue) if queue.len() > 1 => queue.pop_front(),
+            Some(queue) => queue.front().cloned(),
+            None => None,
+        };
+
+        reply.ok_or_else(|| Error::Transport(format!("no reply for {}", method)))
+    }
+}
+
+impl AsyncTransport for MemoryTransport {
+    fn send<'a>(&'a self, method: &'a str, body: &'a Body) -> SendFuture<'a> {
+        Box::pin(std::future::ready(Transport::send(self, method, body)))
+    }
+}
@@ -0,0 +1,108 @@
+use crate::clients::traits::Transport;
+use crate::clients::transport::{Body, Reply};
+use crate::config::token::BotToken;
+use crate::config::Config;
+use crate::errors::Error;
+use std::collections::hash_map::RandomState;
+use std::fmt::{Debug, Formatter};
+use std::hash::{BuildHasher, Hasher};
+use std::io::Read;
+use std::time::Duration;
+
+/// Transport on top of ureq, for small binaries without an async runtime.
+/// `no_proxy`, custom certificates and HTTP/2 are not supported, setting them is an error.
+pub struct UreqTransport {
+    agent: ureq::Agent,
+    url: String,
+    token: BotToken,
+}
+
+impl UreqTransport {
+    /// Agent configured with the timeouts, proxy and user agent of the config.
+    pub fn new(config: &Config) -> Result<Self, Error> {
+        let unsupported = [
+            ("no_proxy", config.no_proxy.is_some()),
+            ("root_certificates", !config.root_certificates.is_empty()),
+            ("client_certificate", config.client_certificate.is_some()),
+            ("http2_prior_knowledge", config.http2_prior_knowledge),
+        ];
+        if let Some((field, _)) = unsupported.iter().find(|(_, set)| *set) {
+            return Err(Error::Config {
+                field: field.to_string(),
+                reason: String::from("is not supported by the ureq transport"),
+            });
+        }
+
+        let mut builder = ureq::AgentBuilder::new()
+            .timeout(Duration::from_secs(config.timeout))
+            .timeout_connect(Duration::from_secs(config.connect_timeout));
+
+        if let Some(proxy) = &config.proxy {
+            let proxy = ureq::Proxy::new(proxy).map_err(|error| Error::Config {
+                field: String::from("proxy"),
+                reason: error.to_string(),
+            })?;
+            builder = builder.proxy(proxy);
+        }
+        if let Some(user_agent) = &config.user_agent {
+            builder = builder.user_agent(user_agent);
+        }
+
+        Ok(Self::with_agent(builder.build(), config))
+    }
+
+    pub fn with_agent(agent: ureq::Agent, config: &Config) -> Self {
+        Self {
+            agent,
+            url: config.build_url(),
+            token: config.token.clone(),
+        }
+    }
+}
+
+impl Transport for UreqTransport {
+    fn send(&self, method: &str, body: &Body) -> Result<Reply, Error> {
+        let request = self.agent.post(&format!("{}{}", self.url, method));
+        let result = match body {
+            Body::Json(value) => request
+                .set("Content-Type", "application/json")
+                .send_string(&value.to_string()),
+            Body::Multipart(parts) => {
+                let boundary = format!(
+                    "telegram-{:016x}",
+                    RandomState::new().build_hasher().finish()
+                );
+                request
+                    .set(
+                        "Content-Type",
+                        &format!("multipart/form-data; boundary={}", boundary),
+                    )
+                    .send_bytes(&Body::encode_multipart(parts, &boundary))
+            }
+        };
+
+        let response = match result {
+            Ok(response) => response,
+            Err(ureq::Error::Status(_, response)) => response,
+            Err(ureq::Error::Transport(error)) => {
+                return Err(Error::Transport(self.token.redact(&error.to_string())))
+            }
+        };
+        let status = response.status();
+        let mut body = vec![];
+        response
+            .into_reader()
+            .read_to_end(&mut body)
+            .map_err(Error::Io)?;
+
+        Ok(Reply { status, body })
+    }
+}
+
+impl Debug for UreqTransport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UreqTransport")
+            .field("url", &self.token.redact(&self.url))
+            .finish()
+    }
+}
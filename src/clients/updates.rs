@@ -0,0 +1,60 @@
+use crate::api::method::Method;
+use crate::api::params::get_update::GetUpdate;
+use crate::api::types::update::Update;
+use crate::clients::sync::Sync;
+use crate::errors::Error;
+use serde::Deserialize;
+use serde_json::Value;
+
+/// Update of a batch that couldn't be decoded, e.g. because of a type changed by a newer Bot API.
+#[derive(Debug, PartialEq)]
+pub struct SkippedUpdate {
+    pub update_id: Option<i64>,
+    pub raw: Value,
+    pub reason: String,
+}
+
+/// Updates returned by one getUpdates call, decoded one by one.
+#[derive(Debug, Default, PartialEq)]
+pub struct UpdateBatch {
+    pub updates: Vec<Update>,
+    pub skipped: Vec<SkippedUpdate>,
+    /// Offset of the next getUpdates call, it confirms the skipped updates too so they aren't
+    /// received again.
+    pub next_offset: Option<i64>,
+}
+
+impl UpdateBatch {
+    pub fn decode(raw: Vec<Value>) -> Self {
+        let mut batch = Self::default();
+
+        for value in raw {
+            let update_id = value.get("update_id").and_then(Value::as_i64);
+            if let Some(update_id) = update_id {
+                batch.next_offset = batch.next_offset.max(Some(update_id + 1));
+            }
+
+            // Decoded from a reference, so the value is only kept when the update is skipped.
+            match Update::deserialize(&value) {
+                Ok(update) => batch.updates.push(update),
+                Err(error) => batch.skipped.push(SkippedUpdate {
+                    update_id,
+                    raw: value,
+                    reason: error.to_string(),
+                }),
+            }
+        }
+
+        batch
+    }
+}
+
+impl Sync {
+    /// Calls getUpdates like `get_updates`, but an update that can't be decoded is skipped instead of
+    /// failing the whole batch.
+    pub fn get_updates_batch(&self, params: &GetUpdate) -> Result<UpdateBatch, Error> {
+        let raw = self.call::<_, Vec<Value>>(GetUpdate::NAME, params)?;
+
+        Ok(UpdateBatch::decode(raw))
+    }
+}
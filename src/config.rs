@@ -1,6 +1,71 @@
+use crate::config::layer::Layer;
+use crate::config::token::BotToken;
+use crate::errors::Error;
+use reqwest::NoProxy;
+use serde::Deserialize;
+use std::ffi::OsString;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::Duration;
 use structopt::StructOpt;
 
-#[derive(StructOpt, Debug, PartialEq)]
+pub mod layer;
+pub mod token;
+
+/// Address of the cloud Bot API server.
+pub const API_URL: &str = "https://api.telegram.org";
+
+/// Bots can upload files of up to 50 MB to the cloud Bot API server.
+pub const CLOUD_UPLOAD_LIMIT: u64 = 50 * 1024 * 1024;
+
+/// Bots can download files of up to 20 MB from the cloud Bot API server.
+pub const CLOUD_DOWNLOAD_LIMIT: u64 = 20 * 1024 * 1024;
+
+/// https://core.telegram.org/bots/api#using-a-local-bot-api-server
+/// Kind of the Bot API server the client talks to, set explicitly as the url alone can't tell a
+/// local server from a proxy in front of the cloud one.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Mode {
+    /// `https://api.telegram.org` with its file size limits.
+    #[default]
+    Cloud,
+    /// Self-hosted telegram-bot-api: files are passed as local paths and have no size limits.
+    Local,
+}
+
+impl Mode {
+    /// Maximum size of an uploaded file, `None` when unlimited.
+    pub fn upload_limit(&self) -> Option<u64> {
+        match self {
+            Mode::Cloud => Some(CLOUD_UPLOAD_LIMIT),
+            Mode::Local => None,
+        }
+    }
+
+    /// Maximum size of a file returned by getFile, `None` when unlimited.
+    pub fn download_limit(&self) -> Option<u64> {
+        match self {
+            Mode::Cloud => Some(CLOUD_DOWNLOAD_LIMIT),
+            Mode::Local => None,
+        }
+    }
+}
+
+impl FromStr for Mode {
+    type Err = String;
+
+    fn from_str(mode: &str) -> Result<Self, Self::Err> {
+        match mode {
+            "cloud" => Ok(Mode::Cloud),
+            "local" => Ok(Mode::Local),
+            _ => Err(format!("expected cloud or local, got {:?}", mode)),
+        }
+    }
+}
+
+#[derive(StructOpt, Debug, Clone, PartialEq)]
 #[structopt(name = "base")]
 pub struct Config {
     /// Environment: Debug mode.
@@ -11,14 +76,26 @@ pub struct Config {
     #[structopt(short, long)]
     pub production: bool,
 
+    /// Config: TOML or YAML file with the settings, overridden by environment variables and arguments.
+    #[structopt(long, parse(from_os_str))]
+    pub config: Option<PathBuf>,
+
     /// Telegram: Token.
-    #[structopt(long, required_unless("help"))]
-    pub token: String,
+    #[structopt(long, default_value = "", parse(from_str))]
+    pub token: BotToken,
+
+    /// Telegram: File with the token, e.g. a mounted secret.
+    #[structopt(long, parse(from_os_str))]
+    pub token_file: Option<PathBuf>,
 
     /// Telegram: Api url.
     #[structopt(long, default_value = "https://api.telegram.org")]
     pub url: String,
 
+    /// Telegram: Kind of the Bot API server, cloud or local.
+    #[structopt(long, default_value = "cloud")]
+    pub mode: Mode,
+
     /// Client: Timeout in secs. The timeout is applied from when the request starts connecting until the response body has finished.
     #[structopt(long, default_value = "5")]
     pub timeout: u64,
@@ -38,18 +115,262 @@ pub struct Config {
     /// Updates: Timeout in seconds for long polling.
     #[structopt(long, default_value = "0")]
     pub updates_timeout: u64,
+
+    /// Client: Proxy url for all requests, e.g. http://proxy:3128 or socks5://proxy:1080.
+    #[structopt(long)]
+    pub proxy: Option<String>,
+
+    /// Client: Hosts reached without the proxy, comma separated, e.g. localhost,10.0.0.0/8,.internal.
+    #[structopt(long)]
+    pub no_proxy: Option<String>,
+
+    /// Client: Extra PEM root certificate trusted for TLS, e.g. a corporate CA. Repeatable.
+    #[structopt(long, parse(from_os_str))]
+    pub root_certificates: Vec<PathBuf>,
+
+    /// Client: PEM certificate presented to the server, requires --client-key.
+    #[structopt(long, parse(from_os_str))]
+    pub client_certificate: Option<PathBuf>,
+
+    /// Client: PKCS#8 PEM private key of the client certificate.
+    #[structopt(long, parse(from_os_str))]
+    pub client_key: Option<PathBuf>,
+
+    /// Client: Only use HTTP/1.
+    #[structopt(long)]
+    pub http1_only: bool,
+
+    /// Client: Use HTTP/2 without negotiating it first.
+    #[structopt(long)]
+    pub http2_prior_knowledge: bool,
+
+    /// Client: User-Agent header sent with every request.
+    #[structopt(long)]
+    pub user_agent: Option<String>,
 }
 
 impl Config {
-    pub fn new() -> Self {
-        Config::from_args()
+    /// Loads the config of the process with precedence file < environment < command line.
+    /// The file is taken from `--config` or `TELEGRAM_CONFIG`.
+    pub fn load() -> Result<Self, Error> {
+        Self::load_from(std::env::args_os(), std::env::vars())
+    }
+
+    pub fn load_from<A, V>(args: A, vars: V) -> Result<Self, Error>
+    where
+        A: IntoIterator,
+        A::Item: Into<OsString> + Clone,
+        V: IntoIterator<Item = (String, String)>,
+    {
+        let matches = Config::clap()
+            .get_matches_from_safe(args)
+            .map_err(|error| Error::Config {
+                field: error
+                    .info
+                    .as_ref()
+                    .and_then(|info| info.first().cloned())
+                    .unwrap_or_default(),
+                reason: error.message,
+            })?;
+        let vars: Vec<(String, String)> = vars.into_iter().collect();
+        let cli = Layer::from_matches(&matches);
+        let env = Layer::from_vars(vars.iter().cloned())?;
+        let file = Config::from_clap(&matches).config.or_else(|| {
+            vars.iter()
+                .find(|(name, _)| name == "TELEGRAM_CONFIG")
+                .map(|(_, path)| PathBuf::from(path))
+        });
+
+        let mut config = Config {
+            url: API_URL.to_string(),
+            ..Default::default()
+        };
+        if let Some(file) = &file {
+            Layer::from_file(file)?.apply(&mut config)?;
+        }
+        env.apply(&mut config)?;
+        cli.apply(&mut config)?;
+        config.config = file;
+
+        config.validate()?;
+
+        Ok(config)
+    }
+
+    /// Checks the values, the error names the wrong field.
+    pub fn validate(&self) -> Result<(), Error> {
+        let invalid = |field: &str, reason: &str| {
+            Err(Error::Config {
+                field: field.to_string(),
+                reason: reason.to_string(),
+            })
+        };
+
+        if self.token.is_empty() {
+            return invalid(
+                "token",
+                "is required, set --token, TELEGRAM_TOKEN or a token file",
+            );
+        }
+        self.token.validate()?;
+        if !self.url.starts_with("http://") && !self.url.starts_with("https://") {
+            return invalid("url", "must start with http:// or https://");
+        }
+        if self.timeout == 0 {
+            return invalid("timeout", "must be greater than 0");
+        }
+        if self.connect_timeout == 0 {
+            return invalid("connect_timeout", "must be greater than 0");
+        }
+        if !(1..=100).contains(&self.updates_limit) {
+            return invalid("updates_limit", "must be between 1 and 100");
+        }
+        if self.http1_only && self.http2_prior_knowledge {
+            return invalid("http1_only", "can't be combined with http2_prior_knowledge");
+        }
+        if self.client_certificate.is_some() != self.client_key.is_some() {
+            return invalid("client_key", "must be set together with client_certificate");
+        }
+
+        Ok(())
     }
 }
 
+/// Applies the config to a blocking or async reqwest client builder, they have the same methods
+/// but no common trait.
+macro_rules! configure {
+    ($config:expr, $builder:expr) => {{
+        let mut builder = $builder
+            .timeout(Duration::from_secs($config.timeout))
+            .connect_timeout(Duration::from_secs($config.connect_timeout));
+
+        if let Some(proxy) = $config.build_proxy()? {
+            builder = builder.proxy(proxy);
+        }
+        for certificate in $config.load_root_certificates()? {
+            builder = builder.add_root_certificate(certificate);
+        }
+        if let Some(identity) = $config.load_identity()? {
+            builder = builder.identity(identity);
+        }
+        if $config.http1_only {
+            builder = builder.http1_only();
+        }
+        if $config.http2_prior_knowledge {
+            builder = builder.http2_prior_knowledge();
+        }
+        if let Some(user_agent) = &$config.user_agent {
+            builder = builder.user_agent(user_agent);
+        }
+
+        builder
+    }};
+}
+
 impl Config {
     pub fn build_url(&self) -> String {
-        format!("{}/bot{}/", self.url, self.token)
+        format!("{}/bot{}/", self.url, self.token.expose())
+    }
+
+    /// Blocking HTTP client configured with the timeouts, proxy, TLS and user agent.
+    pub fn build_sync_client(&self) -> Result<reqwest::blocking::Client, Error> {
+        configure!(self, reqwest::blocking::Client::builder())
+            .build()
+            .map_err(Error::Request)
+    }
+
+    /// Async HTTP client configured with the timeouts, proxy, TLS and user agent.
+    pub fn build_async_client(&self) -> Result<reqwest::Client, Error> {
+        configure!(self, reqwest::Client::builder())
+            .build()
+            .map_err(Error::Request)
     }
+
+    fn build_proxy(&self) -> Result<Option<reqwest::Proxy>, Error> {
+        let url = match &self.proxy {
+            Some(url) => url,
+            None => return Ok(None),
+        };
+        let proxy = reqwest::Proxy::all(url).map_err(|error| Error::Config {
+            field: String::from("proxy"),
+            reason: error.to_string(),
+        })?;
+        let no_proxy = self.no_proxy.as_deref().and_then(NoProxy::from_string);
+
+        Ok(Some(proxy.no_proxy(no_proxy)))
+    }
+
+    fn load_root_certificates(&self) -> Result<Vec<reqwest::Certificate>, Error> {
+        let mut certificates = vec![];
+
+        for path in &self.root_certificates {
+            let pem = read_file("root_certificates", path)?;
+            let bundle = split_pem_bundle(&pem);
+
+            if bundle.is_empty() {
+                return Err(Error::Config {
+                    field: String::from("root_certificates"),
+                    reason: format!("no certificate in {}", path.display()),
+                });
+            }
+            for pem in bundle {
+                let certificate =
+                    reqwest::Certificate::from_pem(&pem).map_err(|error| Error::Config {
+                        field: String::from("root_certificates"),
+                        reason: format!("invalid {}: {}", path.display(), error),
+                    })?;
+                certificates.push(certificate);
+            }
+        }
+
+        Ok(certificates)
+    }
+
+    fn load_identity(&self) -> Result<Option<reqwest::Identity>, Error> {
+        let (certificate, key) = match (&self.client_certificate, &self.client_key) {
+            (Some(certificate), Some(key)) => (certificate, key),
+            _ => return Ok(None),
+        };
+        let pem = read_file("client_certificate", certificate)?;
+        let key = read_file("client_key", key)?;
+
+        reqwest::Identity::from_pkcs8_pem(&pem, &key)
+            .map(Some)
+            .map_err(|error| Error::Config {
+                field: String::from("client_certificate"),
+                reason: format!("invalid {}: {}", certificate.display(), error),
+            })
+    }
+}
+
+/// Splits a PEM file into its `CERTIFICATE` blocks, ignoring anything in between.
+fn split_pem_bundle(pem: &[u8]) -> Vec<Vec<u8>> {
+    const BEGIN: &str = "-----BEGIN CERTIFICATE-----";
+    const END: &str = "-----END CERTIFICATE-----";
+
+    let text = String::from_utf8_lossy(pem);
+    let mut blocks = vec![];
+    let mut rest = &text[..];
+
+    while let Some(start) = rest.find(BEGIN) {
+        let end = match rest[start..].find(END) {
+            Some(end) => start + end + END.len(),
+            None => break,
+        };
+        let mut block = rest.as_bytes()[start..end].to_vec();
+        block.push(b'\n');
+        blocks.push(block);
+        rest = &rest[end..];
+    }
+
+    blocks
+}
+
+fn read_file(field: &str, path: &Path) -> Result<Vec<u8>, Error> {
+    fs::read(path).map_err(|error| Error::Config {
+        field: field.to_string(),
+        reason: format!("can't read {}: {}", path.display(), error),
+    })
 }
 
 impl Default for Config {
@@ -58,12 +379,23 @@ impl Default for Config {
             debug: false,
             production: false,
             url: String::from(""),
-            token: String::from(""),
+            mode: Mode::Cloud,
+            config: None,
+            token: BotToken::default(),
+            token_file: None,
             timeout: 5u64,
             connect_timeout: 5u64,
             updates_offset: 0i64,
             updates_limit: 100i64,
             updates_timeout: 0u64,
+            proxy: None,
+            no_proxy: None,
+            root_certificates: vec![],
+            client_certificate: None,
+            client_key: None,
+            http1_only: false,
+            http2_prior_knowledge: false,
+            user_agent: None,
         }
     }
 }
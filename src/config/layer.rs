@@ -0,0 +1,210 @@
+use crate::config::token::BotToken;
+use crate::config::{Config, Mode};
+use crate::errors::Error;
+use serde::Deserialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use structopt::clap::ArgMatches;
+
+/// Prefix of the environment variables read by `Layer::from_env`, e.g. `TELEGRAM_TOKEN`.
+pub const ENV_PREFIX: &str = "TELEGRAM_";
+
+/// Part of the configuration coming from one source: a file, the environment or the command line.
+/// Only the values present in the source are set.
+#[derive(Debug, Default, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct Layer {
+    pub debug: Option<bool>,
+    pub production: Option<bool>,
+    pub token: Option<BotToken>,
+    pub token_file: Option<PathBuf>,
+    pub url: Option<String>,
+    pub mode: Option<Mode>,
+    pub timeout: Option<u64>,
+    pub connect_timeout: Option<u64>,
+    pub updates_offset: Option<i64>,
+    pub updates_limit: Option<i64>,
+    pub updates_timeout: Option<u64>,
+    pub proxy: Option<String>,
+    pub no_proxy: Option<String>,
+    pub root_certificates: Option<Vec<PathBuf>>,
+    pub client_certificate: Option<PathBuf>,
+    pub client_key: Option<PathBuf>,
+    pub http1_only: Option<bool>,
+    pub http2_prior_knowledge: Option<bool>,
+    pub user_agent: Option<String>,
+}
+
+impl Layer {
+    /// Reads a TOML or YAML file, the format is taken from the extension.
+    pub fn from_file(path: &Path) -> Result<Self, Error> {
+        let content = fs::read_to_string(path).map_err(|error| Error::Config {
+            field: String::from("config"),
+            reason: format!("can't read {}: {}", path.display(), error),
+        })?;
+        let invalid = |error: String| Error::Config {
+            field: String::from("config"),
+            reason: format!("invalid {}: {}", path.display(), error),
+        };
+
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("toml") => toml::from_str(&content).map_err(|error| invalid(error.to_string())),
+            Some("yaml") | Some("yml") => {
+                serde_yaml::from_str(&content).map_err(|error| invalid(error.to_string()))
+            }
+            _ => Err(invalid(String::from("expected .toml, .yaml or .yml file"))),
+        }
+    }
+
+    /// Reads the `TELEGRAM_*` variables of the process environment.
+    pub fn from_env() -> Result<Self, Error> {
+        Self::from_vars(std::env::vars())
+    }
+
+    /// Reads the `TELEGRAM_*` variables, e.g. `TELEGRAM_UPDATES_TIMEOUT` sets `updates_timeout`.
+    pub fn from_vars<I: IntoIterator<Item = (String, String)>>(vars: I) -> Result<Self, Error> {
+        let mut layer = Self::default();
+
+        for (name, value) in vars {
+            let field = match name.strip_prefix(ENV_PREFIX) {
+                Some(field) => field.to_lowercase(),
+                None => continue,
+            };
+
+            match field.as_str() {
+                "debug" => layer.debug = Some(parse(&field, &name, &value)?),
+                "production" => layer.production = Some(parse(&field, &name, &value)?),
+                "token" => layer.token = Some(BotToken::from(value)),
+                "token_file" => layer.token_file = Some(PathBuf::from(value)),
+                "url" => layer.url = Some(value),
+                "mode" => layer.mode = Some(parse(&field, &name, &value)?),
+                "timeout" => layer.timeout = Some(parse(&field, &name, &value)?),
+                "connect_timeout" => layer.connect_timeout = Some(parse(&field, &name, &value)?),
+                "updates_offset" => layer.updates_offset = Some(parse(&field, &name, &value)?),
+                "updates_limit" => layer.updates_limit = Some(parse(&field, &name, &value)?),
+                "updates_timeout" => layer.updates_timeout = Some(parse(&field, &name, &value)?),
+                "proxy" => layer.proxy = Some(value),
+                "no_proxy" => layer.no_proxy = Some(value),
+                "root_certificates" => {
+                    layer.root_certificates = Some(
+                        value
+                            .split(',')
+                            .map(|path| PathBuf::from(path.trim()))
+                            .collect(),
+                    )
+                }
+                "client_certificate" => layer.client_certificate = Some(PathBuf::from(value)),
+                "client_key" => layer.client_key = Some(PathBuf::from(value)),
+                "http1_only" => layer.http1_only = Some(parse(&field, &name, &value)?),
+                "http2_prior_knowledge" => {
+                    layer.http2_prior_knowledge = Some(parse(&field, &name, &value)?)
+                }
+                "user_agent" => layer.user_agent = Some(value),
+                _ => continue,
+            }
+        }
+
+        Ok(layer)
+    }
+
+    /// Takes the arguments that were given on the command line, defaults of the parser are skipped.
+    pub fn from_matches(matches: &ArgMatches) -> Self {
+        let config = <Config as structopt::StructOpt>::from_clap(matches);
+        // Arguments are named after the fields in kebab case, e.g. `updates-limit`.
+        let given = |name: &str| matches.occurrences_of(name.replace('_', "-")) > 0;
+
+        Self {
+            debug: given("debug").then_some(config.debug),
+            production: given("production").then_some(config.production),
+            token: given("token").then_some(config.token),
+            token_file: config.token_file,
+            url: given("url").then_some(config.url),
+            mode: given("mode").then_some(config.mode),
+            timeout: given("timeout").then_some(config.timeout),
+            connect_timeout: given("connect_timeout").then_some(config.connect_timeout),
+            updates_offset: given("updates_offset").then_some(config.updates_offset),
+            updates_limit: given("updates_limit").then_some(config.updates_limit),
+            updates_timeout: given("updates_timeout").then_some(config.updates_timeout),
+            proxy: config.proxy,
+            no_proxy: config.no_proxy,
+            root_certificates: given("root_certificates").then_some(config.root_certificates),
+            client_certificate: config.client_certificate,
+            client_key: config.client_key,
+            http1_only: given("http1_only").then_some(config.http1_only),
+            http2_prior_knowledge: given("http2_prior_knowledge")
+                .then_some(config.http2_prior_knowledge),
+            user_agent: config.user_agent,
+        }
+    }
+
+    /// Applies the layer over the config. A token file replaces a token given at lower precedence.
+    pub fn apply(self, config: &mut Config) -> Result<(), Error> {
+        if let Some(path) = &self.token_file {
+            config.token = read_secret(path)?;
+        }
+        if let Some(token) = self.token {
+            config.token = token;
+        }
+
+        set(&mut config.debug, self.debug);
+        set(&mut config.production, self.production);
+        set(&mut config.url, self.url);
+        set(&mut config.mode, self.mode);
+        set(&mut config.timeout, self.timeout);
+        set(&mut config.connect_timeout, self.connect_timeout);
+        set(&mut config.updates_offset, self.updates_offset);
+        set(&mut config.updates_limit, self.updates_limit);
+        set(&mut config.updates_timeout, self.updates_timeout);
+        set(&mut config.root_certificates, self.root_certificates);
+        set(&mut config.http1_only, self.http1_only);
+        set(
+            &mut config.http2_prior_knowledge,
+            self.http2_prior_knowledge,
+        );
+
+        if self.proxy.is_some() {
+            config.proxy = self.proxy;
+        }
+        if self.no_proxy.is_some() {
+            config.no_proxy = self.no_proxy;
+        }
+        if self.client_certificate.is_some() {
+            config.client_certificate = self.client_certificate;
+        }
+        if self.client_key.is_some() {
+            config.client_key = self.client_key;
+        }
+        if self.user_agent.is_some() {
+            config.user_agent = self.user_agent;
+        }
+
+        Ok(())
+    }
+}
+
+fn set<T>(target: &mut T, value: Option<T>) {
+    if let Some(value) = value {
+        *target = value;
+    }
+}
+
+fn parse<T: FromStr>(field: &str, name: &str, value: &str) -> Result<T, Error>
+where
+    T::Err: ToString,
+{
+    value.trim().parse::<T>().map_err(|error| Error::Config {
+        field: field.to_string(),
+        reason: format!("{}={:?}: {}", name, value, error.to_string()),
+    })
+}
+
+/// Reads a token from a secret file, e.g. a mounted Docker or Kubernetes secret.
+fn read_secret(path: &Path) -> Result<BotToken, Error> {
+    fs::read_to_string(path)
+        .map(BotToken::from)
+        .map_err(|error| Error::Config {
+            field: String::from("token_file"),
+            reason: format!("can't read {}: {}", path.display(), error),
+        })
+}
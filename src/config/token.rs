@@ -0,0 +1,104 @@
+use crate::errors::Error;
+use serde::Deserialize;
+use std::fmt::{Debug, Display, Formatter};
+
+/// Length of the secret part of a token, after the bot id and the colon.
+const SECRET_LENGTH: usize = 35;
+
+/// Bot token as given by @BotFather: `<bot id>:<secret>`.
+/// Only the bot id is shown by `Debug` and `Display`, e.g. `123456:***`, the whole token is returned
+/// by `expose` for the request urls only.
+#[derive(Clone, Default, Deserialize, PartialEq)]
+#[serde(from = "String")]
+pub struct BotToken(String);
+
+impl BotToken {
+    /// Token checked to have the `<digits>:<35 characters>` shape.
+    pub fn new(token: &str) -> Result<Self, Error> {
+        let token = Self::from(token);
+        token.validate()?;
+
+        Ok(token)
+    }
+
+    pub fn validate(&self) -> Result<(), Error> {
+        let valid = match self.0.split_once(':') {
+            Some((id, secret)) => {
+                !id.is_empty()
+                    && id.chars().all(|char| char.is_ascii_digit())
+                    && secret.len() == SECRET_LENGTH
+                    && secret
+                        .chars()
+                        .all(|char| char.is_ascii_alphanumeric() || char == '_' || char == '-')
+            }
+            None => false,
+        };
+
+        match valid {
+            true => Ok(()),
+            false => Err(Error::Config {
+                field: String::from("token"),
+                reason: format!("must look like <bot id>:<{} characters>", SECRET_LENGTH),
+            }),
+        }
+    }
+
+    /// Identifier of the bot, the part of the token before the colon.
+    pub fn bot_id(&self) -> Option<u64> {
+        self.0.split_once(':')?.0.parse().ok()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Whole token including the secret.
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+
+    /// Replaces every occurrence of the token in the text with its redacted form.
+    pub fn redact(&self, text: &str) -> String {
+        match self.0.is_empty() {
+            true => text.to_string(),
+            false => text.replace(&self.0, &self.to_string()),
+        }
+    }
+
+    /// Removes the token from the url of a request error.
+    pub fn redact_error(&self, mut error: reqwest::Error) -> reqwest::Error {
+        if let Some(url) = error.url_mut() {
+            let path = self.redact(url.path());
+            url.set_path(&path);
+        }
+
+        error
+    }
+}
+
+impl From<&str> for BotToken {
+    fn from(token: &str) -> Self {
+        Self(token.trim().to_string())
+    }
+}
+
+impl From<String> for BotToken {
+    fn from(token: String) -> Self {
+        Self::from(token.as_str())
+    }
+}
+
+impl Display for BotToken {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.bot_id() {
+            Some(id) => write!(f, "{}:***", id),
+            None => write!(f, "***"),
+        }
+    }
+}
+
+impl Debug for BotToken {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
@@ -0,0 +1,209 @@
+use crate::api::types::chat_administrator_rights::ChatAdministratorRights;
+use crate::api::types::update::Update;
+use crate::errors::Error;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+
+/// Maximum length of a `start` or `startgroup` parameter.
+pub const START_LIMIT: usize = 64;
+
+/// Maximum length of a `startapp` parameter.
+pub const START_APP_LIMIT: usize = 512;
+
+/// Links opening a chat with the bot, `https://t.me/<bot>?…` or `tg://resolve?domain=<bot>&…`.
+/// Payloads are limited to `[A-Za-z0-9_-]`, see `encode_payload` for arbitrary data.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeepLink {
+    username: String,
+    tg: bool,
+}
+
+impl DeepLink {
+    /// Links to the bot with the username, with or without the leading `@`.
+    pub fn new(username: &str) -> Self {
+        Self {
+            username: username.trim_start_matches('@').to_string(),
+            tg: false,
+        }
+    }
+
+    /// Uses `tg://` links opening the app directly instead of `https://t.me` ones.
+    pub fn tg(mut self) -> Self {
+        self.tg = true;
+        self
+    }
+
+    /// Private chat with the bot, the payload is sent along the `/start` command.
+    pub fn start(&self, payload: &str) -> Result<String, Error> {
+        check("payload", payload, START_LIMIT)?;
+
+        Ok(self.link(None, &[("start", Some(payload))]))
+    }
+
+    /// Prompts to add the bot to a group, asking for the administrator rights if any.
+    pub fn start_group(
+        &self,
+        payload: Option<&str>,
+        admin: Option<&ChatAdministratorRights>,
+    ) -> Result<String, Error> {
+        if let Some(payload) = payload {
+            check("payload", payload, START_LIMIT)?;
+        }
+        let admin = admin.map(admin_rights);
+
+        Ok(self.link(
+            None,
+            &[("startgroup", payload), ("admin", admin.as_deref())],
+        ))
+    }
+
+    /// Prompts to add the bot to a channel as an administrator with the rights.
+    pub fn start_channel(&self, admin: &ChatAdministratorRights) -> String {
+        let admin = admin_rights(admin);
+
+        self.link(None, &[("startchannel", None), ("admin", Some(&admin))])
+    }
+
+    /// Opens the Web App of the bot, the main one or the named one, passing the payload as
+    /// `start_param` of its `initData`.
+    pub fn start_app(&self, app: Option<&str>, payload: Option<&str>) -> Result<String, Error> {
+        if let Some(payload) = payload {
+            check("payload", payload, START_APP_LIMIT)?;
+        }
+
+        // An empty parameter still opens the app instead of the chat.
+        Ok(self.link(app, &[("startapp", Some(payload.unwrap_or_default()))]))
+    }
+
+    fn link(&self, app: Option<&str>, parameters: &[(&str, Option<&str>)]) -> String {
+        let mut link = match (self.tg, app) {
+            (false, None) => format!("https://t.me/{}", self.username),
+            (false, Some(app)) => format!("https://t.me/{}/{}", self.username, app),
+            (true, None) => format!("tg://resolve?domain={}", self.username),
+            (true, Some(app)) => format!("tg://resolve?domain={}&appname={}", self.username, app),
+        };
+
+        for (key, value) in parameters {
+            let value = match value {
+                Some(value) => format!("={}", value),
+                // Only `startgroup` and `startchannel` may go without a value.
+                None if key.starts_with("start") => String::new(),
+                None => continue,
+            };
+            let separator = match link.contains('?') {
+                true => '&',
+                false => '?',
+            };
+            link.push_str(&format!("{}{}{}", separator, key, value));
+        }
+
+        link
+    }
+}
+
+/// Encodes arbitrary data into the `[A-Za-z0-9_-]` alphabet of the payloads, 48 bytes fill the 64
+/// characters of a `start` parameter.
+pub fn encode_payload(data: &[u8]) -> String {
+    URL_SAFE_NO_PAD.encode(data)
+}
+
+pub fn decode_payload(payload: &str) -> Result<Vec<u8>, Error> {
+    URL_SAFE_NO_PAD
+        .decode(payload)
+        .map_err(|error| Error::DeepLink {
+            field: String::from("payload"),
+            reason: error.to_string(),
+        })
+}
+
+/// Payload of a `/start <payload>` command opened from a deep link, also `/start@bot <payload>`
+/// when `bot` is the username of the bot, with or without its `@`. `None` for other texts, for a
+/// `/start` without payload and for a command addressed to another bot in a group.
+pub fn start_payload<'a>(text: &'a str, bot: &str) -> Option<&'a str> {
+    let (command, payload) = text.split_once(char::is_whitespace)?;
+    let (command, mention) = match command.split_once('@') {
+        Some((command, mention)) => (command, Some(mention)),
+        None => (command, None),
+    };
+    let addressed = match mention {
+        Some(mention) => mention.eq_ignore_ascii_case(bot.trim_start_matches('@')),
+        None => true,
+    };
+
+    match command == "/start" && addressed && !payload.trim().is_empty() {
+        true => Some(payload.trim()),
+        false => None,
+    }
+}
+
+/// Filter of the updates with a `/start <payload>` message for the bot, e.g. to route the users
+/// coming from a campaign link before the other handlers:
+/// `batch.updates.iter().filter_map(|update| filter.payload(update))`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StartFilter {
+    bot: String,
+}
+
+impl StartFilter {
+    /// Filter for the bot with the username, with or without the leading `@`.
+    pub fn new(bot: &str) -> Self {
+        Self {
+            bot: bot.trim_start_matches('@').to_string(),
+        }
+    }
+
+    pub fn matches(&self, update: &Update) -> bool {
+        self.payload(update).is_some()
+    }
+
+    /// Payload of the `/start` command in the new message of the update, see `start_payload`.
+    pub fn payload<'a>(&self, update: &'a Update) -> Option<&'a str> {
+        update.message.as_ref()?.start_payload(&self.bot)
+    }
+}
+
+/// `admin` parameter listing the rights, e.g. `change_info+delete_messages`.
+fn admin_rights(rights: &ChatAdministratorRights) -> String {
+    let flag = |flag: Option<bool>| flag.unwrap_or_default();
+    let names = [
+        (rights.can_change_info, "change_info"),
+        (flag(rights.can_post_messages), "post_messages"),
+        (flag(rights.can_edit_messages), "edit_messages"),
+        (rights.can_delete_messages, "delete_messages"),
+        (rights.can_restrict_members, "restrict_members"),
+        (rights.can_invite_users, "invite_users"),
+        (flag(rights.can_pin_messages), "pin_messages"),
+        (flag(rights.can_manage_topics), "manage_topics"),
+        (rights.can_promote_members, "promote_members"),
+        (rights.can_manage_video_chats, "manage_video_chats"),
+        (rights.is_anonymous, "anonymous"),
+        (rights.can_manage_chat, "manage_chat"),
+        (flag(rights.can_post_stories), "post_stories"),
+        (flag(rights.can_edit_stories), "edit_stories"),
+        (flag(rights.can_delete_stories), "delete_stories"),
+    ];
+
+    names
+        .iter()
+        .filter(|(granted, _)| *granted)
+        .map(|(_, name)| *name)
+        .collect::<Vec<_>>()
+        .join("+")
+}
+
+fn check(field: &str, payload: &str, limit: usize) -> Result<(), Error> {
+    let valid = payload
+        .chars()
+        .all(|char| char.is_ascii_alphanumeric() || char == '_' || char == '-');
+
+    let reason = match (valid, payload.len() <= limit) {
+        (true, true) => return Ok(()),
+        (false, _) => String::from("must contain only A-Z, a-z, 0-9, _ and -"),
+        (true, false) => format!("must be at most {} characters long", limit),
+    };
+
+    Err(Error::DeepLink {
+        field: field.to_string(),
+        reason,
+    })
+}
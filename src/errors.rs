@@ -1,21 +1,213 @@
 use crate::api::responses::error::ResponseError;
+use crate::api::types::message::Message;
 use std::fmt::{Display, Formatter};
 
 #[derive(Debug)]
 pub enum Error {
     Request(reqwest::Error),
+    /// Network error of a transport other than reqwest, the token is redacted from the message.
+    Transport(String),
+    /// Bot API error that doesn't fall into one of the well-known cases below.
     Response(ResponseError),
     Decode(serde_json::error::Error),
-    Debug,
+    Io(std::io::Error),
+    /// Configuration value is missing or wrong.
+    Config {
+        field: String,
+        reason: String,
+    },
+    /// Params break a documented constraint of the Bot API, found before sending the request.
+    Validation {
+        field: String,
+        reason: String,
+    },
+    /// Web App or Login Widget data isn't signed by Telegram for this bot, is incomplete or too old.
+    Auth {
+        field: String,
+        reason: String,
+    },
+    /// Telegram Passport data can't be decrypted with the key of the bot or was tampered with.
+    Passport {
+        field: String,
+        reason: String,
+    },
+    /// Deep link parameter has characters Telegram doesn't allow or is too long, or a payload
+    /// can't be decoded.
+    DeepLink {
+        field: String,
+        reason: String,
+    },
+    /// File can't be uploaded or downloaded in the mode of the Bot API server.
+    File {
+        path: String,
+        reason: String,
+    },
+    /// 401: the bot token is invalid or was revoked.
+    Unauthorized(ResponseError),
+    /// 403: the bot was blocked by the user.
+    BotBlocked(ResponseError),
+    /// 403: the bot was kicked from the group, supergroup or channel.
+    BotKicked(ResponseError),
+    /// 403: the user account was deleted.
+    UserDeactivated(ResponseError),
+    /// 403: the bot can't write to the user first.
+    CantInitiateConversation(ResponseError),
+    /// 403: any other access error.
+    Forbidden(ResponseError),
+    /// 400: the chat doesn't exist or the bot never met it.
+    ChatNotFound(ResponseError),
+    /// 400: the user doesn't exist or the bot never met them.
+    UserNotFound(ResponseError),
+    /// 400: the message to edit, delete, reply to or copy is gone.
+    MessageNotFound(ResponseError),
+    /// 400: the new message content and reply markup are exactly the same as the current ones.
+    MessageNotModified(ResponseError),
+    /// 400: the bot lacks the administrator rights for the action.
+    NotEnoughRights(ResponseError),
+    /// 400: the group was upgraded to a supergroup with the given identifier.
+    MigrateToChat {
+        id: i64,
+        response: ResponseError,
+    },
+    /// 404: unknown method or malformed token.
+    NotFound(ResponseError),
+    /// 409: another getUpdates request or a webhook is active for the bot.
+    Conflict(ResponseError),
+    /// 429: flood control exceeded, the request may be repeated after `retry_after` seconds.
+    TooManyRequests {
+        retry_after: i64,
+        response: ResponseError,
+    },
+    /// 5xx: Bot API server or a proxy in front of it failed.
+    Server(ResponseError),
+    /// A text split into several messages failed after the first ones were sent, sending the whole
+    /// text again would repeat them.
+    PartiallySent {
+        sent: Vec<Message>,
+        error: Box<Error>,
+    },
+}
+
+impl Error {
+    /// Returns the Bot API error this error was classified from, if any.
+    pub fn response(&self) -> Option<&ResponseError> {
+        match self {
+            Error::Response(response)
+            | Error::Unauthorized(response)
+            | Error::BotBlocked(response)
+            | Error::BotKicked(response)
+            | Error::UserDeactivated(response)
+            | Error::CantInitiateConversation(response)
+            | Error::Forbidden(response)
+            | Error::ChatNotFound(response)
+            | Error::UserNotFound(response)
+            | Error::MessageNotFound(response)
+            | Error::MessageNotModified(response)
+            | Error::NotEnoughRights(response)
+            | Error::MigrateToChat { response, .. }
+            | Error::NotFound(response)
+            | Error::Conflict(response)
+            | Error::TooManyRequests { response, .. }
+            | Error::Server(response) => Some(response),
+            Error::PartiallySent { error, .. } => error.response(),
+            _ => None,
+        }
+    }
+
+    /// Raw `description` returned by the Bot API.
+    pub fn description(&self) -> Option<&str> {
+        self.response()
+            .map(|response| response.description.as_str())
+    }
+
+    pub fn error_code(&self) -> Option<u64> {
+        self.response().map(|response| response.error_code)
+    }
+}
+
+impl From<ResponseError> for Error {
+    fn from(response: ResponseError) -> Self {
+        if let Some(retry_after) = response.retry_after() {
+            return Error::TooManyRequests {
+                retry_after,
+                response,
+            };
+        }
+
+        if let Some(id) = response.migrate_to_chat_id() {
+            return Error::MigrateToChat { id, response };
+        }
+
+        let description = response.description.to_lowercase();
+
+        match response.error_code {
+            401 => Error::Unauthorized(response),
+            403 if description.contains("bot was blocked by the user") => {
+                Error::BotBlocked(response)
+            }
+            403 if description.contains("bot was kicked") => Error::BotKicked(response),
+            403 if description.contains("user is deactivated") => Error::UserDeactivated(response),
+            403 if description.contains("can't initiate conversation") => {
+                Error::CantInitiateConversation(response)
+            }
+            403 => Error::Forbidden(response),
+            400 if description.contains("chat not found") => Error::ChatNotFound(response),
+            400 if description.contains("user not found") => Error::UserNotFound(response),
+            400 if description.contains("message is not modified") => {
+                Error::MessageNotModified(response)
+            }
+            400 if description.contains("message to") && description.contains("not found") => {
+                Error::MessageNotFound(response)
+            }
+            400 if description.contains("not enough rights") => Error::NotEnoughRights(response),
+            404 => Error::NotFound(response),
+            409 => Error::Conflict(response),
+            500..=599 => Error::Server(response),
+            _ => Error::Response(response),
+        }
+    }
 }
 
 impl Display for Error {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
-            Error::Request(error) => write!(f, "Request Error: {:#?}", error),
-            Error::Response(error) => write!(f, "Response Error: {:#?}", error),
-            Error::Decode(error) => write!(f, "Decode Error {:#?}", error),
-            _ => write!(f, "Debug Error!"),
+            Error::Request(error) => write!(f, "Request Error: {}", error),
+            Error::Transport(error) => write!(f, "Transport Error: {}", error),
+            Error::Decode(error) => write!(f, "Decode Error: {}", error),
+            Error::Io(error) => write!(f, "Io Error: {}", error),
+            Error::Config { field, reason } => write!(f, "Config Error: {} {}", field, reason),
+            Error::Validation { field, reason } => {
+                write!(f, "Validation Error: {} {}", field, reason)
+            }
+            Error::Auth { field, reason } => write!(f, "Auth Error: {} {}", field, reason),
+            Error::Passport { field, reason } => write!(f, "Passport Error: {} {}", field, reason),
+            Error::DeepLink { field, reason } => {
+                write!(f, "Deep Link Error: {} {}", field, reason)
+            }
+            Error::File { path, reason } => write!(f, "File Error: {} {}", path, reason),
+            Error::PartiallySent { sent, error } => {
+                write!(f, "{} (after {} messages were sent)", error, sent.len())
+            }
+            _ => match self.response() {
+                Some(response) => write!(
+                    f,
+                    "Telegram Error {}: {}",
+                    response.error_code, response.description
+                ),
+                None => write!(f, "Unknown Error!"),
+            },
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Request(error) => Some(error),
+            Error::Decode(error) => Some(error),
+            Error::Io(error) => Some(error),
+            Error::PartiallySent { error, .. } => Some(error.as_ref()),
+            _ => None,
         }
     }
 }
@@ -0,0 +1,473 @@
+use crate::api::enums::message_entity_kind::MessageEntityKind;
+use crate::api::types::message_entity::MessageEntity;
+use crate::errors::Error;
+use std::cmp::Reverse;
+use std::collections::VecDeque;
+
+pub mod entities;
+pub mod escape;
+pub mod markdown;
+pub mod split;
+pub mod utf16;
+
+/// Piece of formatted text.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Node {
+    Text(String),
+    Bold(Text),
+    Italic(Text),
+    Underline(Text),
+    Strikethrough(Text),
+    Spoiler(Text),
+    /// In MarkdownV2 the quote lasts until the end of its last line, so it should start and end a line.
+    Blockquote(Text),
+    Code(String),
+    Pre {
+        code: String,
+        language: Option<String>,
+    },
+    Link {
+        text: Text,
+        url: String,
+    },
+    /// Link to a user by id, for users without a username.
+    Mention {
+        text: Text,
+        user_id: i64,
+    },
+    /// Custom emoji shown instead of the `emoji`, e.g. `👍`, where it is supported.
+    CustomEmoji {
+        emoji: String,
+        id: String,
+    },
+}
+
+/// Formatted text that renders to escaped HTML or MarkdownV2, or to plain text with entities, e.g.
+/// `Text::new().text("Hello, ").bold("world").text("!")`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Text {
+    nodes: Vec<Node>,
+}
+
+impl Text {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Formatted text of a received message, e.g. to quote it or send it again. Entities crossing
+    /// each other are split, entities Telegram detects by itself like urls and hashtags are left as
+    /// plain text.
+    pub fn from_entities(text: &str, entities: &[MessageEntity]) -> Self {
+        let units: Vec<u16> = text.encode_utf16().collect();
+        let end = units.len() as i64;
+        let entities = entities
+            .iter()
+            .filter(|entity| entity.length > 0 && entity.offset >= 0 && entity.offset < end)
+            .map(|entity| MessageEntity {
+                length: entity.length.min(end - entity.offset),
+                ..entity.clone()
+            })
+            .collect();
+
+        Self {
+            nodes: build(&units, 0, end, entities),
+        }
+    }
+
+    /// Formatted text of `MarkdownV2` markup, see `markdown::parse_v2`.
+    pub fn from_markdown_v2(markdown: &str) -> Result<Self, Error> {
+        markdown::parse_v2(markdown)
+    }
+
+    /// Formatted text of legacy `Markdown` markup, see `markdown::parse`.
+    pub fn from_markdown(markdown: &str) -> Result<Self, Error> {
+        markdown::parse(markdown)
+    }
+
+    pub fn nodes(&self) -> &[Node] {
+        &self.nodes
+    }
+
+    pub fn push(mut self, node: Node) -> Self {
+        self.nodes.push(node);
+        self
+    }
+
+    pub fn text(self, text: &str) -> Self {
+        self.push(Node::Text(text.to_string()))
+    }
+
+    pub fn bold<T: Into<Text>>(self, text: T) -> Self {
+        self.push(Node::Bold(text.into()))
+    }
+
+    pub fn italic<T: Into<Text>>(self, text: T) -> Self {
+        self.push(Node::Italic(text.into()))
+    }
+
+    pub fn underline<T: Into<Text>>(self, text: T) -> Self {
+        self.push(Node::Underline(text.into()))
+    }
+
+    pub fn strikethrough<T: Into<Text>>(self, text: T) -> Self {
+        self.push(Node::Strikethrough(text.into()))
+    }
+
+    pub fn spoiler<T: Into<Text>>(self, text: T) -> Self {
+        self.push(Node::Spoiler(text.into()))
+    }
+
+    pub fn blockquote<T: Into<Text>>(self, text: T) -> Self {
+        self.push(Node::Blockquote(text.into()))
+    }
+
+    pub fn code(self, code: &str) -> Self {
+        self.push(Node::Code(code.to_string()))
+    }
+
+    pub fn pre(self, code: &str, language: Option<&str>) -> Self {
+        self.push(Node::Pre {
+            code: code.to_string(),
+            language: language.map(str::to_string),
+        })
+    }
+
+    pub fn link<T: Into<Text>>(self, text: T, url: &str) -> Self {
+        self.push(Node::Link {
+            text: text.into(),
+            url: url.to_string(),
+        })
+    }
+
+    pub fn mention<T: Into<Text>>(self, text: T, user_id: i64) -> Self {
+        self.push(Node::Mention {
+            text: text.into(),
+            user_id,
+        })
+    }
+
+    pub fn custom_emoji(self, emoji: &str, id: &str) -> Self {
+        self.push(Node::CustomEmoji {
+            emoji: emoji.to_string(),
+            id: id.to_string(),
+        })
+    }
+
+    /// Text for the `HTML` parse mode.
+    pub fn to_html(&self) -> String {
+        let mut html = String::new();
+        self.write_html(&mut html);
+        html
+    }
+
+    /// Text for the `MarkdownV2` parse mode.
+    pub fn to_markdown_v2(&self) -> String {
+        let mut markdown = String::new();
+        self.write_markdown_v2(&mut markdown);
+        markdown
+    }
+
+    /// Plain text and its entities, to be sent without a parse mode.
+    pub fn to_entities(&self) -> (String, Vec<MessageEntity>) {
+        let mut text = String::new();
+        let mut entities = vec![];
+        self.write_entities(&mut text, &mut entities);
+        entities.retain(|entity| entity.length > 0);
+
+        (text, entities)
+    }
+
+    fn write_html(&self, html: &mut String) {
+        let tag = |html: &mut String, open: &str, close: &str, text: &Text| {
+            html.push_str(open);
+            text.write_html(html);
+            html.push_str(close);
+        };
+
+        for node in &self.nodes {
+            match node {
+                Node::Text(text) => html.push_str(&escape::html(text)),
+                Node::Bold(text) => tag(html, "<b>", "</b>", text),
+                Node::Italic(text) => tag(html, "<i>", "</i>", text),
+                Node::Underline(text) => tag(html, "<u>", "</u>", text),
+                Node::Strikethrough(text) => tag(html, "<s>", "</s>", text),
+                Node::Spoiler(text) => tag(html, "<tg-spoiler>", "</tg-spoiler>", text),
+                Node::Blockquote(text) => tag(html, "<blockquote>", "</blockquote>", text),
+                Node::Code(code) => {
+                    html.push_str(&format!("<code>{}</code>", escape::html(code)));
+                }
+                Node::Pre {
+                    code,
+                    language: Some(language),
+                } => html.push_str(&format!(
+                    "<pre><code class=\"language-{}\">{}</code></pre>",
+                    escape::html(language),
+                    escape::html(code)
+                )),
+                Node::Pre {
+                    code,
+                    language: None,
+                } => html.push_str(&format!("<pre>{}</pre>", escape::html(code))),
+                Node::Link { text, url } => {
+                    let open = format!("<a href=\"{}\">", escape::html(url));
+                    tag(html, &open, "</a>", text);
+                }
+                Node::Mention { text, user_id } => {
+                    let open = format!("<a href=\"tg://user?id={}\">", user_id);
+                    tag(html, &open, "</a>", text);
+                }
+                Node::CustomEmoji { emoji, id } => html.push_str(&format!(
+                    "<tg-emoji emoji-id=\"{}\">{}</tg-emoji>",
+                    escape::html(id),
+                    escape::html(emoji)
+                )),
+            }
+        }
+    }
+
+    fn write_markdown_v2(&self, markdown: &mut String) {
+        let wrap = |markdown: &mut String, open: &str, close: &str, text: &Text| {
+            push_marker(markdown, open);
+            text.write_markdown_v2(markdown);
+            push_marker(markdown, close);
+        };
+
+        for node in &self.nodes {
+            match node {
+                Node::Text(text) => markdown.push_str(&escape::markdown_v2(text)),
+                Node::Bold(text) => wrap(markdown, "*", "*", text),
+                Node::Italic(text) => wrap(markdown, "_", "_", text),
+                Node::Underline(text) => wrap(markdown, "__", "__", text),
+                Node::Strikethrough(text) => wrap(markdown, "~", "~", text),
+                Node::Spoiler(text) => wrap(markdown, "||", "||", text),
+                Node::Blockquote(text) => {
+                    let quote = text.to_markdown_v2();
+                    let lines: Vec<String> =
+                        quote.split('\n').map(|line| format!(">{}", line)).collect();
+                    markdown.push_str(&lines.join("\n"));
+                }
+                Node::Code(code) => {
+                    markdown.push_str(&format!("`{}`", escape::markdown_v2_code(code)));
+                }
+                Node::Pre { code, language } => markdown.push_str(&format!(
+                    "```{}\n{}\n```",
+                    language.as_deref().unwrap_or_default(),
+                    escape::markdown_v2_code(code)
+                )),
+                Node::Link { text, url } => {
+                    let close = format!("]({})", escape::markdown_v2_url(url));
+                    wrap(markdown, "[", &close, text);
+                }
+                Node::Mention { text, user_id } => {
+                    let close = format!("](tg://user?id={})", user_id);
+                    wrap(markdown, "[", &close, text);
+                }
+                Node::CustomEmoji { emoji, id } => markdown.push_str(&format!(
+                    "![{}](tg://emoji?id={})",
+                    escape::markdown_v2(emoji),
+                    escape::markdown_v2_url(id)
+                )),
+            }
+        }
+    }
+
+    fn write_entities(&self, plain: &mut String, entities: &mut Vec<MessageEntity>) {
+        for node in &self.nodes {
+            let offset = utf16::len(plain);
+
+            // Code, pre and custom emoji hold their text, the other entities wrap nested text.
+            let (entity, nested) = match node {
+                Node::Text(text) => {
+                    plain.push_str(text);
+                    continue;
+                }
+                Node::Bold(text) => (entity(MessageEntityKind::Bold, offset), Some(text)),
+                Node::Italic(text) => (entity(MessageEntityKind::Italic, offset), Some(text)),
+                Node::Underline(text) => (entity(MessageEntityKind::Underline, offset), Some(text)),
+                Node::Strikethrough(text) => {
+                    (entity(MessageEntityKind::Strikethrough, offset), Some(text))
+                }
+                Node::Spoiler(text) => (entity(MessageEntityKind::Spoiler, offset), Some(text)),
+                Node::Blockquote(text) => {
+                    (entity(MessageEntityKind::Blockquote, offset), Some(text))
+                }
+                Node::Link { text, url } => (
+                    MessageEntity {
+                        url: Some(url.clone()),
+                        ..entity(MessageEntityKind::TextLink, offset)
+                    },
+                    Some(text),
+                ),
+                Node::Mention { text, user_id } => (
+                    MessageEntity {
+                        url: Some(format!("tg://user?id={}", user_id)),
+                        ..entity(MessageEntityKind::TextLink, offset)
+                    },
+                    Some(text),
+                ),
+                Node::Code(code) => {
+                    plain.push_str(code);
+                    (entity(MessageEntityKind::Code, offset), None)
+                }
+                Node::Pre { code, language } => {
+                    plain.push_str(code);
+                    let entity = MessageEntity {
+                        language: language.clone(),
+                        ..entity(MessageEntityKind::Pre, offset)
+                    };
+                    (entity, None)
+                }
+                Node::CustomEmoji { emoji, id } => {
+                    plain.push_str(emoji);
+                    let entity = MessageEntity {
+                        custom_emoji_id: Some(id.clone()),
+                        ..entity(MessageEntityKind::CustomEmoji, offset)
+                    };
+                    (entity, None)
+                }
+            };
+
+            let index = entities.len();
+            entities.push(entity);
+            if let Some(text) = nested {
+                text.write_entities(plain, entities);
+            }
+            entities[index].length = utf16::len(plain) - offset;
+        }
+    }
+}
+
+impl From<&str> for Text {
+    fn from(text: &str) -> Self {
+        Self::new().text(text)
+    }
+}
+
+impl From<String> for Text {
+    fn from(text: String) -> Self {
+        Self::new().push(Node::Text(text))
+    }
+}
+
+impl From<Node> for Text {
+    fn from(node: Node) -> Self {
+        Self::new().push(node)
+    }
+}
+
+/// Nodes of the text between the UTF-16 offsets, the entities are within them.
+fn build(units: &[u16], start: i64, end: i64, mut entities: Vec<MessageEntity>) -> Vec<Node> {
+    let string = |from: i64, to: i64| String::from_utf16_lossy(&units[from as usize..to as usize]);
+    let mut nodes = vec![];
+    let mut cursor = start;
+
+    entities.sort_by_key(|entity| (entity.offset, Reverse(entity.length)));
+    let mut queue = VecDeque::from(entities);
+
+    while let Some(entity) = queue.pop_front() {
+        if entity.offset > cursor {
+            append(&mut nodes, Node::Text(string(cursor, entity.offset)));
+        }
+
+        // Entities starting inside this one are nested, the part of those reaching past its end
+        // goes back to the queue.
+        let mut nested = vec![];
+        let mut rest = vec![];
+        while queue.front().is_some_and(|next| next.offset < entity.end()) {
+            let next = queue.pop_front().unwrap();
+            if next.end() > entity.end() {
+                rest.push(MessageEntity {
+                    offset: entity.end(),
+                    length: next.end() - entity.end(),
+                    ..next.clone()
+                });
+                nested.push(MessageEntity {
+                    length: entity.end() - next.offset,
+                    ..next
+                });
+            } else {
+                nested.push(next);
+            }
+        }
+        if !rest.is_empty() {
+            queue.extend(rest);
+            queue
+                .make_contiguous()
+                .sort_by_key(|entity| (entity.offset, Reverse(entity.length)));
+        }
+
+        let (from, to) = (entity.offset, entity.end());
+        let content = string(from, to);
+        let text = move || Text {
+            nodes: build(units, from, to, nested),
+        };
+        let node = match &entity.kind {
+            MessageEntityKind::Bold => Node::Bold(text()),
+            MessageEntityKind::Italic => Node::Italic(text()),
+            MessageEntityKind::Underline => Node::Underline(text()),
+            MessageEntityKind::Strikethrough => Node::Strikethrough(text()),
+            MessageEntityKind::Spoiler => Node::Spoiler(text()),
+            MessageEntityKind::Blockquote => Node::Blockquote(text()),
+            MessageEntityKind::Code => Node::Code(content),
+            MessageEntityKind::Pre => Node::Pre {
+                code: content,
+                language: entity.language.clone(),
+            },
+            MessageEntityKind::TextLink => Node::Link {
+                text: text(),
+                url: entity.url.clone().unwrap_or_default(),
+            },
+            MessageEntityKind::TextMention if entity.user.is_some() => Node::Mention {
+                text: text(),
+                user_id: entity.user.as_ref().map_or(0, |user| user.id),
+            },
+            MessageEntityKind::CustomEmoji => Node::CustomEmoji {
+                emoji: content,
+                id: entity.custom_emoji_id.clone().unwrap_or_default(),
+            },
+            _ => {
+                for node in text().nodes {
+                    append(&mut nodes, node);
+                }
+                cursor = to;
+                continue;
+            }
+        };
+
+        append(&mut nodes, node);
+        cursor = to;
+    }
+
+    if end > cursor {
+        append(&mut nodes, Node::Text(string(cursor, end)));
+    }
+
+    nodes
+}
+
+/// Pushes the node, joining it to the previous one if both are plain text.
+fn append(nodes: &mut Vec<Node>, node: Node) {
+    match (nodes.last_mut(), node) {
+        (Some(Node::Text(last)), Node::Text(text)) => last.push_str(&text),
+        (_, node) => nodes.push(node),
+    }
+}
+
+fn entity(kind: MessageEntityKind, offset: i64) -> MessageEntity {
+    MessageEntity::new(kind, offset, 0)
+}
+
+/// Appends a MarkdownV2 marker. Markers made of the same character would merge with the previous
+/// one, e.g. `_` closing italic and `__` closing underline, so they are split by `\r`, which
+/// Telegram ignores.
+fn push_marker(markdown: &mut String, marker: &str) {
+    let first = marker.chars().next();
+    let escaped = markdown
+        .strip_suffix(|char| Some(char) == first)
+        .map(|rest| rest.chars().rev().take_while(|char| *char == '\\').count() % 2 == 1);
+
+    if escaped == Some(false) && matches!(first, Some('_' | '*' | '~' | '|')) {
+        markdown.push('\r');
+    }
+    markdown.push_str(marker);
+}
@@ -0,0 +1,101 @@
+use crate::api::enums::message_entity_kind::MessageEntityKind;
+use crate::api::types::message_entity::MessageEntity;
+use crate::format::utf16;
+use std::cmp::Reverse;
+
+/// Entities paired with the part of the text they cover, those out of the bounds of the text are
+/// left out.
+pub fn parse<'a>(
+    text: &'a str,
+    entities: &'a [MessageEntity],
+) -> impl Iterator<Item = (&'a MessageEntity, &'a str)> {
+    entities
+        .iter()
+        .filter_map(move |entity| Some((entity, entity.text(text)?)))
+}
+
+/// Moves the entities by a number of UTF-16 code units, negative to the left.
+pub fn shift(entities: &mut [MessageEntity], by: i64) {
+    for entity in entities {
+        entity.offset += by;
+    }
+}
+
+/// Inserts the prefix before the text and moves the entities after it.
+pub fn prepend(text: &mut String, entities: &mut [MessageEntity], prefix: &str) {
+    text.insert_str(0, prefix);
+    shift(entities, utf16::len(prefix));
+}
+
+/// Part of the text at the UTF-16 offset and length, with the entities cut to it. Entities left
+/// empty are dropped. `None` if the bounds are out of the text or inside a character.
+pub fn slice(
+    text: &str,
+    entities: &[MessageEntity],
+    offset: i64,
+    length: i64,
+) -> Option<(String, Vec<MessageEntity>)> {
+    let part = utf16::slice(text, offset, length)?;
+    let end = offset + length;
+
+    let entities = entities
+        .iter()
+        .filter_map(|entity| {
+            let start = entity.offset.max(offset);
+            let stop = entity.end().min(end);
+
+            (stop > start).then(|| MessageEntity {
+                offset: start - offset,
+                length: stop - start,
+                ..entity.clone()
+            })
+        })
+        .collect();
+
+    Some((part.to_string(), entities))
+}
+
+/// Removes the leading and trailing whitespace, like Telegram does with the text of a message,
+/// and cuts the entities to the rest.
+pub fn trim(text: &str, entities: &[MessageEntity]) -> (String, Vec<MessageEntity>) {
+    let leading = utf16::len(&text[..text.len() - text.trim_start().len()]);
+    let length = utf16::len(text.trim());
+
+    slice(text, entities, leading, length).unwrap_or_default()
+}
+
+/// Joins entities of the same kind that overlap, and text styles that touch, e.g. two bold ranges
+/// split by a previous edit, and sorts the entities by offset, outer ones first. Touching entities
+/// of other kinds stay apart, two adjacent custom emoji are two emoji.
+pub fn merge(mut entities: Vec<MessageEntity>) -> Vec<MessageEntity> {
+    entities.sort_by_key(|entity| (entity.offset, Reverse(entity.length)));
+
+    let mut merged: Vec<MessageEntity> = Vec::with_capacity(entities.len());
+    for entity in entities {
+        let joined = merged
+            .iter_mut()
+            .rev()
+            .find(|other| other.same_kind(&entity) && joins(other, &entity));
+
+        match joined {
+            Some(other) => other.length = other.end().max(entity.end()) - other.offset,
+            None => merged.push(entity),
+        }
+    }
+
+    merged.sort_by_key(|entity| (entity.offset, Reverse(entity.length)));
+
+    merged
+}
+
+/// Whether `entity`, starting at or after `other`, continues it.
+fn joins(other: &MessageEntity, entity: &MessageEntity) -> bool {
+    match other.kind {
+        MessageEntityKind::Bold
+        | MessageEntityKind::Italic
+        | MessageEntityKind::Underline
+        | MessageEntityKind::Strikethrough
+        | MessageEntityKind::Spoiler => other.end() >= entity.offset,
+        _ => other.end() > entity.offset,
+    }
+}
@@ -0,0 +1,49 @@
+/// Characters that must be escaped anywhere in MarkdownV2 text.
+const MARKDOWN_V2: [char; 19] = [
+    '\\', '_', '*', '[', ']', '(', ')', '~', '`', '>', '#', '+', '-', '=', '|', '{', '}', '.', '!',
+];
+
+/// Escapes text and attribute values for the HTML parse mode.
+pub fn html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for char in text.chars() {
+        match char {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            _ => escaped.push(char),
+        }
+    }
+
+    escaped
+}
+
+/// Escapes text for the MarkdownV2 parse mode, outside of code and links.
+pub fn markdown_v2(text: &str) -> String {
+    escape(text, &MARKDOWN_V2)
+}
+
+/// Escapes the content of inline code and pre blocks for the MarkdownV2 parse mode.
+pub fn markdown_v2_code(text: &str) -> String {
+    escape(text, &['\\', '`'])
+}
+
+/// Escapes the url of an inline link for the MarkdownV2 parse mode.
+pub fn markdown_v2_url(url: &str) -> String {
+    escape(url, &['\\', ')'])
+}
+
+fn escape(text: &str, special: &[char]) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for char in text.chars() {
+        if special.contains(&char) {
+            escaped.push('\\');
+        }
+        escaped.push(char);
+    }
+
+    escaped
+}
@@ -0,0 +1,336 @@
+use crate::errors::Error;
+use crate::format::{Node, Text};
+
+/// Characters of MarkdownV2 that must be escaped outside of code, pre and links.
+const RESERVED: [char; 18] = [
+    '_', '*', '[', ']', '(', ')', '~', '`', '>', '#', '+', '-', '=', '|', '{', '}', '.', '!',
+];
+
+/// Parses text for the `MarkdownV2` parse mode, the way Telegram does. Entities must be nested
+/// properly and reserved characters escaped, `\r` separating markers is dropped.
+pub fn parse_v2(markdown: &str) -> Result<Text, Error> {
+    let mut parser = Parser {
+        chars: markdown.chars().collect(),
+        position: 0,
+        open: vec![],
+    };
+
+    parser.nodes(None)
+}
+
+/// Parses text for the legacy `Markdown` parse mode: bold, italic, code, pre and links, which
+/// can't be nested. Only `_`, `*`, `` ` `` and `[` are escaped, outside of entities.
+pub fn parse(markdown: &str) -> Result<Text, Error> {
+    let chars: Vec<char> = markdown.chars().collect();
+    let mut text = Text::new();
+    let mut plain = String::new();
+    let mut position = 0;
+
+    while let Some(&char) = chars.get(position) {
+        let start = position;
+        position += 1;
+
+        let node = match char {
+            '\\' if matches!(chars.get(position), Some('_' | '*' | '`' | '[')) => {
+                plain.push(chars[position]);
+                position += 1;
+                continue;
+            }
+            '*' | '_' => {
+                let content = until(&chars, &mut position, &[char], start)?;
+                match char {
+                    '*' => Node::Bold(Text::from(content)),
+                    _ => Node::Italic(Text::from(content)),
+                }
+            }
+            '`' if chars[position..].starts_with(&['`', '`']) => {
+                position += 2;
+                pre(until(&chars, &mut position, &['`', '`', '`'], start)?)
+            }
+            '`' => Node::Code(until(&chars, &mut position, &['`'], start)?),
+            '[' => {
+                let content = until(&chars, &mut position, &[']'], start)?;
+                if chars.get(position) != Some(&'(') {
+                    return invalid(format!("link at {} has no url", start));
+                }
+                position += 1;
+                let url = until(&chars, &mut position, &[')'], start)?;
+                link(Text::from(content), url, start)?
+            }
+            char => {
+                plain.push(char);
+                continue;
+            }
+        };
+
+        text = flush(text, &mut plain).push(node);
+    }
+
+    Ok(flush(text, &mut plain))
+}
+
+/// Marker closing the entity the parser is in.
+#[derive(Clone, Copy, PartialEq)]
+enum Close {
+    Marker(&'static str),
+    /// `]` of the text of a link or custom emoji.
+    Bracket,
+}
+
+struct Parser {
+    chars: Vec<char>,
+    position: usize,
+    /// Markers of the entities the parser is in, with their position.
+    open: Vec<(&'static str, usize)>,
+}
+
+impl Parser {
+    /// Nodes up to the closing marker, which is consumed, or to the end of the text.
+    fn nodes(&mut self, close: Option<Close>) -> Result<Text, Error> {
+        let mut text = Text::new();
+        let mut plain = String::new();
+
+        loop {
+            let Some(&char) = self.chars.get(self.position) else {
+                return match close {
+                    None => Ok(flush(text, &mut plain)),
+                    Some(_) => {
+                        let start = self.open.last().map_or(0, |(_, start)| *start);
+                        invalid(format!("entity at {} is not closed", start))
+                    }
+                };
+            };
+            let at = self.position;
+            let line_start = at == 0 || self.chars[at - 1] == '\n';
+            self.position += 1;
+
+            let marker = match char {
+                '_' if self.next_is('_') => Some("__"),
+                '|' if self.next_is('|') => Some("||"),
+                '*' => Some("*"),
+                '_' => Some("_"),
+                '~' => Some("~"),
+                _ => None,
+            };
+            if let Some(marker) = marker {
+                self.position = at + marker.len();
+                if close == Some(Close::Marker(marker)) {
+                    return Ok(flush(text, &mut plain));
+                }
+                if self.open.iter().any(|(open, _)| *open == marker) {
+                    return self.crossing(marker);
+                }
+
+                self.open.push((marker, at));
+                let nested = self.nodes(Some(Close::Marker(marker)))?;
+                self.open.pop();
+                let node = match marker {
+                    "*" => Node::Bold(nested),
+                    "_" => Node::Italic(nested),
+                    "__" => Node::Underline(nested),
+                    "~" => Node::Strikethrough(nested),
+                    _ => Node::Spoiler(nested),
+                };
+                text = flush(text, &mut plain).push(node);
+                continue;
+            }
+
+            let node = match char {
+                '\\' => match self.chars.get(self.position) {
+                    Some(&escaped) if ('\u{1}'..='\u{7e}').contains(&escaped) => {
+                        plain.push(escaped);
+                        self.position += 1;
+                        continue;
+                    }
+                    _ => return invalid(format!("'\\' at {} escapes nothing", at)),
+                },
+                '\r' => continue,
+                ']' if close == Some(Close::Bracket) => return Ok(flush(text, &mut plain)),
+                ']' if self.open.iter().any(|(open, _)| *open == "[") => {
+                    return self.crossing("[");
+                }
+                '`' if self.chars[self.position..].starts_with(&['`', '`']) => {
+                    self.position += 2;
+                    pre(self.code(&['`', '`', '`'], at)?)
+                }
+                '`' => Node::Code(self.code(&['`'], at)?),
+                '[' => {
+                    let content = self.bracket(at)?;
+                    let url = self.url(at)?;
+                    link(content, url, at)?
+                }
+                '!' if self.next_is('[') => {
+                    self.position += 1;
+                    let emoji = self.bracket(at)?;
+                    let url = self.url(at)?;
+                    match url.strip_prefix("tg://emoji?id=") {
+                        Some(id) => Node::CustomEmoji {
+                            emoji: emoji.to_entities().0,
+                            id: id.to_string(),
+                        },
+                        None => return invalid(format!("custom emoji at {} has no id", at)),
+                    }
+                }
+                '>' if line_start => Node::Blockquote(self.blockquote()?),
+                char if RESERVED.contains(&char) => {
+                    return invalid(format!(
+                        "character '{}' at {} is reserved and must be escaped",
+                        char, at
+                    ));
+                }
+                char => {
+                    plain.push(char);
+                    continue;
+                }
+            };
+
+            text = flush(text, &mut plain).push(node);
+        }
+    }
+
+    /// Text of a link or custom emoji starting at `start`, up to its `]`.
+    fn bracket(&mut self, start: usize) -> Result<Text, Error> {
+        self.open.push(("[", start));
+        let text = self.nodes(Some(Close::Bracket))?;
+        self.open.pop();
+
+        Ok(text)
+    }
+
+    /// Error for the marker closing an outer entity while the innermost one is still open.
+    fn crossing<T>(&self, marker: &str) -> Result<T, Error> {
+        let inner = self.open.last().map_or(0, |(_, start)| *start);
+        let outer = self.open.iter().rfind(|(open, _)| *open == marker);
+
+        invalid(format!(
+            "entity at {} is not closed before the entity at {}",
+            inner,
+            outer.map_or(0, |(_, start)| *start)
+        ))
+    }
+
+    fn next_is(&self, char: char) -> bool {
+        self.chars.get(self.position) == Some(&char)
+    }
+
+    /// Content of inline code or a pre block up to the closing backticks, where only `` ` `` and
+    /// `\` are escaped.
+    fn code(&mut self, close: &[char], start: usize) -> Result<String, Error> {
+        let mut code = String::new();
+
+        while let Some(&char) = self.chars.get(self.position) {
+            if self.chars[self.position..].starts_with(close) {
+                self.position += close.len();
+                return Ok(code);
+            }
+            if char == '\\' && self.position + 1 < self.chars.len() {
+                self.position += 1;
+            }
+            code.push(self.chars[self.position]);
+            self.position += 1;
+        }
+
+        invalid(format!("code at {} is not closed", start))
+    }
+
+    /// Url of a link in parentheses right after its text, where only `)` and `\` are escaped.
+    fn url(&mut self, start: usize) -> Result<String, Error> {
+        if !self.next_is('(') {
+            return invalid(format!("link at {} has no url", start));
+        }
+        self.position += 1;
+
+        self.code(&[')'], start)
+    }
+
+    /// Lines starting with `>` from the current one on, parsed without their `>`.
+    fn blockquote(&mut self) -> Result<Text, Error> {
+        let mut quote = String::new();
+
+        loop {
+            while let Some(&char) = self.chars.get(self.position) {
+                self.position += 1;
+                if char == '\n' {
+                    break;
+                }
+                quote.push(char);
+            }
+
+            if !self.next_is('>') {
+                break;
+            }
+            self.position += 1;
+            quote.push('\n');
+        }
+
+        // The line break after the quote is part of the text that follows it.
+        if self.chars.get(self.position - 1) == Some(&'\n') {
+            self.position -= 1;
+        }
+
+        parse_v2(&quote)
+    }
+}
+
+/// Plain text up to the closing characters, for the entities of the legacy Markdown.
+fn until(
+    chars: &[char],
+    position: &mut usize,
+    close: &[char],
+    start: usize,
+) -> Result<String, Error> {
+    let mut content = String::new();
+
+    while *position < chars.len() {
+        if chars[*position..].starts_with(close) {
+            *position += close.len();
+            return Ok(content);
+        }
+        content.push(chars[*position]);
+        *position += 1;
+    }
+
+    invalid(format!("entity at {} is not closed", start))
+}
+
+/// Pre block from the content between the backticks, a first line without spaces is its language.
+fn pre(content: String) -> Node {
+    let (language, code) = match content.split_once('\n') {
+        Some((language, code)) if !language.contains(char::is_whitespace) => (
+            Some(language.to_string()).filter(|language| !language.is_empty()),
+            code,
+        ),
+        _ => (None, content.as_str()),
+    };
+
+    Node::Pre {
+        code: code.strip_suffix('\n').unwrap_or(code).to_string(),
+        language,
+    }
+}
+
+/// Link to the url, or mention of the user of a `tg://user?id=` url.
+fn link(text: Text, url: String, start: usize) -> Result<Node, Error> {
+    match url.strip_prefix("tg://user?id=") {
+        Some(id) => match id.parse() {
+            Ok(user_id) => Ok(Node::Mention { text, user_id }),
+            Err(_) => invalid(format!("mention at {} has an invalid user id", start)),
+        },
+        None => Ok(Node::Link { text, url }),
+    }
+}
+
+/// Pushes the pending plain text.
+fn flush(text: Text, plain: &mut String) -> Text {
+    match plain.is_empty() {
+        true => text,
+        false => text.push(Node::Text(std::mem::take(plain))),
+    }
+}
+
+fn invalid<T>(reason: String) -> Result<T, Error> {
+    Err(Error::Validation {
+        field: String::from("text"),
+        reason,
+    })
+}
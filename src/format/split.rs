@@ -0,0 +1,297 @@
+use crate::api::types::message_entity::MessageEntity;
+use crate::format::entities;
+
+/// Maximum length of the text of a message, in UTF-16 code units.
+pub const TEXT_LIMIT: i64 = 4096;
+
+/// Maximum length of the caption of a media message, in UTF-16 code units.
+pub const CAPTION_LIMIT: i64 = 1024;
+
+/// Splits plain text with entities into parts of at most `limit` UTF-16 code units. Parts end at
+/// a paragraph, line, sentence or word boundary outside of every entity when there is one, entities
+/// are cut only as a last resort and carried over to the next part with re-based offsets.
+pub fn split(
+    text: &str,
+    entities: &[MessageEntity],
+    limit: i64,
+) -> Vec<(String, Vec<MessageEntity>)> {
+    split_after(text, entities, limit, limit)
+}
+
+/// Splits the plain text with entities of a caption too long for a media message, like `split`:
+/// the first part fits in a caption and the others in text messages sent after it.
+pub fn split_caption(text: &str, entities: &[MessageEntity]) -> Vec<(String, Vec<MessageEntity>)> {
+    split_after(text, entities, CAPTION_LIMIT, TEXT_LIMIT)
+}
+
+/// Splits `HTML` of a caption too long for a media message, like `split_html`: the first part
+/// fits in a caption and the others in text messages sent after it.
+pub fn split_html_caption(html: &str) -> Vec<String> {
+    split_html_after(html, CAPTION_LIMIT, TEXT_LIMIT)
+}
+
+/// `split` with a different limit for the first part.
+fn split_after(
+    text: &str,
+    entities: &[MessageEntity],
+    first: i64,
+    limit: i64,
+) -> Vec<(String, Vec<MessageEntity>)> {
+    let mut boundaries = vec![];
+    let mut offset = 0;
+    let mut previous = None;
+    for char in text.chars() {
+        offset += char.len_utf16() as i64;
+        boundaries.push(Boundary {
+            offset,
+            rank: rank(previous, char),
+            nested: false,
+        });
+        previous = Some(char);
+    }
+    for boundary in &mut boundaries {
+        boundary.nested = entities
+            .iter()
+            .any(|entity| entity.offset < boundary.offset && boundary.offset < entity.end());
+    }
+
+    let mut parts = vec![];
+    let mut start = 0;
+    for end in cuts(&boundaries, first, limit) {
+        if let Some((part, entities)) = entities::slice(text, entities, start, end - start) {
+            let (part, entities) = entities::trim(&part, &entities);
+            if !part.is_empty() {
+                parts.push((part, entities));
+            }
+        }
+        start = end;
+    }
+
+    parts
+}
+
+/// Splits text for the `HTML` parse mode into parts of at most `limit` visible UTF-16 code units.
+/// Tags and character references are never cut, parts end outside of every element when possible,
+/// otherwise the open elements are closed at the end of the part and opened again in the next one.
+pub fn split_html(html: &str, limit: i64) -> Vec<String> {
+    split_html_after(html, limit, limit)
+}
+
+/// `split_html` with a different limit for the first part.
+fn split_html_after(html: &str, first: i64, limit: i64) -> Vec<String> {
+    let tokens = tokenize(html);
+
+    let mut boundaries = vec![];
+    let mut offset = 0;
+    let mut depth = 0usize;
+    let mut previous = None;
+    for token in &tokens {
+        match token {
+            Token::Open { .. } => depth += 1,
+            Token::Close { .. } => depth = depth.saturating_sub(1),
+            Token::Char { char, width, .. } => {
+                offset += width;
+                boundaries.push(Boundary {
+                    offset,
+                    rank: rank(previous, *char),
+                    nested: depth > 0,
+                });
+                previous = Some(*char);
+            }
+        }
+    }
+
+    let mut parts = vec![];
+    let mut open: Vec<(&str, &str)> = vec![];
+    let mut visible = 0;
+    let mut tokens = tokens.iter().peekable();
+    for end in cuts(&boundaries, first, limit) {
+        let mut part: String = open.iter().map(|(raw, _)| *raw).collect();
+
+        while let Some(token) = tokens.peek() {
+            match token {
+                Token::Char { width, .. } if visible + width > end => break,
+                // Elements opened right at the cut belong to the next part.
+                Token::Open { .. } if visible >= end => break,
+                Token::Char { raw, width, .. } => {
+                    visible += width;
+                    part.push_str(raw);
+                }
+                Token::Open { raw, name } => {
+                    open.push((raw, name));
+                    part.push_str(raw);
+                }
+                Token::Close { raw, name } => {
+                    if let Some(index) = open.iter().rposition(|(_, open)| open == name) {
+                        open.remove(index);
+                    }
+                    part.push_str(raw);
+                }
+            }
+            tokens.next();
+        }
+
+        for (_, name) in open.iter().rev() {
+            part.push_str(&format!("</{}>", name));
+        }
+
+        if has_text(&part) {
+            parts.push(part.trim().to_string());
+        }
+    }
+
+    parts
+}
+
+/// Place the text may be cut at, after the character ending at `offset`.
+struct Boundary {
+    offset: i64,
+    /// 4 after a blank line, 3 after a line, 2 after a sentence, 1 after a word, 0 elsewhere.
+    rank: u8,
+    /// Inside an entity or an HTML element.
+    nested: bool,
+}
+
+fn rank(previous: Option<char>, char: char) -> u8 {
+    match (previous, char) {
+        (Some('\n'), '\n') => 4,
+        (_, '\n') => 3,
+        (Some('.' | '!' | '?' | '…'), char) if char.is_whitespace() => 2,
+        (_, char) if char.is_whitespace() => 1,
+        _ => 0,
+    }
+}
+
+/// Offsets the text is cut at, the last one is its end. The first part is at most `first` long.
+fn cuts(boundaries: &[Boundary], first: i64, limit: i64) -> Vec<i64> {
+    let mut cuts = vec![];
+    let mut start = 0;
+    let mut next = 0;
+    let total = boundaries.last().map_or(0, |boundary| boundary.offset);
+
+    loop {
+        let limit = match cuts.is_empty() {
+            true => first,
+            false => limit,
+        };
+        if total - start <= limit {
+            break;
+        }
+
+        let window = boundaries[next..]
+            .iter()
+            .take_while(|boundary| boundary.offset - start <= limit);
+        let best = window.max_by_key(|boundary| (!boundary.nested, boundary.rank, boundary.offset));
+
+        // A single character wider than the limit still has to go somewhere.
+        let end = best.map_or(boundaries[next].offset, |boundary| boundary.offset);
+        cuts.push(end);
+        start = end;
+        next = boundaries.partition_point(|boundary| boundary.offset <= start);
+    }
+    cuts.push(total);
+
+    cuts
+}
+
+enum Token<'a> {
+    Open {
+        raw: &'a str,
+        name: &'a str,
+    },
+    Close {
+        raw: &'a str,
+        name: &'a str,
+    },
+    /// Visible character, `raw` is a character reference like `&lt;` or the character itself.
+    Char {
+        raw: &'a str,
+        char: char,
+        width: i64,
+    },
+}
+
+fn tokenize(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = vec![];
+    let mut rest = html;
+
+    while let Some(char) = rest.chars().next() {
+        let tag = (char == '<').then(|| rest.find('>')).flatten();
+        let reference = (char == '&')
+            .then(|| rest.char_indices().take(10).find(|(_, char)| *char == ';'))
+            .flatten()
+            .and_then(|(end, _)| Some((end, decode(&rest[1..end])?)));
+
+        let (length, token) = match (tag, reference) {
+            (Some(end), _) => {
+                let raw = &rest[..=end];
+                let token = match raw.strip_prefix("</") {
+                    Some(tag) => Token::Close {
+                        raw,
+                        name: tag_name(tag),
+                    },
+                    None => Token::Open {
+                        raw,
+                        name: tag_name(&raw[1..]),
+                    },
+                };
+                (end + 1, token)
+            }
+            (None, Some((end, decoded))) => (
+                end + 1,
+                Token::Char {
+                    raw: &rest[..=end],
+                    char: decoded,
+                    width: decoded.len_utf16() as i64,
+                },
+            ),
+            (None, None) => (
+                char.len_utf8(),
+                Token::Char {
+                    raw: &rest[..char.len_utf8()],
+                    char,
+                    width: char.len_utf16() as i64,
+                },
+            ),
+        };
+
+        tokens.push(token);
+        rest = &rest[length..];
+    }
+
+    tokens
+}
+
+/// Name of a tag without its `<` or `</`, e.g. `a` of `a href="…">`.
+fn tag_name(tag: &str) -> &str {
+    tag.split(|char: char| char.is_whitespace() || char == '>')
+        .next()
+        .unwrap_or_default()
+}
+
+/// Character of a reference without its `&` and `;`, e.g. `lt` or `#x1F600`.
+fn decode(reference: &str) -> Option<char> {
+    match reference {
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "amp" => Some('&'),
+        "quot" => Some('"'),
+        _ => {
+            let code = match reference
+                .strip_prefix("#x")
+                .or(reference.strip_prefix("#X"))
+            {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => reference.strip_prefix('#')?.parse().ok()?,
+            };
+            char::from_u32(code)
+        }
+    }
+}
+
+/// Whether the HTML has visible characters other than whitespace.
+fn has_text(html: &str) -> bool {
+    tokenize(html)
+        .iter()
+        .any(|token| matches!(token, Token::Char { char, .. } if !char.is_whitespace()))
+}
@@ -0,0 +1,32 @@
+/// Length of the text in UTF-16 code units, the unit of the offsets and lengths of message entities.
+pub fn len(text: &str) -> i64 {
+    text.encode_utf16().count() as i64
+}
+
+/// Byte index of the UTF-16 offset, `None` if it is past the end or inside a character.
+pub fn byte_index(text: &str, offset: i64) -> Option<usize> {
+    if offset < 0 {
+        return None;
+    }
+
+    let mut units = 0;
+    for (index, char) in text.char_indices() {
+        if units == offset {
+            return Some(index);
+        }
+        if units > offset {
+            return None;
+        }
+        units += char.len_utf16() as i64;
+    }
+
+    (units == offset).then_some(text.len())
+}
+
+/// Part of the text at the UTF-16 offset and length.
+pub fn slice(text: &str, offset: i64, length: i64) -> Option<&str> {
+    let start = byte_index(text, offset)?;
+    let end = byte_index(text, offset.checked_add(length)?)?;
+
+    text.get(start..end)
+}
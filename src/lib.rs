@@ -1,10 +1,18 @@
 #![allow(clippy::new_without_default, dead_code)]
 
-pub use crate::client::Client;
+pub use crate::client::{Client, ClientBuilder};
+pub use crate::config::token::BotToken;
+pub use crate::errors::Error;
+pub use crate::registry::BotRegistry;
 
 pub mod api;
+pub mod auth;
 pub mod client;
-mod clients;
-mod config;
-mod errors;
+pub mod clients;
+pub mod config;
+pub mod deep_link;
+pub mod errors;
+pub mod format;
+pub mod passport;
+pub mod registry;
 mod tests;
@@ -0,0 +1,373 @@
+use crate::api::enums::passport_element_error::PassportElementError;
+use crate::api::types::credentials::Credentials;
+use crate::api::types::encrypted_credentials::EncryptedCredentials;
+use crate::api::types::file_credentials::FileCredentials;
+use crate::api::types::id_document_data::IdDocumentData;
+use crate::api::types::passport_data::PassportData;
+use crate::api::types::passport_element_error_data_field::PassportElementErrorDataField;
+use crate::api::types::passport_element_error_file::PassportElementErrorFile;
+use crate::api::types::passport_element_error_files::PassportElementErrorFiles;
+use crate::api::types::passport_element_error_front_side::PassportElementErrorFrontSide;
+use crate::api::types::passport_element_error_reverse_side::PassportElementErrorReverseSide;
+use crate::api::types::passport_element_error_selfie::PassportElementErrorSelfie;
+use crate::api::types::passport_element_error_translation_file::PassportElementErrorTranslationFile;
+use crate::api::types::passport_element_error_translation_files::PassportElementErrorTranslationFiles;
+use crate::api::types::passport_element_error_unspecified::PassportElementErrorUnspecified;
+use crate::api::types::personal_details::PersonalDetails;
+use crate::api::types::residential_address::ResidentialAddress;
+use crate::api::types::secure_value::SecureValue;
+use crate::errors::Error;
+use aes::cipher::block_padding::NoPadding;
+use aes::cipher::{BlockDecryptMut, KeyIvInit};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use rsa::pkcs1::DecodeRsaPrivateKey;
+use rsa::pkcs8::DecodePrivateKey;
+use rsa::{Oaep, RsaPrivateKey};
+use serde::de::DeserializeOwned;
+use sha1::Sha1;
+use sha2::{Digest, Sha256, Sha512};
+
+/// Private RSA key of the bot, its public key is the one given to @BotFather for Telegram Passport.
+pub struct PassportKey(RsaPrivateKey);
+
+impl PassportKey {
+    /// Key in PEM, either PKCS#1 `BEGIN RSA PRIVATE KEY` or PKCS#8 `BEGIN PRIVATE KEY`.
+    pub fn from_pem(pem: &str) -> Result<Self, Error> {
+        let key = RsaPrivateKey::from_pkcs1_pem(pem)
+            .or_else(|_| RsaPrivateKey::from_pkcs8_pem(pem))
+            .map_err(|error| Error::Config {
+                field: String::from("passport_key"),
+                reason: error.to_string(),
+            })?;
+
+        Ok(Self(key))
+    }
+}
+
+/// Passport shared by the user, with the data of the elements decrypted.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecryptedPassport {
+    /// Nonce of the authorization request, to be checked against the one the bot sent.
+    pub nonce: String,
+    pub elements: Vec<DecryptedElement>,
+}
+
+impl DecryptedPassport {
+    /// Rejects a passport shared for another authorization request than the bot's.
+    pub fn check_nonce(&self, nonce: &str) -> Result<(), Error> {
+        match self.nonce == nonce {
+            true => Ok(()),
+            false => Err(invalid("nonce", "doesn't match the one of the request")),
+        }
+    }
+
+    pub fn element(&self, kind: &str) -> Option<&DecryptedElement> {
+        self.elements.iter().find(|element| element.kind == kind)
+    }
+
+    pub fn personal_details(&self) -> Option<&PersonalDetails> {
+        match self.element("personal_details")?.data.as_ref()? {
+            ElementData::PersonalDetails(details) => Some(details),
+            _ => None,
+        }
+    }
+}
+
+/// Passport element, e.g. `passport` or `phone_number`, with its data decrypted.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecryptedElement {
+    pub kind: String,
+    /// Hash of the element, identifies it in a `PassportElementErrorUnspecified`.
+    pub hash: String,
+    pub data: Option<ElementData>,
+    pub phone_number: Option<String>,
+    pub email: Option<String>,
+    /// Credentials of the files of the element, see `decrypt_file`.
+    pub credentials: Option<SecureValue>,
+}
+
+impl DecryptedElement {
+    /// Error in a field of the data, e.g. `birth_date` of `personal_details`.
+    pub fn data_field_error(
+        &self,
+        field_name: &str,
+        message: &str,
+    ) -> Result<PassportElementError, Error> {
+        let data = self.value().and_then(|value| value.data.as_ref());
+
+        Ok(PassportElementError::PassportElementErrorDataField(
+            PassportElementErrorDataField {
+                kind: self.kind.clone(),
+                source: String::from("data"),
+                field_name: field_name.to_string(),
+                data_hash: data.ok_or_else(|| not_shared("data"))?.data_hash.clone(),
+                message: message.to_string(),
+            },
+        ))
+    }
+
+    pub fn front_side_error(&self, message: &str) -> Result<PassportElementError, Error> {
+        let value = self.value().and_then(|value| value.front_side.as_ref());
+
+        Ok(PassportElementError::PassportElementErrorFrontSide(
+            PassportElementErrorFrontSide {
+                kind: self.kind.clone(),
+                source: String::from("front_side"),
+                file_hash: file_hash("front_side", value)?,
+                message: message.to_string(),
+            },
+        ))
+    }
+
+    pub fn reverse_side_error(&self, message: &str) -> Result<PassportElementError, Error> {
+        let value = self.value().and_then(|value| value.reverse_side.as_ref());
+
+        Ok(PassportElementError::PassportElementErrorReverseSide(
+            PassportElementErrorReverseSide {
+                kind: self.kind.clone(),
+                source: String::from("reverse_side"),
+                file_hash: file_hash("reverse_side", value)?,
+                message: message.to_string(),
+            },
+        ))
+    }
+
+    /// Error in the selfie with the document, e.g. a blurry one.
+    pub fn selfie_error(&self, message: &str) -> Result<PassportElementError, Error> {
+        let value = self.value().and_then(|value| value.selfie.as_ref());
+
+        Ok(PassportElementError::PassportElementErrorSelfie(
+            PassportElementErrorSelfie {
+                kind: self.kind.clone(),
+                source: String::from("selfie"),
+                file_hash: file_hash("selfie", value)?,
+                message: message.to_string(),
+            },
+        ))
+    }
+
+    /// Error in one of the scans of `files`, by its index.
+    pub fn file_error(&self, index: usize, message: &str) -> Result<PassportElementError, Error> {
+        let files = self.value().and_then(|value| value.files.as_ref());
+
+        Ok(PassportElementError::PassportElementErrorFile(
+            PassportElementErrorFile {
+                kind: self.kind.clone(),
+                source: String::from("file"),
+                file_hash: file_hash("files", files.and_then(|files| files.get(index)))?,
+                message: message.to_string(),
+            },
+        ))
+    }
+
+    pub fn files_error(&self, message: &str) -> Result<PassportElementError, Error> {
+        let files = self.value().and_then(|value| value.files.as_ref());
+
+        Ok(PassportElementError::PassportElementErrorFiles(
+            PassportElementErrorFiles {
+                kind: self.kind.clone(),
+                source: String::from("files"),
+                file_hashes: file_hashes("files", files)?,
+                message: message.to_string(),
+            },
+        ))
+    }
+
+    /// Error in one of the files of `translation`, by its index.
+    pub fn translation_file_error(
+        &self,
+        index: usize,
+        message: &str,
+    ) -> Result<PassportElementError, Error> {
+        let files = self.value().and_then(|value| value.translation.as_ref());
+
+        Ok(PassportElementError::PassportElementErrorTranslationFile(
+            PassportElementErrorTranslationFile {
+                kind: self.kind.clone(),
+                source: String::from("translation_file"),
+                file_hash: file_hash("translation", files.and_then(|files| files.get(index)))?,
+                message: message.to_string(),
+            },
+        ))
+    }
+
+    pub fn translation_files_error(&self, message: &str) -> Result<PassportElementError, Error> {
+        let files = self.value().and_then(|value| value.translation.as_ref());
+
+        Ok(PassportElementError::PassportElementErrorTranslationFiles(
+            PassportElementErrorTranslationFiles {
+                kind: self.kind.clone(),
+                source: String::from("translation_files"),
+                file_hashes: file_hashes("translation", files)?,
+                message: message.to_string(),
+            },
+        ))
+    }
+
+    /// Error somewhere in the element, e.g. a `phone_number` that has no data or files.
+    pub fn unspecified_error(&self, message: &str) -> PassportElementError {
+        PassportElementError::PassportElementErrorUnspecified(PassportElementErrorUnspecified {
+            kind: self.kind.clone(),
+            source: String::from("unspecified"),
+            element_hash: self.hash.clone(),
+            message: message.to_string(),
+        })
+    }
+
+    fn value(&self) -> Option<&SecureValue> {
+        self.credentials.as_ref()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ElementData {
+    PersonalDetails(PersonalDetails),
+    /// `passport`, `driver_license`, `identity_card` or `internal_passport`.
+    IdDocument(IdDocumentData),
+    Address(ResidentialAddress),
+}
+
+/// Decrypts the credentials and then the data of every element of the passport.
+pub fn decrypt(passport: &PassportData, key: &PassportKey) -> Result<DecryptedPassport, Error> {
+    let credentials = decrypt_credentials(&passport.credentials, key)?;
+
+    let mut elements = vec![];
+    for element in &passport.data {
+        let value = credentials.secure_data.get(&element.kind);
+
+        let data = match (&element.data, value.and_then(|value| value.data.as_ref())) {
+            (Some(data), Some(credentials)) => {
+                let data = decrypt_data(
+                    &decode("data", data)?,
+                    &decode("secret", &credentials.secret)?,
+                    &decode("data_hash", &credentials.data_hash)?,
+                )?;
+                Some(element_data(&element.kind, &data)?)
+            }
+            (Some(_), None) => return Err(invalid(&element.kind, "has no credentials")),
+            (None, _) => None,
+        };
+
+        elements.push(DecryptedElement {
+            kind: element.kind.clone(),
+            hash: element.hash.clone(),
+            data: data.flatten(),
+            phone_number: element.phone_number.clone(),
+            email: element.email.clone(),
+            credentials: value.cloned(),
+        });
+    }
+
+    Ok(DecryptedPassport {
+        nonce: credentials.nonce,
+        elements,
+    })
+}
+
+/// Decrypts the secret with the key of the bot, then the credentials with the secret.
+pub fn decrypt_credentials(
+    credentials: &EncryptedCredentials,
+    key: &PassportKey,
+) -> Result<Credentials, Error> {
+    let secret = key
+        .0
+        .decrypt(Oaep::new::<Sha1>(), &decode("secret", &credentials.secret)?)
+        .map_err(|_| invalid("secret", "can't be decrypted with the key"))?;
+    let data = decrypt_data(
+        &decode("data", &credentials.data)?,
+        &secret,
+        &decode("hash", &credentials.hash)?,
+    )?;
+
+    json("credentials", &data)
+}
+
+/// Decrypts the content of a passport file downloaded with `getFile`.
+pub fn decrypt_file(content: &[u8], credentials: &FileCredentials) -> Result<Vec<u8>, Error> {
+    decrypt_data(
+        content,
+        &decode("secret", &credentials.secret)?,
+        &decode("file_hash", &credentials.file_hash)?,
+    )
+}
+
+/// AES-256-CBC with the key and iv derived from the secret and the hash, checked against the hash
+/// and stripped of its random padding, the first byte being its length.
+fn decrypt_data(data: &[u8], secret: &[u8], hash: &[u8]) -> Result<Vec<u8>, Error> {
+    let secret_hash = Sha512::new()
+        .chain_update(secret)
+        .chain_update(hash)
+        .finalize();
+    let (key, iv) = (&secret_hash[..32], &secret_hash[32..48]);
+
+    let mut data = data.to_vec();
+    cbc::Decryptor::<aes::Aes256>::new(key.into(), iv.into())
+        .decrypt_padded_mut::<NoPadding>(&mut data)
+        .map_err(|_| invalid("data", "must be a multiple of 16 bytes long"))?;
+
+    if Sha256::digest(&data).as_slice() != hash {
+        return Err(invalid("data", "doesn't match its hash"));
+    }
+
+    let padding = data.first().copied().unwrap_or_default() as usize;
+    if !(32..=255).contains(&padding) || padding > data.len() {
+        return Err(invalid("data", "has a wrong padding"));
+    }
+
+    Ok(data.split_off(padding))
+}
+
+fn element_data(kind: &str, data: &[u8]) -> Result<Option<ElementData>, Error> {
+    let data = match kind {
+        "personal_details" => ElementData::PersonalDetails(json(kind, data)?),
+        "passport" | "driver_license" | "identity_card" | "internal_passport" => {
+            ElementData::IdDocument(json(kind, data)?)
+        }
+        "address" => ElementData::Address(json(kind, data)?),
+        _ => return Ok(None),
+    };
+
+    Ok(Some(data))
+}
+
+fn file_hash(field: &str, credentials: Option<&FileCredentials>) -> Result<String, Error> {
+    match credentials {
+        Some(credentials) => Ok(credentials.file_hash.clone()),
+        None => Err(not_shared(field)),
+    }
+}
+
+fn file_hashes(
+    field: &str,
+    credentials: Option<&Vec<FileCredentials>>,
+) -> Result<Vec<String>, Error> {
+    match credentials {
+        Some(credentials) if !credentials.is_empty() => Ok(credentials
+            .iter()
+            .map(|credentials| credentials.file_hash.clone())
+            .collect()),
+        _ => Err(not_shared(field)),
+    }
+}
+
+fn not_shared(field: &str) -> Error {
+    invalid(field, "wasn't shared with the element")
+}
+
+fn json<T: DeserializeOwned>(field: &str, data: &[u8]) -> Result<T, Error> {
+    serde_json::from_slice(data).map_err(|error| invalid(field, &error.to_string()))
+}
+
+fn decode(field: &str, base64: &str) -> Result<Vec<u8>, Error> {
+    STANDARD
+        .decode(base64)
+        .map_err(|_| invalid(field, "must be base64 encoded"))
+}
+
+fn invalid(field: &str, reason: &str) -> Error {
+    Error::Passport {
+        field: field.to_string(),
+        reason: reason.to_string(),
+    }
+}
@@ -0,0 +1,167 @@
+use crate::client::{Client, ClientBuilder};
+use crate::clients::limiter::Limiter;
+use crate::clients::retry::Retry;
+use crate::config::token::BotToken;
+use crate::config::Config;
+use crate::errors::Error;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+/// Maximum length of a webhook secret token.
+const SECRET_LENGTH: usize = 256;
+
+#[derive(Debug)]
+struct Bot {
+    client: Arc<Client>,
+    secret: String,
+}
+
+/// Many bots served by one process. The bots share the HTTP connection pools, the retry policy and
+/// optionally a limiter keyed by bot id, while every bot gets its own `Client` keyed by its bot id.
+#[derive(Debug)]
+pub struct BotRegistry {
+    config: Config,
+    sync_client: reqwest::blocking::Client,
+    async_client: reqwest::Client,
+    retry: Retry,
+    limiter: Option<Arc<Limiter>>,
+    bots: RwLock<HashMap<u64, Bot>>,
+}
+
+impl BotRegistry {
+    /// Shared settings are taken from the config, its token is ignored.
+    pub fn new(config: Config) -> Result<Self, Error> {
+        let sync_client = config.build_sync_client()?;
+        let async_client = config.build_async_client()?;
+
+        Ok(Self {
+            config,
+            sync_client,
+            async_client,
+            retry: Retry::default(),
+            limiter: None,
+            bots: RwLock::new(HashMap::new()),
+        })
+    }
+
+    /// Retry policy of the bots registered afterwards.
+    pub fn retry(mut self, retry: Retry) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// Limiter shared by the bots registered afterwards. Every bot keeps its own flood limits in it,
+    /// as Telegram counts the messages of each bot separately.
+    pub fn limiter(mut self, limiter: Arc<Limiter>) -> Self {
+        self.limiter = Some(limiter);
+        self
+    }
+
+    /// Adds a bot, or replaces the one with the same bot id. Its webhook requests must carry the
+    /// secret in the `X-Telegram-Bot-Api-Secret-Token` header, so set it as the `secret_token` of
+    /// setWebhook: the bot id in the path is public, it is the prefix of the token.
+    pub fn register(&self, token: &str, secret: &str) -> Result<Arc<Client>, Error> {
+        let valid = !secret.is_empty()
+            && secret.len() <= SECRET_LENGTH
+            && secret
+                .chars()
+                .all(|char| char.is_ascii_alphanumeric() || char == '_' || char == '-');
+
+        if !valid {
+            return Err(Error::Config {
+                field: String::from("secret_token"),
+                reason: format!(
+                    "must be 1-{} characters A-Z, a-z, 0-9, _ and -",
+                    SECRET_LENGTH
+                ),
+            });
+        }
+
+        self.insert(token, secret.to_string())
+    }
+
+    pub fn get(&self, bot_id: u64) -> Option<Arc<Client>> {
+        let bots = self.bots.read().unwrap();
+        bots.get(&bot_id).map(|bot| bot.client.clone())
+    }
+
+    pub fn remove(&self, bot_id: u64) -> Option<Arc<Client>> {
+        let mut bots = self.bots.write().unwrap();
+        bots.remove(&bot_id).map(|bot| bot.client)
+    }
+
+    pub fn ids(&self) -> Vec<u64> {
+        let bots = self.bots.read().unwrap();
+        let mut ids: Vec<u64> = bots.keys().copied().collect();
+        ids.sort_unstable();
+        ids
+    }
+
+    pub fn len(&self) -> usize {
+        self.bots.read().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Finds the bot a webhook request is meant for. The last segment of the path is taken as the bot
+    /// id, e.g. `/webhook/123456`, otherwise the bot is looked up by the secret header.
+    /// A bot only accepts requests carrying its secret.
+    pub fn route(&self, path: &str, secret: Option<&str>) -> Option<Arc<Client>> {
+        let secret = secret?;
+        let bots = self.bots.read().unwrap();
+        let by_path = path
+            .trim_end_matches('/')
+            .rsplit('/')
+            .next()
+            .and_then(|segment| segment.parse::<u64>().ok())
+            .and_then(|bot_id| bots.get(&bot_id));
+        let by_secret = || bots.values().find(|bot| same(&bot.secret, secret));
+        let bot = by_path.or_else(by_secret)?;
+
+        match same(&bot.secret, secret) {
+            true => Some(bot.client.clone()),
+            false => None,
+        }
+    }
+
+    fn insert(&self, token: &str, secret: String) -> Result<Arc<Client>, Error> {
+        let token = BotToken::new(token)?;
+        let bot_id = token.bot_id().unwrap_or_default();
+        let config = Config {
+            token,
+            ..self.config.clone()
+        };
+
+        let mut builder = ClientBuilder::from_config(config)
+            .sync_client(self.sync_client.clone())
+            .async_client(self.async_client.clone())
+            .retry(self.retry.clone());
+        if let Some(limiter) = &self.limiter {
+            builder = builder.limiter(limiter.clone());
+        }
+        let client = Arc::new(builder.build()?);
+
+        let mut bots = self.bots.write().unwrap();
+        bots.insert(
+            bot_id,
+            Bot {
+                client: client.clone(),
+                secret,
+            },
+        );
+
+        Ok(client)
+    }
+}
+
+/// Compares secrets in constant time, only their length may leak.
+fn same(expected: &str, secret: &str) -> bool {
+    let difference = expected
+        .bytes()
+        .zip(secret.bytes())
+        .fold(0, |difference, (a, b)| difference | (a ^ b));
+
+    expected.len() == secret.len() && difference == 0
+}
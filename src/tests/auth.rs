@@ -0,0 +1,187 @@
+use crate::auth::login::validate_login;
+use crate::auth::web_app::validate_init_data;
+use crate::config::token::BotToken;
+use crate::errors::Error;
+use crate::tests::helpers::TOKEN;
+use hmac::{Hmac, Mac};
+use sha2::{Digest, Sha256};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+fn hmac(key: &[u8], data: &str) -> Vec<u8> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).unwrap();
+    mac.update(data.as_bytes());
+    mac.finalize().into_bytes().to_vec()
+}
+
+fn now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs() as i64
+}
+
+/// Query string signed the way Telegram signs authorization data with the secret.
+fn signed(secret: &[u8], fields: &[(&str, String)]) -> String {
+    let mut lines: Vec<String> = fields
+        .iter()
+        .map(|(key, value)| format!("{}={}", key, value))
+        .collect();
+    lines.sort();
+    let hash = hex::encode(hmac(secret, &lines.join("\n")));
+
+    url::form_urlencoded::Serializer::new(String::new())
+        .extend_pairs(fields.iter().map(|(key, value)| (*key, value.as_str())))
+        .append_pair("hash", &hash)
+        .finish()
+}
+
+fn init_data(token: &str, fields: &[(&str, String)]) -> String {
+    signed(&hmac(b"WebAppData", token), fields)
+}
+
+fn fields(auth_date: i64) -> Vec<(&'static str, String)> {
+    vec![
+        ("query_id", String::from("AAHdF6IQAAAAAN0XohDhrOrc")),
+        (
+            "user",
+            String::from(
+                r#"{"id":279058397,"first_name":"Vladislav","username":"vdkfrost","language_code":"ru","is_premium":true,"allows_write_to_pm":true}"#,
+            ),
+        ),
+        (
+            "chat",
+            String::from(r#"{"id":-100123,"type":"supergroup","title":"Bots & Co"}"#),
+        ),
+        ("start_param", String::from("ref_42")),
+        ("auth_date", auth_date.to_string()),
+    ]
+}
+
+fn rejected_field(error: Error) -> String {
+    match error {
+        Error::Auth { field, .. } => field,
+        error => panic!("unexpected error {:?}", error),
+    }
+}
+
+#[test]
+fn web_app_init_data() {
+    let auth_date = now();
+    let data = init_data(TOKEN, &fields(auth_date));
+
+    let data =
+        validate_init_data(&data, &BotToken::from(TOKEN), Some(Duration::from_secs(60))).unwrap();
+
+    assert_eq!(data.query_id.as_deref(), Some("AAHdF6IQAAAAAN0XohDhrOrc"));
+    assert_eq!(data.start_param.as_deref(), Some("ref_42"));
+    assert_eq!(data.auth_date, auth_date);
+    let user = data.user.unwrap();
+    assert_eq!(user.id, 279058397);
+    assert_eq!(user.username.as_deref(), Some("vdkfrost"));
+    assert_eq!(user.allows_write_to_pm, Some(true));
+    let chat = data.chat.unwrap();
+    assert_eq!(chat.kind, "supergroup");
+    assert_eq!(chat.title, "Bots & Co");
+    assert!(data.receiver.is_none());
+}
+
+#[test]
+fn web_app_init_data_tampered() {
+    let data = init_data(TOKEN, &fields(now())).replace("ref_42", "ref_43");
+    let error = validate_init_data(&data, &BotToken::from(TOKEN), None).unwrap_err();
+    assert_eq!(rejected_field(error), "hash");
+
+    let other = "1111111111:XXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX";
+    let data = init_data(other, &fields(now()));
+    let error = validate_init_data(&data, &BotToken::from(TOKEN), None).unwrap_err();
+    assert_eq!(rejected_field(error), "hash");
+
+    let error = validate_init_data("auth_date=1", &BotToken::from(TOKEN), None).unwrap_err();
+    assert_eq!(rejected_field(error), "hash");
+}
+
+#[test]
+fn web_app_init_data_expired() {
+    let data = init_data(TOKEN, &fields(now() - 3600));
+
+    let error = validate_init_data(&data, &BotToken::from(TOKEN), Some(Duration::from_secs(60)))
+        .unwrap_err();
+    assert_eq!(rejected_field(error), "auth_date");
+
+    assert!(validate_init_data(&data, &BotToken::from(TOKEN), None).is_ok());
+}
+
+#[test]
+fn web_app_init_data_from_the_future() {
+    let max_age = Some(Duration::from_secs(3600));
+
+    let data = init_data(TOKEN, &fields(now() + 30));
+    assert!(validate_init_data(&data, &BotToken::from(TOKEN), max_age).is_ok());
+
+    let data = init_data(TOKEN, &fields(now() + 86400));
+    let error = validate_init_data(&data, &BotToken::from(TOKEN), max_age).unwrap_err();
+    assert_eq!(error.to_string(), "Auth Error: auth_date is in the future");
+}
+
+fn login(token: &str, auth_date: i64) -> String {
+    let fields = [
+        ("id", String::from("42")),
+        ("first_name", String::from("Ann")),
+        ("username", String::from("ann")),
+        (
+            "photo_url",
+            String::from("https://t.me/i/userpic/320/ann.jpg"),
+        ),
+        ("auth_date", auth_date.to_string()),
+    ];
+
+    signed(&Sha256::digest(token.as_bytes()), &fields)
+}
+
+#[test]
+fn login_data() {
+    let auth_date = now();
+    let query = login(TOKEN, auth_date);
+
+    let data = validate_login(
+        &query,
+        &BotToken::from(TOKEN),
+        Some(Duration::from_secs(60)),
+    )
+    .unwrap();
+
+    assert_eq!(data.id, 42);
+    assert_eq!(data.first_name, "Ann");
+    assert_eq!(data.last_name, None);
+    assert_eq!(data.username.as_deref(), Some("ann"));
+    assert_eq!(
+        data.photo_url.as_deref(),
+        Some("https://t.me/i/userpic/320/ann.jpg")
+    );
+    assert_eq!(data.auth_date, auth_date);
+}
+
+#[test]
+fn login_data_rejected() {
+    let token = BotToken::from(TOKEN);
+
+    let query = login(TOKEN, now()).replace("id=42", "id=43");
+    assert_eq!(
+        rejected_field(validate_login(&query, &token, None).unwrap_err()),
+        "hash"
+    );
+
+    // Signed the Web App way instead of with the hash of the token.
+    let query = init_data(
+        TOKEN,
+        &[("id", String::from("42")), ("auth_date", now().to_string())],
+    );
+    assert_eq!(
+        rejected_field(validate_login(&query, &token, None).unwrap_err()),
+        "hash"
+    );
+
+    let query = login(TOKEN, now() - 86400 * 2);
+    let error = validate_login(&query, &token, Some(Duration::from_secs(86400))).unwrap_err();
+    assert_eq!(rejected_field(error), "auth_date");
+}
@@ -1 +1,221 @@
+use crate::api::requests::sync::Requests;
+use crate::api::types::user::User;
+use crate::clients::retry::Retry;
+use crate::config::API_URL;
+use crate::errors::Error;
+use crate::tests::helpers::TOKEN;
+use crate::{Client, ClientBuilder};
+use std::fs;
+use std::time::Duration;
 
+#[test]
+fn new_without_arguments() {
+    let client = Client::new(TOKEN).unwrap();
+
+    assert_eq!(client.config.token.expose(), TOKEN);
+    assert_eq!(client.config.url, API_URL);
+    assert_eq!(client.config.proxy, None);
+}
+
+#[test]
+fn builder_settings() {
+    let client = Client::builder(TOKEN)
+        .url("http://localhost:8081/")
+        .timeout(Duration::from_secs(30))
+        .connect_timeout(Duration::from_secs(10))
+        .updates_timeout(Duration::from_secs(50))
+        .proxy("http://127.0.0.1:3128")
+        .user_agent("telegram-test")
+        .retry(Retry::new())
+        .build()
+        .unwrap();
+
+    assert_eq!(client.config.url, "http://localhost:8081");
+    assert_eq!(client.config.timeout, 30);
+    assert_eq!(client.config.connect_timeout, 10);
+    assert_eq!(client.config.updates_timeout, 50);
+    assert_eq!(
+        client.config.proxy.as_deref(),
+        Some("http://127.0.0.1:3128")
+    );
+    assert_eq!(client.config.user_agent.as_deref(), Some("telegram-test"));
+}
+
+#[test]
+fn builder_invalid_proxy() {
+    let result = Client::builder(TOKEN).proxy("not a proxy url").build();
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn new_with_malformed_token() {
+    assert!(matches!(
+        Client::new("not a token"),
+        Err(Error::Config { .. })
+    ));
+}
+
+#[test]
+fn builder_rejects_sub_second_and_zero_timeouts() {
+    let field = |builder: ClientBuilder| match builder.build() {
+        Err(Error::Config { field, .. }) => field,
+        result => panic!("unexpected result {:?}", result),
+    };
+
+    assert_eq!(
+        field(Client::builder(TOKEN).timeout(Duration::from_millis(500))),
+        "timeout"
+    );
+    assert_eq!(
+        field(Client::builder(TOKEN).connect_timeout(Duration::from_millis(1500))),
+        "connect_timeout"
+    );
+    assert_eq!(
+        field(Client::builder(TOKEN).updates_timeout(Duration::from_millis(2500))),
+        "updates_timeout"
+    );
+    assert_eq!(
+        field(Client::builder(TOKEN).timeout(Duration::ZERO)),
+        "timeout"
+    );
+}
+
+#[test]
+fn builder_custom_http_client() {
+    let mock_response = fs::read_to_string("src/tests/responses/get_me_success.json").unwrap();
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("POST", format!("/bot{}/getMe", TOKEN).as_str())
+        .match_header("user-agent", "custom-client")
+        .with_body(&mock_response)
+        .create();
+    let http = reqwest::blocking::Client::builder()
+        .user_agent("custom-client")
+        .build()
+        .unwrap();
+
+    let client = Client::builder(TOKEN)
+        .url(&server.url())
+        .sync_client(http)
+        .build()
+        .unwrap();
+    let user = client.sync.get_me().unwrap();
+
+    assert!(matches!(user, User { is_bot: true, .. }));
+    mock.assert();
+}
+
+#[test]
+fn builder_through_proxy() {
+    let mock_response = fs::read_to_string("src/tests/responses/get_me_success.json").unwrap();
+    let mut proxy = mockito::Server::new();
+    let mock = proxy
+        .mock("POST", format!("/bot{}/getMe", TOKEN).as_str())
+        .match_header("host", "api.telegram.invalid")
+        .with_body(&mock_response)
+        .create();
+
+    let client = Client::builder(TOKEN)
+        .url("http://api.telegram.invalid")
+        .proxy(&proxy.url())
+        .build()
+        .unwrap();
+    client.sync.get_me().unwrap();
+
+    mock.assert();
+}
+
+#[test]
+fn builder_bypasses_proxy() {
+    let mock_response = fs::read_to_string("src/tests/responses/get_me_success.json").unwrap();
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("POST", format!("/bot{}/getMe", TOKEN).as_str())
+        .with_body(&mock_response)
+        .create();
+
+    let client = Client::builder(TOKEN)
+        .url(&server.url())
+        .proxy("http://127.0.0.1:1")
+        .no_proxy("localhost,127.0.0.1")
+        .build()
+        .unwrap();
+    client.sync.get_me().unwrap();
+
+    mock.assert();
+}
+
+#[test]
+fn builder_socks5_proxy() {
+    let client = Client::builder(TOKEN)
+        .proxy("socks5://127.0.0.1:1080")
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        client.config.proxy.as_deref(),
+        Some("socks5://127.0.0.1:1080")
+    );
+}
+
+#[test]
+fn builder_tls_settings() {
+    let client = Client::builder(TOKEN)
+        .root_certificate("src/tests/certificates/ca.pem")
+        .client_certificate(
+            "src/tests/certificates/client.pem",
+            "src/tests/certificates/client.key",
+        )
+        .build()
+        .unwrap();
+
+    assert_eq!(client.config.root_certificates.len(), 1);
+    assert!(client.config.client_key.is_some());
+}
+
+#[test]
+fn builder_invalid_tls_settings() {
+    let field = |builder: ClientBuilder| match builder.build() {
+        Err(Error::Config { field, .. }) => field,
+        result => panic!("unexpected {:?}", result),
+    };
+
+    assert_eq!(
+        field(Client::builder(TOKEN).root_certificate("src/tests/certificates/missing.pem")),
+        "root_certificates"
+    );
+    assert_eq!(
+        field(Client::builder(TOKEN).root_certificate("src/tests/certificates/client.key")),
+        "root_certificates"
+    );
+    assert_eq!(
+        field(Client::builder(TOKEN).client_certificate(
+            "src/tests/certificates/client.pem",
+            "src/tests/certificates/ca.pem",
+        )),
+        "client_certificate"
+    );
+}
+
+#[test]
+fn builder_http_versions() {
+    let mock_response = fs::read_to_string("src/tests/responses/get_me_success.json").unwrap();
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("POST", format!("/bot{}/getMe", TOKEN).as_str())
+        .with_body(&mock_response)
+        .create();
+
+    let client = Client::builder(TOKEN)
+        .url(&server.url())
+        .http2_prior_knowledge()
+        .http1_only()
+        .build()
+        .unwrap();
+    client.sync.get_me().unwrap();
+
+    assert!(client.config.http1_only);
+    assert!(!client.config.http2_prior_knowledge);
+    mock.assert();
+}
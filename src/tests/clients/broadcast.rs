@@ -0,0 +1,184 @@
+use crate::api::enums::chat_uid::ChatUId;
+use crate::api::enums::file_input::FileInput;
+use crate::api::params::send_message::SendMessage;
+use crate::api::params::send_photo::SendPhoto;
+use crate::clients::broadcast::{Broadcast, Report, Template};
+use crate::errors::Error;
+use crate::tests::helpers::{mock_sync, TOKEN};
+use mockito::{Matcher, Mock, ServerGuard};
+use serde_json::json;
+use std::fs;
+
+fn mock_chat(server: &mut ServerGuard, chat_id: i64, status: usize, response: &str) -> Mock {
+    server
+        .mock("POST", format!("/bot{}/sendMessage", TOKEN).as_str())
+        .match_body(Matcher::PartialJson(
+            json!({ "chat_id": chat_id, "text": "News" }),
+        ))
+        .with_status(status)
+        .with_body(response)
+        .create()
+}
+
+fn template() -> Template {
+    Template::Text(SendMessage {
+        text: String::from("News"),
+        ..Default::default()
+    })
+}
+
+fn mock_chats(server: &mut ServerGuard) -> Vec<Mock> {
+    let success = fs::read_to_string("src/tests/responses/send_message_success.json").unwrap();
+
+    vec![
+        mock_chat(server, 1, 200, &success),
+        mock_chat(
+            server,
+            2,
+            403,
+            r#"{"ok":false,"error_code":403,"description":"Forbidden: bot was blocked by the user"}"#,
+        ),
+        mock_chat(
+            server,
+            3,
+            400,
+            r#"{"ok":false,"error_code":400,"description":"Bad Request: chat not found"}"#,
+        ),
+        mock_chat(
+            server,
+            4,
+            403,
+            r#"{"ok":false,"error_code":403,"description":"Forbidden: user is deactivated"}"#,
+        ),
+        mock_chat(
+            server,
+            5,
+            400,
+            r#"{"ok":false,"error_code":400,"description":"Bad Request: message text is empty"}"#,
+        ),
+    ]
+}
+
+#[test]
+fn broadcast_report() {
+    let mut server = mockito::Server::new();
+    let mocks = mock_chats(&mut server);
+    let sync = mock_sync(&server);
+    let mut progress = vec![];
+
+    let report = Broadcast::new(&sync)
+        .run((1..=5).map(ChatUId::from), &template(), |report| {
+            progress.push(report.processed)
+        })
+        .unwrap();
+
+    assert_eq!(progress, vec![1, 2, 3, 4, 5]);
+    assert_eq!(report.processed, 5);
+    assert_eq!(report.sent, 1);
+    assert_eq!(report.blocked, vec![ChatUId::from(2)]);
+    assert_eq!(report.not_found, vec![ChatUId::from(3)]);
+    assert_eq!(report.deactivated, vec![ChatUId::from(4)]);
+    assert_eq!(report.failed.len(), 1);
+    assert_eq!(report.failed[0].chat_id, ChatUId::from(5));
+    assert_eq!(report.failed[0].error_code, Some(400));
+    mocks.iter().for_each(Mock::assert);
+}
+
+#[test]
+fn broadcast_resumes_from_checkpoint() {
+    let path = std::env::temp_dir().join(format!("broadcast-{}.json", std::process::id()));
+    let checkpoint = Report {
+        processed: 2,
+        sent: 1,
+        blocked: vec![ChatUId::from(2)],
+        ..Default::default()
+    };
+    fs::write(&path, serde_json::to_string(&checkpoint).unwrap()).unwrap();
+
+    let mut server = mockito::Server::new();
+    let mocks = mock_chats(&mut server);
+    let sync = mock_sync(&server);
+
+    let report = Broadcast::new(&sync)
+        .checkpoint(&path, 1)
+        .run((1..=5).map(ChatUId::from), &template(), |_| {})
+        .unwrap();
+    let saved = serde_json::from_str::<Report>(&fs::read_to_string(&path).unwrap()).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(report, saved);
+    assert_eq!(report.processed, 5);
+    assert_eq!(report.sent, 1);
+    assert_eq!(report.blocked, vec![ChatUId::from(2)]);
+    assert_eq!(report.not_found, vec![ChatUId::from(3)]);
+    assert!(!mocks[0].matched());
+    assert!(!mocks[1].matched());
+    mocks[2..].iter().for_each(Mock::assert);
+}
+
+#[test]
+fn broadcast_leaves_floods_to_client() {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("POST", format!("/bot{}/sendMessage", TOKEN).as_str())
+        .with_status(429)
+        .with_body(r#"{"ok":false,"error_code":429,"description":"Too Many Requests: retry after 0","parameters":{"retry_after":0}}"#)
+        .expect(1)
+        .create();
+    let sync = mock_sync(&server);
+
+    let report = Broadcast::new(&sync)
+        .run([ChatUId::from(1)], &template(), |_| {})
+        .unwrap();
+
+    assert_eq!(report.failed.len(), 1);
+    assert_eq!(report.failed[0].error_code, Some(429));
+    mock.assert();
+}
+
+#[test]
+fn checkpoint_with_tmp_extension() {
+    let path = std::env::temp_dir().join(format!("broadcast-{}.tmp", std::process::id()));
+    let mut server = mockito::Server::new();
+    let _mocks = mock_chats(&mut server);
+    let sync = mock_sync(&server);
+
+    let report = Broadcast::new(&sync)
+        .checkpoint(&path, 1)
+        .run((1..=2).map(ChatUId::from), &template(), |_| {})
+        .unwrap();
+    let saved = serde_json::from_str::<Report>(&fs::read_to_string(&path).unwrap()).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(report, saved);
+}
+
+#[test]
+fn broadcast_validates_template_first() {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("POST", format!("/bot{}/sendPhoto", TOKEN).as_str())
+        .expect(0)
+        .create();
+    let sync = mock_sync(&server);
+    let template = Template::Photo(SendPhoto {
+        chat_id: ChatUId::from(0),
+        photo: FileInput::String(String::from("AgACAgIAAxkBAAIBZ2W")),
+        message_thread_id: None,
+        caption: Some("a".repeat(1025)),
+        parse_mode: None,
+        caption_entities: None,
+        has_spoiler: None,
+        disable_notification: None,
+        protect_content: None,
+        reply_parameters: None,
+        reply_markup: None,
+    });
+
+    let error = Broadcast::new(&sync)
+        .run((1..=5).map(ChatUId::from), &template, |_| {})
+        .unwrap_err();
+
+    assert!(matches!(error, Error::Validation { field, .. } if field == "caption"));
+    mock.assert();
+}
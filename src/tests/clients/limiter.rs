@@ -0,0 +1,193 @@
+use crate::api::enums::chat_uid::ChatUId;
+use crate::api::params::send_message::SendMessage;
+use crate::api::requests::sync::Requests;
+use crate::clients::limiter::{Clock, Limiter, Limits, VirtualClock};
+use crate::clients::r#async::Async;
+use crate::config::Config;
+use crate::tests::helpers::{mock_sync, TOKEN};
+use std::fs;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Bot id of the `TOKEN` used by the clients in these tests.
+const BOT: u64 = 0;
+
+/// Global slots are aligned to 1/30 of a second, so reservations may land slightly later.
+fn about(delay: Duration, secs: u64) -> bool {
+    delay >= Duration::from_secs(secs)
+        && delay < Duration::from_secs(secs) + Duration::from_millis(34)
+}
+
+fn limiter() -> (Arc<VirtualClock>, Limiter) {
+    let clock = Arc::new(VirtualClock::new());
+    let limiter = Limiter::with_clock(Limits::default(), clock.clone());
+
+    (clock, limiter)
+}
+
+#[test]
+fn private_chat_one_per_second() {
+    let (clock, limiter) = limiter();
+    let chat_id = ChatUId::from(42);
+
+    for second in 0..3 {
+        limiter.acquire(BOT, &chat_id);
+        assert_eq!(clock.now().as_secs(), second);
+    }
+}
+
+#[test]
+fn group_twenty_per_minute() {
+    let (clock, limiter) = limiter();
+    let chat_id = ChatUId::from(-100);
+
+    for _ in 0..20 {
+        limiter.acquire(BOT, &chat_id);
+    }
+    assert!(clock.now() < Duration::from_secs(1));
+
+    limiter.acquire(BOT, &chat_id);
+    assert!(clock.now() >= Duration::from_secs(60));
+}
+
+#[test]
+fn global_thirty_per_second() {
+    let (clock, limiter) = limiter();
+
+    for user_id in 1..=30 {
+        limiter.acquire(BOT, &ChatUId::from(user_id));
+    }
+    assert!(clock.now() < Duration::from_secs(1));
+
+    limiter.acquire(BOT, &ChatUId::from(31));
+    assert!(clock.now() >= Duration::from_secs(1));
+}
+
+#[test]
+fn busy_chat_does_not_block_others() {
+    let (_, limiter) = limiter();
+    let busy = ChatUId::from(42);
+
+    let delays: Vec<Duration> = (0..5).map(|_| limiter.reserve(BOT, &busy)).collect();
+    assert!(about(delays[4], 4));
+
+    assert!(limiter.reserve(BOT, &ChatUId::from(43)) < Duration::from_millis(100));
+    assert!(limiter.reserve(BOT, &ChatUId::from("@channel")) < Duration::from_millis(100));
+}
+
+#[test]
+fn flood_pauses_and_slows_down_chat() {
+    let (_, limiter) = limiter();
+    let chat_id = ChatUId::from(42);
+
+    limiter.penalize(BOT, &chat_id, 10);
+
+    assert!(about(limiter.reserve(BOT, &chat_id), 10));
+    assert!(about(limiter.reserve(BOT, &chat_id), 12));
+}
+
+#[test]
+fn slowdown_decays_after_quiet_period() {
+    let (clock, limiter) = limiter();
+    let chat_id = ChatUId::from(42);
+
+    limiter.penalize(BOT, &chat_id, 10);
+    limiter.acquire(BOT, &chat_id);
+    clock.advance(Duration::from_secs(60));
+
+    assert!(limiter.reserve(BOT, &chat_id) < Duration::from_millis(34));
+    assert!(about(limiter.reserve(BOT, &chat_id), 1));
+}
+
+#[test]
+fn flood_pauses_other_chats_of_the_bot() {
+    let (_, limiter) = limiter();
+
+    limiter.penalize(BOT, &ChatUId::from(42), 10);
+
+    assert!(about(limiter.reserve(BOT, &ChatUId::from(43)), 10));
+    assert!(about(limiter.reserve(BOT, &ChatUId::from(-100)), 10));
+}
+
+#[test]
+fn idle_chats_are_dropped() {
+    let (clock, limiter) = limiter();
+
+    for user_id in 1..=100 {
+        limiter.acquire(BOT, &ChatUId::from(user_id));
+    }
+    assert_eq!(limiter.queues(), 100);
+
+    clock.advance(Duration::from_secs(60));
+    limiter.acquire(BOT, &ChatUId::from(101));
+    assert_eq!(limiter.queues(), 1);
+}
+
+#[test]
+fn sync_waits_for_limiter() {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("POST", format!("/bot{}/sendMessage", TOKEN).as_str())
+        .with_body(fs::read_to_string("src/tests/responses/send_message_success.json").unwrap())
+        .expect(3)
+        .create();
+    let (clock, limiter) = limiter();
+    let mut sync = mock_sync(&server);
+    sync.set_limiter(Arc::new(limiter));
+
+    for _ in 0..3 {
+        let params = SendMessage {
+            chat_id: ChatUId::from(147951145),
+            text: "Hello World".to_string(),
+            ..Default::default()
+        };
+        sync.send_message(&params).unwrap();
+    }
+
+    assert_eq!(clock.now().as_secs(), 2);
+    mock.assert();
+}
+
+#[test]
+fn async_reserves_from_limiter() {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("POST", format!("/bot{}/sendMessage", TOKEN).as_str())
+        .with_body(fs::read_to_string("src/tests/responses/send_message_success.json").unwrap())
+        .create();
+    let config = Config {
+        url: server.url(),
+        token: TOKEN.into(),
+        ..Default::default()
+    };
+    let (_, limiter) = limiter();
+    let limiter = Arc::new(limiter);
+    let mut r#async = Async::new(&config).unwrap();
+    r#async.set_limiter(limiter.clone());
+    let params = SendMessage {
+        chat_id: ChatUId::from(147951145),
+        text: "Hello World".to_string(),
+        ..Default::default()
+    };
+
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    runtime.block_on(r#async.execute(&params)).unwrap();
+
+    assert!(about(limiter.reserve(BOT, &ChatUId::from(147951145)), 1));
+    mock.assert();
+}
+
+#[test]
+fn bots_have_their_own_budgets() {
+    let (_, limiter) = limiter();
+
+    for bot_id in 1..=2 {
+        for user_id in 1..=30 {
+            assert!(limiter.reserve(bot_id, &ChatUId::from(user_id)) < Duration::from_secs(1));
+        }
+    }
+    assert!(about(limiter.reserve(1, &ChatUId::from(31)), 1));
+
+    limiter.penalize(2, &ChatUId::from(42), 10);
+    assert!(limiter.reserve(1, &ChatUId::from(42)) < Duration::from_secs(2));
+}
@@ -0,0 +1,267 @@
+use crate::api::enums::file_input::FileInput;
+use crate::api::types::file::File;
+use crate::clients::limiter::{Clock, VirtualClock};
+use crate::clients::local::{FileLocation, Migration, LOCKOUT};
+use crate::clients::sync::Sync;
+use crate::clients::transport::memory::MemoryTransport;
+use crate::clients::transport::{Body, Part};
+use crate::config::{Config, Mode, API_URL, CLOUD_DOWNLOAD_LIMIT, CLOUD_UPLOAD_LIMIT};
+use crate::errors::Error;
+use crate::tests::helpers::{sync_for, TOKEN};
+use crate::Client;
+use mockito::{Mock, ServerGuard};
+use serde_json::Value;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::{Duration, UNIX_EPOCH};
+
+fn mock_method(server: &mut ServerGuard, method: &str, status: usize, response: &str) -> Mock {
+    server
+        .mock("POST", format!("/bot{}/{}", TOKEN, method).as_str())
+        .with_status(status)
+        .with_body(response)
+        .create()
+}
+
+fn file(size: i64) -> File {
+    File {
+        file_id: String::from("BQACAgIAAxkBAAIBQ2W6"),
+        file_unique_id: String::from("AgADWD8AAp0R0Ek"),
+        file_size: Some(size),
+        file_path: Some(String::from(
+            "/var/lib/telegram-bot-api/documents/file_3.pdf",
+        )),
+    }
+}
+
+#[test]
+fn mode_is_explicit() {
+    let config = Config {
+        url: String::from("https://telegram-proxy.internal"),
+        token: TOKEN.into(),
+        ..Default::default()
+    };
+    assert_eq!(Sync::new(&config).unwrap().mode(), Mode::Cloud);
+
+    let client = Client::builder(TOKEN)
+        .url("http://localhost:8081")
+        .mode(Mode::Local)
+        .build()
+        .unwrap();
+    assert_eq!(client.config.mode, Mode::Local);
+    assert_eq!(client.sync.mode(), Mode::Local);
+}
+
+#[test]
+fn local_file_as_uri() {
+    let sync = sync_for("http://localhost:8081", Mode::Local);
+
+    assert_eq!(
+        sync.local_file("/data/video.mp4").unwrap(),
+        FileInput::String(String::from("file:///data/video.mp4"))
+    );
+    assert_eq!(
+        sync.local_file("/data/my video #1.mp4").unwrap(),
+        FileInput::String(String::from("file:///data/my%20video%20%231.mp4"))
+    );
+    assert!(matches!(
+        sync.local_file("video.mp4"),
+        Err(Error::File { path, .. }) if path == "video.mp4"
+    ));
+    assert!(matches!(
+        sync_for(API_URL, Mode::Cloud).local_file("/data/video.mp4"),
+        Err(Error::File { .. })
+    ));
+}
+
+#[test]
+fn upload_limit_in_cloud_only() {
+    let size = CLOUD_UPLOAD_LIMIT + 1;
+
+    assert!(sync_for(API_URL, Mode::Cloud)
+        .check_upload("/data/video.mp4", size)
+        .is_err());
+    assert!(sync_for(API_URL, Mode::Cloud)
+        .check_upload("/data/video.mp4", CLOUD_UPLOAD_LIMIT)
+        .is_ok());
+    assert!(sync_for("http://localhost:8081", Mode::Local)
+        .check_upload("/data/video.mp4", size)
+        .is_ok());
+}
+
+#[test]
+fn multipart_upload_checks_limit() {
+    let transport = Arc::new(MemoryTransport::new());
+    let client = Client::builder(TOKEN)
+        .transport(transport.clone())
+        .build()
+        .unwrap();
+    let parts = vec![Part::File {
+        name: String::from("document"),
+        file_name: String::from("backup.tar"),
+        content: vec![0; CLOUD_UPLOAD_LIMIT as usize + 1],
+    }];
+
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+
+    let result = client
+        .sync
+        .call_multipart::<Value>("sendDocument", parts.clone());
+    assert!(matches!(result, Err(Error::File { path, .. }) if path == "backup.tar"));
+
+    let result = runtime.block_on(
+        client
+            .r#async
+            .call_multipart::<Value>("sendDocument", parts),
+    );
+    assert!(matches!(result, Err(Error::File { path, .. }) if path == "backup.tar"));
+    assert!(transport.requests().is_empty());
+}
+
+#[test]
+fn async_multipart_upload_to_local_server() {
+    let response = fs::read_to_string("src/tests/responses/send_message_success.json").unwrap();
+    let transport = Arc::new(MemoryTransport::new());
+    transport.reply("sendDocument", 200, &response);
+    let client = Client::builder(TOKEN)
+        .url("http://localhost:8081")
+        .mode(Mode::Local)
+        .transport(transport.clone())
+        .build()
+        .unwrap();
+    let parts = vec![Part::File {
+        name: String::from("document"),
+        file_name: String::from("backup.tar"),
+        content: vec![0; CLOUD_UPLOAD_LIMIT as usize + 1],
+    }];
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+
+    let result = runtime.block_on(
+        client
+            .r#async
+            .call_multipart::<Value>("sendDocument", parts.clone()),
+    );
+
+    assert_eq!(client.r#async.mode(), Mode::Local);
+    assert!(result.is_ok());
+    assert_eq!(transport.requests()[0].body, Body::Multipart(parts));
+}
+
+#[test]
+fn file_location_in_local_mode() {
+    let sync = sync_for("http://localhost:8081", Mode::Local);
+    let location = sync
+        .file_location(&file(2 * CLOUD_DOWNLOAD_LIMIT as i64))
+        .unwrap();
+
+    assert_eq!(
+        location,
+        FileLocation::Path(PathBuf::from(
+            "/var/lib/telegram-bot-api/documents/file_3.pdf"
+        ))
+    );
+}
+
+#[test]
+fn file_location_in_cloud_mode() {
+    let sync = sync_for(API_URL, Mode::Cloud);
+    let mut small = file(1024);
+    small.file_path = Some(String::from("documents/file_3.pdf"));
+
+    assert_eq!(
+        sync.file_location(&small).unwrap(),
+        FileLocation::Url(format!(
+            "https://api.telegram.org/file/bot{}/documents/file_3.pdf",
+            TOKEN
+        ))
+    );
+    assert!(matches!(
+        sync.file_location(&file(CLOUD_DOWNLOAD_LIMIT as i64 + 1)),
+        Err(Error::File { .. })
+    ));
+}
+
+#[test]
+fn get_file_location() {
+    let response = fs::read_to_string("src/tests/responses/get_file_success.json").unwrap();
+    let mut server = mockito::Server::new();
+    let mock = mock_method(&mut server, "getFile", 200, &response);
+
+    let location = sync_for(&server.url(), Mode::Local)
+        .get_file_location("BQACAgIAAxkBAAIBQ2W6")
+        .unwrap();
+
+    assert_eq!(
+        location,
+        FileLocation::Path(PathBuf::from("documents/file_3.pdf"))
+    );
+    mock.assert();
+}
+
+#[test]
+fn migrate_from_cloud_to_local() {
+    let log_out = fs::read_to_string("src/tests/responses/log_out_success.json").unwrap();
+    let get_me = fs::read_to_string("src/tests/responses/get_me_success.json").unwrap();
+    let mut cloud = mockito::Server::new();
+    let mut local = mockito::Server::new();
+    let log_out_mock = mock_method(&mut cloud, "logOut", 200, &log_out);
+    let get_me_mock = mock_method(&mut local, "getMe", 200, &get_me);
+
+    let source = sync_for(&cloud.url(), Mode::Cloud);
+    let target = sync_for(&local.url(), Mode::Local);
+
+    let clock = Arc::new(VirtualClock::new());
+    clock.advance(Duration::from_secs(100));
+    let migration = Migration::new(&source, &target).with_clock(clock);
+
+    let user = migration.run().unwrap();
+
+    assert_eq!(user.id, 6591790550);
+    assert_eq!(
+        migration.last_log_out(),
+        Some(UNIX_EPOCH + Duration::from_secs(100))
+    );
+    log_out_mock.assert();
+    get_me_mock.assert();
+}
+
+#[test]
+fn migrate_from_local_waits_out_close() {
+    let success = fs::read_to_string("src/tests/responses/close_success.json").unwrap();
+    let flood = fs::read_to_string("src/tests/responses/close_error_with_params.json").unwrap();
+    let get_me = fs::read_to_string("src/tests/responses/get_me_success.json").unwrap();
+    let mut source_server = mockito::Server::new();
+    let mut cloud = mockito::Server::new();
+    let delete_webhook = mock_method(&mut source_server, "deleteWebhook", 200, &success);
+    let close_flood = mock_method(&mut source_server, "close", 429, &flood).expect(1);
+    let close = mock_method(&mut source_server, "close", 200, &success);
+    let get_me_mock = mock_method(&mut cloud, "getMe", 200, &get_me);
+
+    let source = sync_for(&source_server.url(), Mode::Local);
+    let target = sync_for(&cloud.url(), Mode::Cloud);
+    let clock = Arc::new(VirtualClock::new());
+
+    Migration::new(&source, &target)
+        .with_clock(clock.clone())
+        .logged_out_at(UNIX_EPOCH)
+        .run()
+        .unwrap();
+
+    // 586 seconds for close, then the rest of the 10 minutes since logOut.
+    assert_eq!(clock.now(), LOCKOUT);
+    delete_webhook.assert();
+    close_flood.assert();
+    close.assert();
+    get_me_mock.assert();
+}
+
+#[test]
+fn migrate_from_cloud_to_cloud() {
+    let cloud = sync_for(API_URL, Mode::Cloud);
+
+    assert!(matches!(
+        Migration::new(&cloud, &cloud).run(),
+        Err(Error::Config { field, .. }) if field == "url"
+    ));
+}
@@ -0,0 +1,225 @@
+use crate::api::enums::chat_uid::ChatUId;
+use crate::api::enums::file_input::FileInput;
+use crate::api::enums::parse_mode::ParseMode;
+use crate::api::params::send_message::SendMessage;
+use crate::api::params::send_photo::SendPhoto;
+use crate::api::types::message_entity::MessageEntity;
+use crate::clients::transport::memory::MemoryTransport;
+use crate::clients::transport::Body;
+use crate::errors::Error;
+use crate::tests::helpers::memory_client;
+use crate::Client;
+use serde_json::json;
+use std::fs;
+use std::sync::Arc;
+
+fn send_message_client() -> (Client, Arc<MemoryTransport>) {
+    let response = fs::read_to_string("src/tests/responses/send_message_success.json").unwrap();
+    let transport = Arc::new(MemoryTransport::new());
+    transport.reply("sendMessage", 200, &response);
+
+    (memory_client(transport.clone()), transport)
+}
+
+fn bodies(transport: &MemoryTransport) -> Vec<serde_json::Value> {
+    transport
+        .requests()
+        .into_iter()
+        .map(|request| match request.body {
+            Body::Json(body) => body,
+            body => panic!("unexpected body {:?}", body),
+        })
+        .collect()
+}
+
+fn paragraph(letter: &str) -> String {
+    letter.repeat(3000)
+}
+
+#[test]
+fn short_message_sent_once() {
+    let (client, transport) = send_message_client();
+    let params = SendMessage {
+        text: String::from("hello"),
+        ..Default::default()
+    };
+
+    let messages = client.sync.send_long_message(&params, true).unwrap();
+
+    assert_eq!(messages.len(), 1);
+    assert_eq!(bodies(&transport)[0]["text"], "hello");
+}
+
+#[test]
+fn long_message_with_entities() {
+    let (client, transport) = send_message_client();
+    let text = format!("{}\n\n{}", paragraph("a"), paragraph("b"));
+    let entities = serde_json::from_value::<Vec<MessageEntity>>(json!([
+        {"type": "bold", "offset": 0, "length": 10},
+        {"type": "italic", "offset": 3002, "length": 5}
+    ]))
+    .unwrap();
+    let params = SendMessage {
+        text,
+        entities: Some(entities),
+        reply_markup: Some(
+            serde_json::from_value(
+                json!({"inline_keyboard": [[{"text": "Ok", "callback_data": "ok"}]]}),
+            )
+            .unwrap(),
+        ),
+        ..Default::default()
+    };
+
+    let messages = client.sync.send_long_message(&params, true).unwrap();
+    let bodies = bodies(&transport);
+
+    assert_eq!(messages.len(), 2);
+    assert_eq!(bodies[0]["text"], paragraph("a"));
+    assert_eq!(
+        bodies[0]["entities"],
+        json!([{"type": "bold", "offset": 0, "length": 10}])
+    );
+    assert!(bodies[0].get("reply_markup").is_none());
+    assert!(bodies[0].get("reply_parameters").is_none());
+
+    assert_eq!(bodies[1]["text"], paragraph("b"));
+    assert_eq!(
+        bodies[1]["entities"],
+        json!([{"type": "italic", "offset": 0, "length": 5}])
+    );
+    assert!(bodies[1].get("reply_markup").is_some());
+    assert_eq!(
+        bodies[1]["reply_parameters"],
+        json!({"message_id": 494, "allow_sending_without_reply": true})
+    );
+}
+
+#[test]
+fn long_html_message() {
+    let (client, transport) = send_message_client();
+    let text = format!("<b>{}</b>\n\n<i>{}</i>", paragraph("a"), paragraph("b"));
+    let params = SendMessage {
+        text,
+        parse_mode: Some(ParseMode::Html),
+        ..Default::default()
+    };
+
+    let messages = client.sync.send_long_message(&params, false).unwrap();
+    let bodies = bodies(&transport);
+
+    assert_eq!(messages.len(), 2);
+    assert_eq!(bodies[0]["text"], format!("<b>{}</b>", paragraph("a")));
+    assert_eq!(bodies[1]["text"], format!("<i>{}</i>", paragraph("b")));
+    assert_eq!(bodies[1]["parse_mode"], "HTML");
+    assert!(bodies[1].get("reply_parameters").is_none());
+}
+
+#[test]
+fn long_markdown_message() {
+    let (client, transport) = send_message_client();
+    let text = format!("*{}*\n\n_{}_ \\.", paragraph("a"), paragraph("b"));
+    let params = SendMessage {
+        text,
+        parse_mode: Some(ParseMode::MarkdownV2),
+        ..Default::default()
+    };
+
+    let messages = client.sync.send_long_message(&params, false).unwrap();
+    let bodies = bodies(&transport);
+
+    assert_eq!(messages.len(), 2);
+    assert_eq!(bodies[0]["text"], paragraph("a"));
+    assert_eq!(
+        bodies[0]["entities"],
+        json!([{"type": "bold", "offset": 0, "length": 3000}])
+    );
+    assert!(bodies[0].get("parse_mode").is_none());
+    assert_eq!(bodies[1]["text"], format!("{} .", paragraph("b")));
+    assert_eq!(
+        bodies[1]["entities"],
+        json!([{"type": "italic", "offset": 0, "length": 3000}])
+    );
+}
+
+#[test]
+fn invalid_markdown_not_sent() {
+    let (client, transport) = send_message_client();
+    let params = SendMessage {
+        text: format!("{} 1.5", paragraph("a")),
+        parse_mode: Some(ParseMode::MarkdownV2),
+        ..Default::default()
+    };
+
+    let error = client.sync.send_long_message(&params, false).unwrap_err();
+
+    assert!(matches!(error, Error::Validation { .. }));
+    assert!(transport.requests().is_empty());
+}
+
+#[test]
+fn partially_sent_message() {
+    let (client, transport) = send_message_client();
+    transport.reply(
+        "sendMessage",
+        400,
+        r#"{"ok":false,"error_code":400,"description":"Bad Request: chat not found"}"#,
+    );
+    let params = SendMessage {
+        text: format!("{}\n\n{}", paragraph("a"), paragraph("b")),
+        ..Default::default()
+    };
+
+    let error = client.sync.send_long_message(&params, false).unwrap_err();
+
+    match error {
+        Error::PartiallySent { sent, error } => {
+            assert_eq!(sent.len(), 1);
+            assert!(matches!(*error, Error::ChatNotFound(_)));
+        }
+        error => panic!("unexpected error {:?}", error),
+    }
+    assert_eq!(transport.requests().len(), 2);
+}
+
+#[test]
+fn long_caption_followed_by_text() {
+    let (client, transport) = send_message_client();
+    let response = fs::read_to_string("src/tests/responses/send_message_success.json").unwrap();
+    transport.reply("sendPhoto", 200, &response);
+    let photo = SendPhoto {
+        chat_id: ChatUId::from(147951145),
+        photo: FileInput::String(String::from("AgACAgIAAxkBAAIBZ2W")),
+        message_thread_id: None,
+        caption: Some(format!("{}\n\n{}", "a".repeat(900), "b".repeat(900))),
+        parse_mode: None,
+        caption_entities: None,
+        has_spoiler: None,
+        disable_notification: Some(true),
+        protect_content: None,
+        reply_parameters: None,
+        reply_markup: Some(
+            serde_json::from_value(
+                json!({"inline_keyboard": [[{"text": "Ok", "callback_data": "ok"}]]}),
+            )
+            .unwrap(),
+        ),
+    };
+
+    let messages = client.sync.send_with_long_caption(photo, true).unwrap();
+    let bodies = bodies(&transport);
+
+    assert_eq!(messages.len(), 2);
+    assert_eq!(transport.requests()[0].method, "sendPhoto");
+    assert_eq!(bodies[0]["caption"], "a".repeat(900));
+    assert!(bodies[0].get("reply_markup").is_some());
+    assert_eq!(transport.requests()[1].method, "sendMessage");
+    assert_eq!(bodies[1]["text"], "b".repeat(900));
+    assert_eq!(bodies[1]["chat_id"], 147951145);
+    assert_eq!(bodies[1]["disable_notification"], true);
+    assert_eq!(
+        bodies[1]["reply_parameters"]["message_id"],
+        messages[0].message_id.message_id
+    );
+    assert!(bodies[1].get("reply_markup").is_none());
+}
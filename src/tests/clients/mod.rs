@@ -1 +1,8 @@
+mod broadcast;
+mod limiter;
+mod local;
+mod long_message;
+mod retry;
 mod sync;
+mod transport;
+mod updates;
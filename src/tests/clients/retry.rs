@@ -0,0 +1,251 @@
+use crate::api::enums::chat_uid::ChatUId;
+use crate::api::method::Method;
+use crate::api::params::delete_message::DeleteMessage;
+use crate::api::params::export_chat_invite_link::ExportChatInviteLink;
+use crate::api::params::get_me::GetMe;
+use crate::api::params::send_message::SendMessage;
+use crate::api::params::set_message_reaction::SetMessageReaction;
+use crate::api::requests::sync::Requests;
+use crate::api::responses::error::ResponseError;
+use crate::clients::policy::{Attempt, Policy};
+use crate::clients::r#async::Async;
+use crate::clients::retry::{Decision, Retry};
+use crate::clients::transport::Body;
+use crate::config::Config;
+use crate::errors::Error;
+use crate::tests::helpers::{mock_sync, TOKEN};
+use mockito::Matcher;
+use serde_json::json;
+use std::fs;
+use std::time::Duration;
+
+fn server_error() -> Error {
+    Error::from(ResponseError::new(502, "Bad Gateway"))
+}
+
+#[test]
+fn default_never_retries() {
+    let retry = Retry::default();
+    let flood = Error::from(ResponseError::new(
+        429,
+        r#"{"ok":false,"error_code":429,"description":"Too Many Requests: retry after 5","parameters":{"retry_after":5}}"#,
+    ));
+
+    assert_eq!(retry.decide("getMe", true, 0, &flood), Decision::Fail);
+    assert_eq!(
+        retry.decide("getMe", true, 0, &server_error()),
+        Decision::Fail
+    );
+}
+
+#[test]
+fn flood_sleeps_exactly_retry_after() {
+    let retry = Retry::new();
+    let flood = Error::from(ResponseError::new(
+        429,
+        r#"{"ok":false,"error_code":429,"description":"Too Many Requests: retry after 5","parameters":{"retry_after":5}}"#,
+    ));
+
+    assert_eq!(
+        retry.decide("sendMessage", false, 0, &flood),
+        Decision::Retry(Duration::from_secs(5))
+    );
+    assert_eq!(
+        retry.decide("sendMessage", false, 3, &flood),
+        Decision::Fail
+    );
+}
+
+#[test]
+fn non_idempotent_methods_need_opt_in() {
+    let retry = Retry::new();
+
+    assert!(matches!(
+        retry.decide("getMe", true, 0, &server_error()),
+        Decision::Retry(_)
+    ));
+    assert_eq!(
+        retry.decide("sendMessage", false, 0, &server_error()),
+        Decision::Fail
+    );
+
+    let retry = Retry::new().budget("sendMessage", 1);
+
+    assert!(matches!(
+        retry.decide("sendMessage", false, 0, &server_error()),
+        Decision::Retry(_)
+    ));
+    assert_eq!(
+        retry.decide("sendMessage", false, 1, &server_error()),
+        Decision::Fail
+    );
+}
+
+#[test]
+fn flood_waits_keep_backoff_budget() {
+    let mut retry = Retry::new();
+    retry.attempts = 1;
+    retry.flood_attempts = 1;
+    let policy = Policy {
+        retry,
+        ..Default::default()
+    };
+    let mut attempt = Attempt::new("getMe", true);
+    let mut body = Body::Json(json!({}));
+    let flood = || {
+        Error::from(ResponseError::new(
+            429,
+            r#"{"ok":false,"error_code":429,"description":"Too Many Requests: retry after 0","parameters":{"retry_after":0}}"#,
+        ))
+    };
+
+    assert!(policy.recover(&mut attempt, &mut body, flood()).is_ok());
+    assert!(policy
+        .recover(&mut attempt, &mut body, server_error())
+        .is_ok());
+    assert!(policy.recover(&mut attempt, &mut body, flood()).is_err());
+    assert!(policy
+        .recover(&mut attempt, &mut body, server_error())
+        .is_err());
+}
+
+#[test]
+fn backoff_grows_and_is_capped() {
+    let mut retry = Retry::new();
+    retry.base_delay = Duration::from_millis(100);
+    retry.max_delay = Duration::from_millis(1000);
+
+    for _ in 0..20 {
+        let first = retry.backoff(0);
+        let third = retry.backoff(2);
+        let tenth = retry.backoff(10);
+
+        assert!(first >= Duration::from_millis(50) && first <= Duration::from_millis(100));
+        assert!(third >= Duration::from_millis(200) && third <= Duration::from_millis(400));
+        assert!(tenth >= Duration::from_millis(500) && tenth <= Duration::from_millis(1000));
+    }
+}
+
+#[test]
+fn retries_flood_until_budget_is_spent() {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("POST", format!("/bot{}/getMe", TOKEN).as_str())
+        .with_status(429)
+        .with_body(r#"{"ok":false,"error_code":429,"description":"Too Many Requests: retry after 0","parameters":{"retry_after":0}}"#)
+        .expect(4)
+        .create();
+    let mut sync = mock_sync(&server);
+    sync.set_retry(Retry::new());
+
+    let error = sync.get_me().unwrap_err();
+
+    assert!(matches!(
+        error,
+        Error::TooManyRequests { retry_after: 0, .. }
+    ));
+    mock.assert();
+}
+
+#[test]
+fn resends_to_migrated_chat() {
+    let mut server = mockito::Server::new();
+    let migrated = server
+        .mock("POST", format!("/bot{}/sendMessage", TOKEN).as_str())
+        .match_body(Matcher::PartialJson(json!({"chat_id": -4000})))
+        .with_status(400)
+        .with_body(r#"{"ok":false,"error_code":400,"description":"Bad Request: group chat was upgraded to a supergroup chat","parameters":{"migrate_to_chat_id":-1004000}}"#)
+        .create();
+    let sent = server
+        .mock("POST", format!("/bot{}/sendMessage", TOKEN).as_str())
+        .match_body(Matcher::PartialJson(json!({"chat_id": -1004000})))
+        .with_body(fs::read_to_string("src/tests/responses/send_message_success.json").unwrap())
+        .create();
+    let mut sync = mock_sync(&server);
+    sync.set_retry(Retry::new());
+    let params = SendMessage {
+        chat_id: ChatUId::from(-4000),
+        text: "Hello World".to_string(),
+        ..Default::default()
+    };
+
+    sync.send_message(&params).unwrap();
+
+    migrated.assert();
+    sent.assert();
+}
+
+fn decide<M: Method>(retry: &Retry) -> Decision {
+    retry.decide(M::NAME, M::IDEMPOTENT, 0, &server_error())
+}
+
+#[test]
+fn idempotency_comes_from_the_method() {
+    let retry = Retry::new();
+
+    assert!(matches!(decide::<GetMe>(&retry), Decision::Retry(_)));
+    assert_eq!(decide::<DeleteMessage>(&retry), Decision::Fail);
+    assert_eq!(decide::<SendMessage>(&retry), Decision::Fail);
+    assert_eq!(decide::<SetMessageReaction>(&retry), Decision::Fail);
+    assert_eq!(decide::<ExportChatInviteLink>(&retry), Decision::Fail);
+}
+
+#[test]
+fn migration_keeps_flood_budget() {
+    let mut server = mockito::Server::new();
+    let migrated = server
+        .mock("POST", format!("/bot{}/sendMessage", TOKEN).as_str())
+        .match_body(Matcher::PartialJson(json!({"chat_id": -4000})))
+        .with_status(400)
+        .with_body(r#"{"ok":false,"error_code":400,"description":"Bad Request: group chat was upgraded to a supergroup chat","parameters":{"migrate_to_chat_id":-1004000}}"#)
+        .create();
+    let flood = server
+        .mock("POST", format!("/bot{}/sendMessage", TOKEN).as_str())
+        .match_body(Matcher::PartialJson(json!({"chat_id": -1004000})))
+        .with_status(429)
+        .with_body(r#"{"ok":false,"error_code":429,"description":"Too Many Requests: retry after 0","parameters":{"retry_after":0}}"#)
+        .expect(2)
+        .create();
+    let mut retry = Retry::new();
+    retry.flood_attempts = 1;
+    let mut sync = mock_sync(&server);
+    sync.set_retry(retry);
+    let params = SendMessage {
+        chat_id: ChatUId::from(-4000),
+        text: "Hello World".to_string(),
+        ..Default::default()
+    };
+
+    let error = sync.send_message(&params).unwrap_err();
+
+    assert!(matches!(error, Error::TooManyRequests { .. }));
+    migrated.assert();
+    flood.assert();
+}
+
+#[test]
+fn async_retries_flood() {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("POST", format!("/bot{}/getMe", TOKEN).as_str())
+        .with_status(429)
+        .with_body(r#"{"ok":false,"error_code":429,"description":"Too Many Requests: retry after 0","parameters":{"retry_after":0}}"#)
+        .expect(4)
+        .create();
+    let config = Config {
+        url: server.url(),
+        token: TOKEN.into(),
+        ..Default::default()
+    };
+    let mut r#async = Async::new(&config).unwrap();
+    r#async.set_retry(Retry::new());
+
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let error = runtime.block_on(r#async.execute(&GetMe {})).unwrap_err();
+
+    assert!(matches!(
+        error,
+        Error::TooManyRequests { retry_after: 0, .. }
+    ));
+    mock.assert();
+}
@@ -4,11 +4,13 @@ use crate::api::params::copy_messages::CopyMessages;
 use crate::api::params::delete_webhook::DeleteWebhook;
 use crate::api::params::forward_message::ForwardMessage;
 use crate::api::params::forward_messages::ForwardMessages;
+use crate::api::params::get_file::GetFile;
 use crate::api::params::get_update::GetUpdate;
 use crate::api::params::send_message::SendMessage;
 use crate::api::params::set_webhook::SetWebhook;
 use crate::api::requests::sync::Requests;
 use crate::api::responses::error::ResponseError;
+use crate::api::types::file::File;
 use crate::api::types::message::Message;
 use crate::api::types::message_id::MessageId;
 use crate::api::types::update::Update;
@@ -282,7 +284,7 @@ fn forward_message_success() {
     let mut server = mockito::Server::new();
     let mocked = Mocked::new(&mut server, "forwardMessage", &mock_response);
 
-    let mock_result = mocked.result::<MessageId>().unwrap();
+    let mock_result = mocked.result::<Message>().unwrap();
     let params = ForwardMessage {
         message_id: MessageId::from(456),
         chat_id: ChatUId::from(147951145),
@@ -436,3 +438,38 @@ fn copy_messages_error() {
         mocked.server.assert();
     }
 }
+
+#[test]
+fn get_file_success() {
+    let mock_response = fs::read_to_string("src/tests/responses/get_file_success.json").unwrap();
+    let mut server = mockito::Server::new();
+    let mocked = Mocked::new(&mut server, "getFile", &mock_response);
+
+    let mock_result = mocked.result::<File>().unwrap();
+    let params = GetFile {
+        file_id: String::from(
+            "BQACAgIAAxkBAAIBQ2W6Yr6Y2mUOoXx0JWsY7VnQmZ3rAAJYPwACnRHQSUmJxP1VQmxmNAQ",
+        ),
+    };
+    let real_result = mocked.client.sync.get_file(&params).unwrap();
+
+    assert_eq!(mock_result, real_result);
+    mocked.server.assert();
+}
+
+#[test]
+fn get_file_error() {
+    let mock_response = fs::read_to_string("src/tests/responses/get_file_error.json").unwrap();
+    let mut server = mockito::Server::new();
+    let mocked = Mocked::with_status(&mut server, "getFile", 400, &mock_response);
+
+    let params = GetFile {
+        file_id: String::from("invalid"),
+    };
+    let error = mocked.client.sync.get_file(&params).unwrap_err();
+
+    assert!(matches!(error, Error::Response(_)));
+    assert_eq!(error.error_code(), Some(400));
+    assert_eq!(error.description(), Some("Bad Request: invalid file_id"));
+    mocked.server.assert();
+}
@@ -0,0 +1,275 @@
+use crate::api::enums::chat_uid::ChatUId;
+use crate::api::params::get_me::GetMe;
+use crate::api::params::send_message::SendMessage;
+use crate::api::requests::sync::Requests;
+use crate::api::types::message::Message;
+use crate::api::types::user::User;
+use crate::clients::transport::memory::{MemoryTransport, Request};
+use crate::clients::transport::{Body, Part};
+use crate::config::Config;
+use crate::errors::Error;
+use crate::tests::helpers::{memory_client, TOKEN};
+use crate::Client;
+use mockito::Matcher;
+use serde_json::json;
+use std::fs;
+use std::sync::Arc;
+
+fn parts() -> Vec<Part> {
+    vec![
+        Part::Text {
+            name: String::from("chat_id"),
+            value: String::from("-1001"),
+        },
+        Part::File {
+            name: String::from("document"),
+            file_name: String::from("report.txt"),
+            content: b"weekly report".to_vec(),
+        },
+    ]
+}
+
+#[test]
+fn memory_transport_replies() {
+    let response = fs::read_to_string("src/tests/responses/get_me_success.json").unwrap();
+    let transport = Arc::new(MemoryTransport::new());
+    transport.reply("getMe", 200, &response);
+    let client = memory_client(transport.clone());
+
+    let user = client.sync.get_me().unwrap();
+
+    assert!(matches!(user, User { is_bot: true, .. }));
+    assert_eq!(transport.requests().len(), 1);
+    assert_eq!(transport.requests()[0].method, "getMe");
+    assert_eq!(transport.requests()[0].body, Body::Json(json!({})));
+}
+
+#[test]
+fn memory_transport_errors() {
+    let transport = Arc::new(MemoryTransport::new());
+    transport.reply(
+        "getMe",
+        401,
+        r#"{"ok":false,"error_code":401,"description":"Unauthorized"}"#,
+    );
+    let client = memory_client(transport.clone());
+
+    assert!(matches!(client.sync.get_me(), Err(Error::Unauthorized(_))));
+    assert!(matches!(
+        client.sync.get_webhook_info(),
+        Err(Error::Transport(_))
+    ));
+}
+
+#[test]
+fn memory_transport_migration() {
+    let response = fs::read_to_string("src/tests/responses/send_message_success.json").unwrap();
+    let transport = Arc::new(MemoryTransport::new());
+    transport
+        .reply(
+            "sendMessage",
+            400,
+            r#"{"ok":false,"error_code":400,"description":"Bad Request: group chat was upgraded to a supergroup chat","parameters":{"migrate_to_chat_id":-1001}}"#,
+        )
+        .reply("sendMessage", 200, &response);
+    let client = memory_client(transport.clone());
+    let params = SendMessage {
+        chat_id: ChatUId::from(-1),
+        text: String::from("Hello"),
+        ..Default::default()
+    };
+
+    let message = client.sync.send_message(&params).unwrap();
+    let requests = transport.requests();
+
+    assert_eq!(message, message_of(&response));
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[0].body.chat_id(), Some(json!(-1)));
+    assert_eq!(requests[1].body.chat_id(), Some(json!(-1001)));
+}
+
+fn message_of(response: &str) -> Message {
+    let value: serde_json::Value = serde_json::from_str(response).unwrap();
+    serde_json::from_value(value["result"].clone()).unwrap()
+}
+
+#[test]
+fn multipart_body() {
+    let mut body = Body::Multipart(parts());
+    body.set_chat_id(-1002);
+
+    assert_eq!(body.chat_id(), Some(json!(-1002)));
+    if let Body::Multipart(parts) = body {
+        let encoded = Body::encode_multipart(&parts, "boundary");
+
+        assert_eq!(
+            String::from_utf8(encoded).unwrap(),
+            "--boundary\r\n\
+             Content-Disposition: form-data; name=\"chat_id\"\r\n\r\n\
+             -1002\r\n\
+             --boundary\r\n\
+             Content-Disposition: form-data; name=\"document\"; filename=\"report.txt\"\r\n\
+             Content-Type: application/octet-stream\r\n\r\n\
+             weekly report\r\n\
+             --boundary--\r\n"
+        );
+    }
+}
+
+#[test]
+fn reqwest_transport_multipart() {
+    let response = fs::read_to_string("src/tests/responses/send_message_success.json").unwrap();
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("POST", format!("/bot{}/sendDocument", TOKEN).as_str())
+        .match_header(
+            "content-type",
+            Matcher::Regex(String::from("^multipart/form-data; boundary=")),
+        )
+        .match_body(Matcher::Regex(String::from(
+            "filename=\"report.txt\"[\\s\\S]*weekly report",
+        )))
+        .with_body(&response)
+        .create();
+    let config = Config {
+        url: server.url(),
+        token: TOKEN.into(),
+        ..Default::default()
+    };
+    let client = Client::from_config(config).unwrap();
+
+    let message = client
+        .sync
+        .execute_body::<Message>("sendDocument", false, None, Body::Multipart(parts()))
+        .unwrap();
+
+    assert_eq!(message, message_of(&response));
+    mock.assert();
+}
+
+#[test]
+fn async_reqwest_transport_multipart() {
+    let response = fs::read_to_string("src/tests/responses/send_message_success.json").unwrap();
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("POST", format!("/bot{}/sendDocument", TOKEN).as_str())
+        .match_header(
+            "content-type",
+            Matcher::Regex(String::from("^multipart/form-data; boundary=")),
+        )
+        .match_body(Matcher::Regex(String::from(
+            "filename=\"report.txt\"[\\s\\S]*weekly report",
+        )))
+        .with_body(&response)
+        .create();
+    let config = Config {
+        url: server.url(),
+        token: TOKEN.into(),
+        ..Default::default()
+    };
+    let client = Client::from_config(config).unwrap();
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+
+    let message = runtime
+        .block_on(client.r#async.execute_body::<Message>(
+            "sendDocument",
+            false,
+            None,
+            Body::Multipart(parts()),
+        ))
+        .unwrap();
+
+    assert_eq!(message, message_of(&response));
+    mock.assert();
+}
+
+#[test]
+fn async_client_uses_transport() {
+    let response = fs::read_to_string("src/tests/responses/get_me_success.json").unwrap();
+    let transport = Arc::new(MemoryTransport::new());
+    transport.reply("getMe", 200, &response);
+    let client = memory_client(transport.clone());
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+
+    let user = runtime.block_on(client.r#async.execute(&GetMe {})).unwrap();
+
+    assert_eq!(user.username.as_deref(), Some("development_bot"));
+    assert_eq!(
+        transport.requests(),
+        vec![Request {
+            method: String::from("getMe"),
+            body: Body::Json(json!({})),
+        }]
+    );
+}
+
+#[test]
+fn transports_redact_token() {
+    let client = Client::new(TOKEN).unwrap();
+
+    assert!(!format!("{:?}", client.sync).contains(TOKEN));
+    assert!(format!("{:?}", MemoryTransport::new()).contains("MemoryTransport"));
+}
+
+#[cfg(feature = "ureq")]
+#[test]
+fn ureq_transport() {
+    use crate::clients::traits::Transport;
+    use crate::clients::transport::ureq::UreqTransport;
+
+    let response = fs::read_to_string("src/tests/responses/get_me_success.json").unwrap();
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("POST", format!("/bot{}/getMe", TOKEN).as_str())
+        .match_header("content-type", "application/json")
+        .with_body(&response)
+        .expect(2)
+        .create();
+    let error = server
+        .mock("POST", format!("/bot{}/sendDocument", TOKEN).as_str())
+        .match_body(Matcher::Regex(String::from("weekly report")))
+        .with_status(400)
+        .with_body(r#"{"ok":false,"error_code":400,"description":"Bad Request: chat not found"}"#)
+        .create();
+    let config = Config {
+        url: server.url(),
+        token: TOKEN.into(),
+        ..Default::default()
+    };
+    let transport = Arc::new(UreqTransport::new(&config).unwrap());
+    let client = Client::from_config(config).unwrap();
+    let sync = crate::clients::sync::Sync::with_transport(&client.config, transport.clone());
+
+    assert!(sync.get_me().is_ok());
+    assert!(matches!(
+        sync.execute_body::<Message>("sendDocument", false, None, Body::Multipart(parts())),
+        Err(Error::ChatNotFound(_))
+    ));
+    assert!(!format!("{:?}", transport).contains(TOKEN));
+    assert!(transport
+        .send("getMe", &Body::Json(json!({})))
+        .unwrap()
+        .text()
+        .contains("development_bot"));
+    mock.assert();
+    error.assert();
+}
+
+#[cfg(feature = "ureq")]
+#[test]
+fn ureq_rejects_unsupported_settings() {
+    use crate::clients::transport::ureq::UreqTransport;
+
+    let config = Config {
+        url: String::from("http://localhost:8081"),
+        token: TOKEN.into(),
+        proxy: Some(String::from("http://127.0.0.1:3128")),
+        no_proxy: Some(String::from("localhost")),
+        ..Default::default()
+    };
+
+    assert!(matches!(
+        UreqTransport::new(&config),
+        Err(Error::Config { field, .. }) if field == "no_proxy"
+    ));
+}
@@ -0,0 +1,137 @@
+use crate::api::params::get_update::GetUpdate;
+use crate::api::requests::sync::Requests;
+use crate::api::types::update::Update;
+use crate::clients::transport::memory::MemoryTransport;
+use crate::clients::updates::UpdateBatch;
+use crate::tests::helpers::memory_client;
+use serde_json::json;
+use std::sync::Arc;
+
+fn message(update_id: i64, text: &str) -> serde_json::Value {
+    json!({
+        "update_id": update_id,
+        "message": {
+            "message_id": update_id,
+            "chat": {"id": 147951145, "type": "private"},
+            "date": 1707331948,
+            "text": text
+        }
+    })
+}
+
+fn updates_reply(updates: Vec<serde_json::Value>) -> String {
+    json!({"ok": true, "result": updates}).to_string()
+}
+
+#[test]
+fn unknown_fields_kept() {
+    let update = json!({
+        "update_id": 10,
+        "business_message": {"message_id": 1},
+    });
+
+    let decoded = serde_json::from_value::<Update>(update.clone()).unwrap();
+
+    assert_eq!(decoded.extra["business_message"], json!({"message_id": 1}));
+    assert_eq!(serde_json::to_value(&decoded).unwrap(), update);
+}
+
+#[test]
+fn unknown_message_fields_kept() {
+    let mut update = message(10, "hello");
+    update["message"]["paid_star_count"] = json!(5);
+
+    let decoded = serde_json::from_value::<Update>(update.clone()).unwrap();
+    let message = decoded.message.as_ref().unwrap();
+
+    assert_eq!(message.text.as_deref(), Some("hello"));
+    assert_eq!(message.extra["paid_star_count"], json!(5));
+    assert!(decoded.extra.is_empty());
+    assert_eq!(serde_json::to_value(&decoded).unwrap(), update);
+}
+
+#[test]
+fn unknown_nested_fields_kept() {
+    let mut update = message(10, "hello");
+    update["message"]["chat"]["max_reaction_count"] = json!(3);
+    update["message"]["from"] = json!({
+        "id": 147951145,
+        "is_bot": false,
+        "first_name": "Alice",
+        "has_main_web_app": true
+    });
+    update["message"]["entities"] = json!([
+        {"type": "bold", "offset": 0, "length": 5, "expandable": true}
+    ]);
+
+    let decoded = serde_json::from_value::<Update>(update.clone()).unwrap();
+    let message = decoded.message.as_ref().unwrap();
+
+    assert_eq!(message.chat.extra["max_reaction_count"], json!(3));
+    assert_eq!(
+        message.from.as_ref().unwrap().extra["has_main_web_app"],
+        json!(true)
+    );
+    assert_eq!(
+        message.entities.as_ref().unwrap()[0].extra["expandable"],
+        json!(true)
+    );
+    assert!(message.extra.is_empty());
+    assert_eq!(serde_json::to_value(&decoded).unwrap(), update);
+}
+
+#[test]
+fn batch_skips_undecodable() {
+    let broken = json!({"update_id": 11, "message": {"message_id": "not a number"}});
+    let batch = UpdateBatch::decode(vec![
+        message(10, "first"),
+        broken.clone(),
+        message(12, "last"),
+    ]);
+
+    assert_eq!(batch.updates.len(), 2);
+    assert_eq!(batch.updates[1].update_id, 12);
+    assert_eq!(batch.skipped.len(), 1);
+    assert_eq!(batch.skipped[0].update_id, Some(11));
+    assert_eq!(batch.skipped[0].raw, broken);
+    assert_eq!(batch.next_offset, Some(13));
+}
+
+#[test]
+fn batch_offset_past_skipped() {
+    let batch = UpdateBatch::decode(vec![message(10, "first"), json!({"update_id": 11})]);
+    assert_eq!(batch.next_offset, Some(12));
+
+    let batch = UpdateBatch::decode(vec![json!({"update_id": "broken"})]);
+    assert_eq!(batch.next_offset, None);
+    assert_eq!(batch.skipped[0].update_id, None);
+
+    assert_eq!(UpdateBatch::decode(vec![]), UpdateBatch::default());
+}
+
+#[test]
+fn get_updates_batch() {
+    let transport = Arc::new(MemoryTransport::new());
+    transport.reply(
+        "getUpdates",
+        200,
+        &updates_reply(vec![
+            message(10, "first"),
+            json!({"update_id": 11, "message": {"message_id": 11}}),
+        ]),
+    );
+    let client = memory_client(transport.clone());
+    let params = GetUpdate {
+        offset: 10,
+        ..Default::default()
+    };
+
+    assert!(client.sync.get_updates(&params).is_err());
+
+    let batch = client.sync.get_updates_batch(&params).unwrap();
+
+    assert_eq!(batch.updates.len(), 1);
+    assert_eq!(batch.skipped.len(), 1);
+    assert_eq!(batch.next_offset, Some(12));
+    assert_eq!(transport.requests()[1].method, "getUpdates");
+}
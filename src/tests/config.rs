@@ -1,4 +1,8 @@
-use crate::config::Config;
+use crate::config::{Config, Mode, API_URL};
+use crate::errors::Error;
+use crate::tests::helpers::TOKEN;
+use std::fs;
+use std::path::PathBuf;
 
 #[test]
 fn create_with_default() {
@@ -7,7 +11,7 @@ fn create_with_default() {
     assert!(!config.debug);
     assert!(!config.production);
 
-    assert_eq!(config.token, String::from(""));
+    assert_eq!(config.token.expose(), "");
     assert_eq!(config.url, String::from(""));
     assert_eq!(config.timeout, 5u64);
     assert_eq!(config.connect_timeout, 5u64);
@@ -21,10 +25,204 @@ fn build_url() {
     let token = String::from("token");
     let url = String::from("http://localhost");
     let config = Config {
-        token: token.clone(),
+        token: token.clone().into(),
         url: url.clone(),
         ..Default::default()
     };
 
     assert_eq!(config.build_url(), format!("{}/bot{}/", url, token));
 }
+
+fn temp_file(name: &str, content: &str) -> PathBuf {
+    let path =
+        std::env::temp_dir().join(format!("telegram-config-{}-{}", std::process::id(), name));
+    fs::write(&path, content).unwrap();
+    path
+}
+
+fn vars(vars: &[(&str, &str)]) -> Vec<(String, String)> {
+    vars.iter()
+        .map(|(name, value)| (name.to_string(), value.to_string()))
+        .collect()
+}
+
+#[test]
+fn load_with_precedence() {
+    let file = temp_file(
+        "precedence.toml",
+        "token = \"1:FILEXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX\"\nurl = \"http://file\"\ntimeout = 10\nupdates_limit = 50\n",
+    );
+    let env = vars(&[
+        ("TELEGRAM_URL", "http://env"),
+        ("TELEGRAM_TIMEOUT", "20"),
+        ("TELEGRAM_MODE", "local"),
+        ("HOME", "/root"),
+    ]);
+    let args = [
+        "bot",
+        "--config",
+        file.to_str().unwrap(),
+        "--timeout",
+        "30",
+        "--updates-timeout",
+        "15",
+    ];
+
+    let config = Config::load_from(args, env).unwrap();
+
+    assert_eq!(
+        config.token.expose(),
+        "1:FILEXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX"
+    );
+    assert_eq!(config.url, "http://env");
+    assert_eq!(config.mode, Mode::Local);
+    assert_eq!(config.timeout, 30);
+    assert_eq!(config.updates_limit, 50);
+    assert_eq!(config.connect_timeout, 5);
+    assert_eq!(config.updates_timeout, 15);
+    assert_eq!(config.config, Some(file));
+}
+
+#[test]
+fn load_yaml_from_env() {
+    let file = temp_file(
+        "env.yaml",
+        "token: 2:YAMLXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX\nupdates_timeout: 25\nproxy: http://proxy:3128\n",
+    );
+    let env = vars(&[("TELEGRAM_CONFIG", file.to_str().unwrap())]);
+
+    let config = Config::load_from(["bot"], env).unwrap();
+
+    assert_eq!(
+        config.token.expose(),
+        "2:YAMLXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX"
+    );
+    assert_eq!(config.url, API_URL);
+    assert_eq!(config.mode, Mode::Cloud);
+    assert_eq!(config.updates_timeout, 25);
+    assert_eq!(config.proxy, Some(String::from("http://proxy:3128")));
+}
+
+#[test]
+fn load_token_file() {
+    let secret = temp_file("token", &format!("{}\n", TOKEN));
+    let file = temp_file(
+        "token.toml",
+        "token = \"1:FILEXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX\"\n",
+    );
+    let env = vars(&[
+        ("TELEGRAM_CONFIG", file.to_str().unwrap()),
+        ("TELEGRAM_TOKEN_FILE", secret.to_str().unwrap()),
+    ]);
+
+    let config = Config::load_from(["bot"], env.clone()).unwrap();
+    assert_eq!(config.token.expose(), TOKEN);
+
+    let config = Config::load_from(
+        ["bot", "--token", "3:CLIXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX"],
+        env,
+    )
+    .unwrap();
+    assert_eq!(
+        config.token.expose(),
+        "3:CLIXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX"
+    );
+}
+
+#[test]
+fn load_errors_name_the_field() {
+    let field = |args: &[&str], env: &[(&str, &str)]| match Config::load_from(args, vars(env)) {
+        Err(Error::Config { field, .. }) => field,
+        result => panic!("unexpected {:?}", result),
+    };
+
+    assert_eq!(field(&["bot"], &[]), "token");
+    assert_eq!(field(&["bot", "--token", "123456:short"], &[]), "token");
+    assert_eq!(
+        field(&["bot", "--token", TOKEN, "--url", "api"], &[]),
+        "url"
+    );
+    assert_eq!(
+        field(&["bot", "--token", TOKEN, "--timeout", "0"], &[]),
+        "timeout"
+    );
+    assert_eq!(
+        field(
+            &["bot"],
+            &[
+                ("TELEGRAM_TOKEN", TOKEN),
+                ("TELEGRAM_UPDATES_LIMIT", "1000")
+            ]
+        ),
+        "updates_limit"
+    );
+    assert_eq!(
+        field(
+            &["bot"],
+            &[("TELEGRAM_TOKEN", TOKEN), ("TELEGRAM_TIMEOUT", "soon")]
+        ),
+        "timeout"
+    );
+    assert_eq!(
+        field(&["bot"], &[("TELEGRAM_TOKEN_FILE", "/nonexistent/token")]),
+        "token_file"
+    );
+
+    let file = temp_file("unknown.toml", "tokn = \"t\"\n");
+    assert_eq!(
+        field(&["bot", "--config", file.to_str().unwrap()], &[]),
+        "config"
+    );
+}
+
+#[test]
+fn load_tls_settings() {
+    let env = vars(&[
+        ("TELEGRAM_TOKEN", TOKEN),
+        ("TELEGRAM_NO_PROXY", "localhost,.internal"),
+        (
+            "TELEGRAM_ROOT_CERTIFICATES",
+            "/etc/ssl/corporate.pem, /etc/ssl/staging.pem",
+        ),
+        ("TELEGRAM_HTTP1_ONLY", "true"),
+    ]);
+    let args = [
+        "bot",
+        "--root-certificates",
+        "src/tests/certificates/ca.pem",
+        "--client-certificate",
+        "client.pem",
+        "--client-key",
+        "client.key",
+    ];
+
+    let config = Config::load_from(args, env.clone()).unwrap();
+
+    assert_eq!(config.no_proxy.as_deref(), Some("localhost,.internal"));
+    assert_eq!(
+        config.root_certificates,
+        vec![PathBuf::from("src/tests/certificates/ca.pem")]
+    );
+    assert_eq!(config.client_certificate, Some(PathBuf::from("client.pem")));
+    assert!(config.http1_only);
+
+    let config = Config::load_from(["bot"], env).unwrap();
+    assert_eq!(config.root_certificates.len(), 2);
+}
+
+#[test]
+fn load_tls_errors_name_the_field() {
+    let field = |args: &[&str]| match Config::load_from(args, vars(&[("TELEGRAM_TOKEN", TOKEN)])) {
+        Err(Error::Config { field, .. }) => field,
+        result => panic!("unexpected {:?}", result),
+    };
+
+    assert_eq!(
+        field(&["bot", "--client-certificate", "client.pem"]),
+        "client_key"
+    );
+    assert_eq!(
+        field(&["bot", "--http1-only", "--http2-prior-knowledge"]),
+        "http1_only"
+    );
+}
@@ -0,0 +1,153 @@
+use crate::api::types::chat_administrator_rights::ChatAdministratorRights;
+use crate::api::types::message::Message;
+use crate::api::types::update::Update;
+use crate::deep_link::{decode_payload, encode_payload, start_payload, DeepLink, StartFilter};
+use crate::errors::Error;
+use serde_json::json;
+
+fn rights() -> ChatAdministratorRights {
+    serde_json::from_value(json!({
+        "is_anonymous": false,
+        "can_manage_chat": false,
+        "can_delete_messages": true,
+        "can_manage_video_chats": false,
+        "can_restrict_members": false,
+        "can_promote_members": false,
+        "can_change_info": true,
+        "can_invite_users": true,
+        "can_post_messages": true,
+        "can_pin_messages": false
+    }))
+    .unwrap()
+}
+
+#[test]
+fn start_links() {
+    let link = DeepLink::new("@shop_bot");
+
+    assert_eq!(
+        link.start("ref_42").unwrap(),
+        "https://t.me/shop_bot?start=ref_42"
+    );
+    assert_eq!(
+        link.clone().tg().start("ref_42").unwrap(),
+        "tg://resolve?domain=shop_bot&start=ref_42"
+    );
+    assert_eq!(
+        link.start_app(Some("store"), Some("item-7")).unwrap(),
+        "https://t.me/shop_bot/store?startapp=item-7"
+    );
+    assert_eq!(
+        link.clone().tg().start_app(None, None).unwrap(),
+        "tg://resolve?domain=shop_bot&startapp="
+    );
+}
+
+#[test]
+fn admin_links() {
+    let link = DeepLink::new("shop_bot");
+
+    assert_eq!(
+        link.start_group(Some("team"), Some(&rights())).unwrap(),
+        "https://t.me/shop_bot?startgroup=team&admin=change_info+post_messages+delete_messages+invite_users"
+    );
+    assert_eq!(
+        link.start_group(None, None).unwrap(),
+        "https://t.me/shop_bot?startgroup"
+    );
+    assert_eq!(
+        link.tg().start_channel(&rights()),
+        "tg://resolve?domain=shop_bot&startchannel&admin=change_info+post_messages+delete_messages+invite_users"
+    );
+}
+
+#[test]
+fn invalid_payloads() {
+    let link = DeepLink::new("shop_bot");
+
+    assert!(matches!(
+        link.start("a b"),
+        Err(Error::DeepLink { field, .. }) if field == "payload"
+    ));
+    assert!(link.start(&"a".repeat(64)).is_ok());
+    assert!(link.start(&"a".repeat(65)).is_err());
+    assert!(link.start_app(None, Some(&"a".repeat(512))).is_ok());
+    assert!(matches!(
+        decode_payload("not base64!"),
+        Err(Error::DeepLink { field, .. }) if field == "payload"
+    ));
+}
+
+#[test]
+fn payload_round_trip() {
+    let data = "campaign=spring&user=42 ✓".as_bytes();
+    let payload = encode_payload(data);
+
+    assert!(payload
+        .chars()
+        .all(|char| char.is_ascii_alphanumeric() || char == '_' || char == '-'));
+    assert!(DeepLink::new("shop_bot").start(&payload).is_ok());
+    assert_eq!(decode_payload(&payload).unwrap(), data);
+    assert_eq!(encode_payload(&[0xfb, 0xff]), "-_8");
+}
+
+#[test]
+fn start_payloads() {
+    assert_eq!(start_payload("/start ref_42", "shop_bot"), Some("ref_42"));
+    assert_eq!(
+        start_payload("/start@shop_bot  ref_42 ", "shop_bot"),
+        Some("ref_42")
+    );
+    assert_eq!(
+        start_payload("/start@Shop_Bot ref_42", "@shop_bot"),
+        Some("ref_42")
+    );
+    assert_eq!(start_payload("/start@other_bot ref_42", "shop_bot"), None);
+    assert_eq!(start_payload("/start", "shop_bot"), None);
+    assert_eq!(start_payload("/start ", "shop_bot"), None);
+    assert_eq!(start_payload("/started ref_42", "shop_bot"), None);
+    assert_eq!(start_payload("hello /start ref_42", "shop_bot"), None);
+
+    let message = serde_json::from_value::<Message>(json!({
+        "message_id": 1,
+        "date": 0,
+        "chat": {"id": 1, "type": "private"},
+        "text": "/start ref_42"
+    }))
+    .unwrap();
+    assert_eq!(message.start_payload("shop_bot"), Some("ref_42"));
+}
+
+#[test]
+fn start_filter() {
+    let update = |update_id: i64, text: &str| {
+        serde_json::from_value::<Update>(json!({
+            "update_id": update_id,
+            "message": {
+                "message_id": update_id,
+                "date": 0,
+                "chat": {"id": 1, "type": "private"},
+                "text": text
+            }
+        }))
+        .unwrap()
+    };
+    let updates = [
+        update(1, "/start ref_42"),
+        update(2, "hello"),
+        update(3, "/start@other_bot ref_43"),
+        update(4, "/start@shop_bot ref_44"),
+        serde_json::from_value::<Update>(json!({"update_id": 5})).unwrap(),
+    ];
+    let filter = StartFilter::new("@shop_bot");
+
+    let payloads: Vec<&str> = updates
+        .iter()
+        .filter_map(|update| filter.payload(update))
+        .collect();
+
+    assert_eq!(payloads, ["ref_42", "ref_44"]);
+    assert!(filter.matches(&updates[0]));
+    assert!(!filter.matches(&updates[1]));
+    assert!(!filter.matches(&updates[4]));
+}
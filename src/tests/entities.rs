@@ -0,0 +1,165 @@
+use crate::api::enums::message_entity_kind::MessageEntityKind;
+use crate::api::types::message::Message;
+use crate::api::types::message_entity::MessageEntity;
+use crate::format::{entities, utf16};
+use serde_json::json;
+
+fn bold(offset: i64, length: i64) -> MessageEntity {
+    MessageEntity::new(MessageEntityKind::Bold, offset, length)
+}
+
+fn link(offset: i64, length: i64, url: &str) -> MessageEntity {
+    MessageEntity {
+        url: Some(url.to_string()),
+        ..MessageEntity::new(MessageEntityKind::TextLink, offset, length)
+    }
+}
+
+#[test]
+fn entity_kind_names() {
+    let entity = serde_json::from_value::<MessageEntity>(
+        json!({"type": "bot_command", "offset": 0, "length": 6}),
+    )
+    .unwrap();
+    assert_eq!(entity.kind, MessageEntityKind::BotCommand);
+
+    let entity = serde_json::from_value::<MessageEntity>(
+        json!({"type": "date_time", "offset": 0, "length": 6}),
+    )
+    .unwrap();
+    assert_eq!(
+        entity.kind,
+        MessageEntityKind::Unknown(String::from("date_time"))
+    );
+    assert_eq!(serde_json::to_value(&entity).unwrap()["type"], "date_time");
+
+    assert_eq!(
+        serde_json::to_value(bold(1, 2)).unwrap(),
+        json!({"type": "bold", "offset": 1, "length": 2})
+    );
+}
+
+#[test]
+fn utf16_positions() {
+    let text = "a👍b";
+
+    assert_eq!(utf16::len(text), 4);
+    assert_eq!(utf16::byte_index(text, 1), Some(1));
+    assert_eq!(utf16::byte_index(text, 2), None);
+    assert_eq!(utf16::byte_index(text, 3), Some(5));
+    assert_eq!(utf16::byte_index(text, 4), Some(6));
+    assert_eq!(utf16::byte_index(text, 5), None);
+    assert_eq!(utf16::byte_index(text, -1), None);
+    assert_eq!(utf16::slice(text, 1, 2), Some("👍"));
+    assert_eq!(utf16::slice(text, 1, 1), None);
+}
+
+#[test]
+fn entity_text() {
+    let text = "🎉 Party at #home 🏠 tonight";
+
+    assert_eq!(bold(3, 5).text(text), Some("Party"));
+    assert_eq!(bold(12, 5).text(text), Some("#home"));
+    assert_eq!(bold(18, 2).text(text), Some("🏠"));
+    assert_eq!(bold(18, 1).text(text), None);
+    assert_eq!(bold(28, 1).text(text), None);
+}
+
+#[test]
+fn message_entities() {
+    let message = serde_json::from_value::<Message>(json!({
+        "message_id": 1,
+        "date": 0,
+        "chat": {"id": 1, "type": "private"},
+        "text": "👋 /start now",
+        "entities": [
+            {"type": "bot_command", "offset": 3, "length": 6},
+            {"type": "bold", "offset": 40, "length": 1}
+        ]
+    }))
+    .unwrap();
+
+    let parsed = message.parse_entities();
+
+    assert_eq!(parsed.len(), 1);
+    assert_eq!(parsed[0].0.kind, MessageEntityKind::BotCommand);
+    assert_eq!(parsed[0].1, "/start");
+}
+
+#[test]
+fn prepend_text() {
+    let mut text = String::from("bold");
+    let mut entities = vec![bold(0, 4)];
+
+    entities::prepend(&mut text, &mut entities, "🔥 ");
+
+    assert_eq!(text, "🔥 bold");
+    assert_eq!(entities, vec![bold(3, 4)]);
+    assert_eq!(entities[0].text(&text), Some("bold"));
+}
+
+#[test]
+fn trim_text() {
+    let (text, entities) = entities::trim(
+        "  🔥 hot  \n",
+        &[bold(0, 4), bold(5, 6), link(9, 2, "https://example.com")],
+    );
+
+    assert_eq!(text, "🔥 hot");
+    assert_eq!(entities, vec![bold(0, 2), bold(3, 3)]);
+
+    assert_eq!(
+        entities::trim("   ", &[bold(0, 3)]),
+        (String::new(), vec![])
+    );
+}
+
+#[test]
+fn slice_text() {
+    let (text, entities) =
+        entities::slice("one 👍 two", &[bold(0, 6), link(4, 6, "u")], 4, 6).unwrap();
+
+    assert_eq!(text, "👍 two");
+    assert_eq!(entities, vec![bold(0, 2), link(0, 6, "u")]);
+
+    assert!(entities::slice("👍", &[], 1, 1).is_none());
+}
+
+#[test]
+fn merge_entities() {
+    let merged = entities::merge(vec![
+        bold(5, 5),
+        link(0, 3, "a"),
+        bold(0, 3),
+        bold(2, 3),
+        link(3, 2, "b"),
+        bold(12, 1),
+    ]);
+
+    assert_eq!(
+        merged,
+        vec![bold(0, 10), link(0, 3, "a"), link(3, 2, "b"), bold(12, 1)]
+    );
+}
+
+#[test]
+fn merge_keeps_touching_entities_apart() {
+    let emoji = |offset| MessageEntity {
+        custom_emoji_id: Some(String::from("5368324170671202286")),
+        ..MessageEntity::new(MessageEntityKind::CustomEmoji, offset, 2)
+    };
+    let code = |offset, length| MessageEntity::new(MessageEntityKind::Code, offset, length);
+
+    assert_eq!(
+        entities::merge(vec![emoji(0), emoji(2)]),
+        vec![emoji(0), emoji(2)]
+    );
+    assert_eq!(
+        entities::merge(vec![code(0, 3), code(3, 2), code(4, 3)]),
+        vec![code(0, 3), code(3, 4)]
+    );
+    assert_eq!(
+        entities::merge(vec![link(0, 3, "a"), link(3, 2, "a")]),
+        vec![link(0, 3, "a"), link(3, 2, "a")]
+    );
+}
@@ -0,0 +1,304 @@
+use crate::api::enums::bot_command_scope::BotCommandScope;
+use crate::api::enums::chat_boost_source::ChatBoostSource;
+use crate::api::enums::chat_member::ChatMember;
+use crate::api::enums::chat_uid::ChatUId;
+use crate::api::enums::file_input::FileInput;
+use crate::api::enums::inline_query_result::InlineQueryResult;
+use crate::api::enums::input_media::InputMedia;
+use crate::api::enums::input_message_content::InputMessageContent;
+use crate::api::enums::maybe_inaccessible_message::MaybeInaccessibleMessage;
+use crate::api::enums::menu_button::MenuButton;
+use crate::api::enums::message_origin::MessageOrigin;
+use crate::api::enums::passport_element_error::PassportElementError;
+use crate::api::enums::reaction_type::ReactionType;
+use crate::api::enums::reply_markup::ReplyMarkup;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{json, Value};
+
+fn round_trip<T: Serialize + DeserializeOwned>(json: &str) -> T {
+    let decoded = serde_json::from_str::<T>(json).unwrap();
+    let encoded = serde_json::to_value(&decoded).unwrap();
+
+    assert_eq!(encoded, serde_json::from_str::<Value>(json).unwrap());
+
+    decoded
+}
+
+#[test]
+fn chat_uid_untagged() {
+    assert_eq!(serde_json::to_string(&ChatUId::from(-100)).unwrap(), "-100");
+    assert_eq!(
+        serde_json::to_string(&ChatUId::from("@channel")).unwrap(),
+        "\"@channel\""
+    );
+}
+
+#[test]
+fn reply_markup_untagged() {
+    let markup = round_trip::<ReplyMarkup>(
+        r#"{"inline_keyboard":[[{"text":"Yes","callback_data":"yes"},{"text":"No","callback_data":"no"}]]}"#,
+    );
+    assert!(matches!(markup, ReplyMarkup::InlineKeyboardMarkup(_)));
+
+    let markup = round_trip::<ReplyMarkup>(
+        r#"{"keyboard":[[{"text":"Share contact","request_contact":true}]],"resize_keyboard":true}"#,
+    );
+    assert!(matches!(markup, ReplyMarkup::ReplyKeyboardMarkup(_)));
+
+    let markup = round_trip::<ReplyMarkup>(r#"{"remove_keyboard":true}"#);
+    assert!(matches!(markup, ReplyMarkup::ReplyKeyboardRemove(_)));
+
+    let markup = round_trip::<ReplyMarkup>(r#"{"force_reply":true,"selective":true}"#);
+    assert!(matches!(markup, ReplyMarkup::ForceReply(_)));
+}
+
+#[test]
+fn file_input_untagged() {
+    let file = round_trip::<FileInput>(r#""AgACAgIAAxkBAAIBZ2W""#);
+    assert_eq!(file, FileInput::String(String::from("AgACAgIAAxkBAAIBZ2W")));
+}
+
+#[test]
+fn input_message_content_by_fields() {
+    let content =
+        round_trip::<InputMessageContent>(r#"{"message_text":"Hello","parse_mode":"HTML"}"#);
+    assert!(matches!(
+        content,
+        InputMessageContent::InputTextMessageContent(_)
+    ));
+
+    let content = round_trip::<InputMessageContent>(r#"{"latitude":51.5,"longitude":-0.12}"#);
+    assert!(matches!(
+        content,
+        InputMessageContent::InputLocationMessageContent(_)
+    ));
+
+    let content = round_trip::<InputMessageContent>(
+        r#"{"latitude":51.5,"longitude":-0.12,"title":"Office","address":"Baker Street"}"#,
+    );
+    assert!(matches!(
+        content,
+        InputMessageContent::InputVenueMessageContent(_)
+    ));
+
+    let content =
+        round_trip::<InputMessageContent>(r#"{"phone_number":"+100000","first_name":"John"}"#);
+    assert!(matches!(
+        content,
+        InputMessageContent::InputContactMessageContent(_)
+    ));
+}
+
+#[test]
+fn menu_button_tagged_by_type() {
+    let button = round_trip::<MenuButton>(r#"{"type":"default"}"#);
+    assert!(matches!(button, MenuButton::MenuButtonDefault(_)));
+
+    let button = round_trip::<MenuButton>(r#"{"type":"commands"}"#);
+    assert!(matches!(button, MenuButton::MenuButtonCommands(_)));
+
+    let button = round_trip::<MenuButton>(
+        r#"{"type":"web_app","text":"Open","web_app":{"url":"https://example.com"}}"#,
+    );
+    assert!(matches!(button, MenuButton::MenuButtonWebApp(_)));
+}
+
+#[test]
+fn bot_command_scope_tagged_by_type() {
+    let scope = round_trip::<BotCommandScope>(r#"{"type":"default"}"#);
+    assert!(matches!(scope, BotCommandScope::BotCommandScopeDefault(_)));
+
+    let scope = round_trip::<BotCommandScope>(r#"{"type":"all_private_chats"}"#);
+    assert!(matches!(
+        scope,
+        BotCommandScope::BotCommandScopeAllPrivateChats(_)
+    ));
+
+    let scope = round_trip::<BotCommandScope>(r#"{"type":"chat_administrators","chat_id":-100}"#);
+    assert!(matches!(
+        scope,
+        BotCommandScope::BotCommandScopeChatAdministrators(_)
+    ));
+
+    let scope =
+        round_trip::<BotCommandScope>(r#"{"type":"chat_member","chat_id":"@group","user_id":42}"#);
+    assert!(matches!(
+        scope,
+        BotCommandScope::BotCommandScopeChatMember(_)
+    ));
+}
+
+#[test]
+fn passport_element_error_tagged_by_source() {
+    let error = round_trip::<PassportElementError>(
+        r#"{"source":"selfie","type":"passport","file_hash":"aGFzaA==","message":"Selfie is blurry"}"#,
+    );
+    assert!(matches!(
+        error,
+        PassportElementError::PassportElementErrorSelfie(_)
+    ));
+
+    let error = round_trip::<PassportElementError>(
+        r#"{"source":"data","type":"personal_details","field_name":"birth_date","data_hash":"aGFzaA==","message":"Invalid date"}"#,
+    );
+    assert!(matches!(
+        error,
+        PassportElementError::PassportElementErrorDataField(_)
+    ));
+
+    let error = round_trip::<PassportElementError>(
+        r#"{"source":"files","type":"utility_bill","file_hashes":["aGFzaA=="],"message":"Unreadable"}"#,
+    );
+    assert!(matches!(
+        error,
+        PassportElementError::PassportElementErrorFiles(_)
+    ));
+}
+
+#[test]
+fn chat_member_tagged_by_status() {
+    let user = r#"{"id":42,"is_bot":false,"first_name":"John"}"#;
+
+    let member = round_trip::<ChatMember>(&format!(r#"{{"status":"member","user":{}}}"#, user));
+    assert!(matches!(member, ChatMember::ChatMemberMember(_)));
+
+    let member = round_trip::<ChatMember>(&format!(r#"{{"status":"left","user":{}}}"#, user));
+    assert!(matches!(member, ChatMember::ChatMemberLeft(_)));
+
+    let member = round_trip::<ChatMember>(&format!(
+        r#"{{"status":"kicked","user":{},"until_date":0}}"#,
+        user
+    ));
+    assert!(matches!(member, ChatMember::ChatMemberBanned(_)));
+
+    let member = round_trip::<ChatMember>(&format!(
+        r#"{{"status":"creator","user":{},"is_anonymous":false}}"#,
+        user
+    ));
+    assert!(matches!(member, ChatMember::ChatMemberOwner(_)));
+}
+
+#[test]
+fn chat_boost_source_tagged_by_source() {
+    let user = r#"{"id":42,"is_bot":false,"first_name":"John"}"#;
+
+    let source =
+        round_trip::<ChatBoostSource>(&format!(r#"{{"source":"premium","user":{}}}"#, user));
+    assert!(matches!(source, ChatBoostSource::ChatBoostSourcePremium(_)));
+
+    let source =
+        round_trip::<ChatBoostSource>(&format!(r#"{{"source":"gift_code","user":{}}}"#, user));
+    assert!(matches!(
+        source,
+        ChatBoostSource::ChatBoostSourceGiftCode(_)
+    ));
+
+    let source = round_trip::<ChatBoostSource>(
+        r#"{"source":"giveaway","giveaway_message_id":7,"is_unclaimed":true}"#,
+    );
+    assert!(matches!(
+        source,
+        ChatBoostSource::ChatBoostSourceGiveaway(_)
+    ));
+}
+
+#[test]
+fn reaction_type_tagged_by_type() {
+    let reaction = round_trip::<ReactionType>(r#"{"type":"emoji","emoji":"👍"}"#);
+    assert!(matches!(reaction, ReactionType::ReactionTypeEmoji(_)));
+
+    let reaction = round_trip::<ReactionType>(
+        r#"{"type":"custom_emoji","custom_emoji_id":"5368324170671202286"}"#,
+    );
+    assert!(matches!(reaction, ReactionType::ReactionTypeCustomEmoji(_)));
+}
+
+#[test]
+fn message_origin_tagged_by_type() {
+    let origin = round_trip::<MessageOrigin>(
+        r#"{"type":"hidden_user","date":1700000000,"sender_user_name":"John"}"#,
+    );
+    assert!(matches!(origin, MessageOrigin::MessageOriginHiddenUser(_)));
+
+    let origin = round_trip::<MessageOrigin>(
+        r#"{"type":"user","date":1700000000,"sender_user":{"id":42,"is_bot":false,"first_name":"John"}}"#,
+    );
+    assert!(matches!(origin, MessageOrigin::MessageOriginUser(_)));
+}
+
+#[test]
+fn input_media_tagged_by_type() {
+    let media =
+        round_trip::<InputMedia>(r#"{"type":"photo","media":"attach://photo","caption":"Photo"}"#);
+    assert!(matches!(media, InputMedia::InputMediaPhoto(_)));
+
+    let media = round_trip::<InputMedia>(r#"{"type":"document","media":"BQACAgIAAxkBAAIBaGW"}"#);
+    assert!(matches!(media, InputMedia::InputMediaDocument(_)));
+}
+
+#[test]
+fn inline_query_result_tagged_by_type() {
+    let result = round_trip::<InlineQueryResult>(
+        r#"{"type":"photo","id":"1","photo_url":"https://example.com/a.jpg","thumbnail_url":"https://example.com/t.jpg"}"#,
+    );
+    assert!(matches!(
+        result,
+        InlineQueryResult::InlineQueryResultPhoto(_)
+    ));
+
+    let result = round_trip::<InlineQueryResult>(
+        r#"{"type":"photo","id":"2","photo_file_id":"AgACAgIAAxkBAAIBZ2W"}"#,
+    );
+    assert!(matches!(
+        result,
+        InlineQueryResult::InlineQueryResultCachedPhoto(_)
+    ));
+
+    let result = round_trip::<InlineQueryResult>(
+        r#"{"type":"mpeg4_gif","id":"3","mpeg4_file_id":"CgACAgIAAxkBAAIBaWW"}"#,
+    );
+    assert!(matches!(
+        result,
+        InlineQueryResult::InlineQueryResultCachedMpeg4Gif(_)
+    ));
+
+    let result = round_trip::<InlineQueryResult>(
+        r#"{"type":"article","id":"4","title":"Hello","input_message_content":{"message_text":"Hello"}}"#,
+    );
+    assert!(matches!(
+        result,
+        InlineQueryResult::InlineQueryResultArticle(_)
+    ));
+}
+
+#[test]
+fn maybe_inaccessible_message_by_date() {
+    let message = round_trip::<MaybeInaccessibleMessage>(
+        r#"{"chat":{"id":-100,"type":"supergroup"},"message_id":10,"date":0}"#,
+    );
+    assert!(matches!(
+        message,
+        MaybeInaccessibleMessage::InaccessibleMessage(_)
+    ));
+}
+
+#[test]
+fn unknown_tag() {
+    let reaction = round_trip::<ReactionType>(r#"{"type":"paid"}"#);
+    assert_eq!(reaction, ReactionType::Unknown(json!({"type": "paid"})));
+
+    let origin = round_trip::<MessageOrigin>(r#"{"type":"bot","date":1706810988}"#);
+    assert!(matches!(origin, MessageOrigin::Unknown(_)));
+
+    let source = round_trip::<ChatBoostSource>(r#"{"source":"stars","star_count":50}"#);
+    assert!(matches!(source, ChatBoostSource::Unknown(_)));
+
+    let member = round_trip::<ChatMember>(r#"{"status":"guest"}"#);
+    assert!(matches!(member, ChatMember::Unknown(_)));
+}
+
+#[test]
+fn known_tag_still_strict() {
+    assert!(serde_json::from_str::<ReactionType>(r#"{"type":"emoji"}"#).is_err());
+}
@@ -1,21 +1,180 @@
+use crate::api::requests::sync::Requests;
+use crate::api::responses::error::ResponseError;
+use crate::clients::sync::Sync;
+use crate::config::token::BotToken;
+use crate::config::Config;
 use crate::errors::Error;
+use crate::tests::helpers::*;
+use std::error::Error as StdError;
+use std::fs;
 
-fn error_request() {
-    todo!()
+fn classify(status: u16, body: &str) -> Error {
+    Error::from(ResponseError::new(status, body))
 }
 
+#[test]
+fn request_error() {
+    let config = Config {
+        url: String::from("http://127.0.0.1:1"),
+        token: BotToken::from(TOKEN),
+        ..Default::default()
+    };
+
+    let error = Sync::new(&config).unwrap().get_me().unwrap_err();
+
+    assert!(matches!(error, Error::Request(_)));
+    assert!(error.source().is_some());
+}
+
+#[test]
 fn response_error() {
-    todo!()
+    let error = classify(
+        400,
+        r#"{"ok":false,"error_code":400,"description":"Bad Request: chat_id is empty"}"#,
+    );
+
+    assert!(matches!(error, Error::Response(_)));
+    assert_eq!(error.error_code(), Some(400));
+    assert_eq!(error.description(), Some("Bad Request: chat_id is empty"));
 }
 
+#[test]
 fn decode_error() {
-    todo!()
+    let mut server = mockito::Server::new();
+    let mocked = Mocked::new(&mut server, "getMe", r#"{"ok":true,"result":[]}"#);
+
+    let error = mocked.client.sync.get_me().unwrap_err();
+
+    assert!(matches!(error, Error::Decode(_)));
+    mocked.server.assert();
+}
+
+#[test]
+fn response_error_with_non_json_body() {
+    let mut server = mockito::Server::new();
+    let mocked = Mocked::with_status(&mut server, "getMe", 502, "<html>502 Bad Gateway</html>");
+
+    let error = mocked.client.sync.get_me().unwrap_err();
+
+    assert!(matches!(error, Error::Server(_)));
+    assert_eq!(error.error_code(), Some(502));
+    assert_eq!(error.description(), Some("<html>502 Bad Gateway</html>"));
+    mocked.server.assert();
+}
+
+#[test]
+fn too_many_requests() {
+    let mock_response =
+        fs::read_to_string("src/tests/responses/close_error_with_params.json").unwrap();
+    let mut server = mockito::Server::new();
+    let mocked = Mocked::with_status(&mut server, "close", 429, &mock_response);
+
+    let error = mocked.client.sync.close().unwrap_err();
+
+    assert!(matches!(
+        error,
+        Error::TooManyRequests {
+            retry_after: 586,
+            ..
+        }
+    ));
+    assert_eq!(
+        error.to_string(),
+        "Telegram Error 429: Too Many Requests: retry after 586"
+    );
+    mocked.server.assert();
+}
+
+#[test]
+fn migrate_to_chat() {
+    let error = classify(
+        400,
+        r#"{"ok":false,"error_code":400,"description":"Bad Request: group chat was upgraded to a supergroup chat","parameters":{"migrate_to_chat_id":-1001234567890}}"#,
+    );
+
+    assert!(matches!(
+        error,
+        Error::MigrateToChat {
+            id: -1001234567890,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn well_known_errors() {
+    let error = classify(
+        401,
+        r#"{"ok":false,"error_code":401,"description":"Unauthorized"}"#,
+    );
+    assert!(matches!(error, Error::Unauthorized(_)));
+
+    let error = classify(
+        403,
+        r#"{"ok":false,"error_code":403,"description":"Forbidden: bot was blocked by the user"}"#,
+    );
+    assert!(matches!(error, Error::BotBlocked(_)));
+
+    let error = classify(
+        403,
+        r#"{"ok":false,"error_code":403,"description":"Forbidden: bot was kicked from the supergroup chat"}"#,
+    );
+    assert!(matches!(error, Error::BotKicked(_)));
+
+    let error = classify(
+        403,
+        r#"{"ok":false,"error_code":403,"description":"Forbidden: user is deactivated"}"#,
+    );
+    assert!(matches!(error, Error::UserDeactivated(_)));
+
+    let error = classify(
+        403,
+        r#"{"ok":false,"error_code":403,"description":"Forbidden: bot can't initiate conversation with a user"}"#,
+    );
+    assert!(matches!(error, Error::CantInitiateConversation(_)));
+
+    let error = classify(
+        400,
+        r#"{"ok":false,"error_code":400,"description":"Bad Request: chat not found"}"#,
+    );
+    assert!(matches!(error, Error::ChatNotFound(_)));
+
+    let error = classify(
+        400,
+        r#"{"ok":false,"error_code":400,"description":"Bad Request: message to edit not found"}"#,
+    );
+    assert!(matches!(error, Error::MessageNotFound(_)));
+
+    let error = classify(
+        400,
+        r#"{"ok":false,"error_code":400,"description":"Bad Request: message is not modified: specified new message content and reply markup are exactly the same as a current content and reply markup of the message"}"#,
+    );
+    assert!(matches!(error, Error::MessageNotModified(_)));
+
+    let error = classify(
+        400,
+        r#"{"ok":false,"error_code":400,"description":"Bad Request: not enough rights to send text messages to the chat"}"#,
+    );
+    assert!(matches!(error, Error::NotEnoughRights(_)));
+
+    let error = classify(
+        409,
+        r#"{"ok":false,"error_code":409,"description":"Conflict: terminated by other getUpdates request"}"#,
+    );
+    assert!(matches!(error, Error::Conflict(_)));
 }
 
 #[test]
-fn debug_error() {
-    let error = Error::Debug;
-    let error_string = format!("{}", error);
+fn display_error() {
+    let error = Error::Decode(serde_json::from_str::<bool>("{").unwrap_err());
+    assert_eq!(
+        format!("{}", error),
+        "Decode Error: invalid type: map, expected a boolean at line 1 column 0"
+    );
 
-    assert_eq!(error_string, "Debug Error!");
+    let error = Error::Auth {
+        field: String::from("hash"),
+        reason: String::from("is missing"),
+    };
+    assert_eq!(format!("{}", error), "Auth Error: hash is missing");
 }
@@ -0,0 +1,329 @@
+use crate::api::enums::parse_mode::ParseMode;
+use crate::api::params::send_message::SendMessage;
+use crate::api::types::message::Message;
+use crate::api::types::message_entity::MessageEntity;
+use crate::format::{escape, Text};
+use serde_json::json;
+
+fn sample() -> Text {
+    Text::new()
+        .text("Order #42 ")
+        .bold(Text::new().text("shipped ").italic("today"))
+        .text(", see ")
+        .link("the tracker", "https://example.com/track?id=(42)")
+        .text(" 🚚")
+}
+
+#[test]
+fn parse_mode_names() {
+    assert_eq!(
+        serde_json::to_value(ParseMode::Html).unwrap(),
+        json!("HTML")
+    );
+    assert_eq!(
+        serde_json::to_value(ParseMode::MarkdownV2).unwrap(),
+        json!("MarkdownV2")
+    );
+
+    let params = SendMessage {
+        text: String::from("<b>hi</b>"),
+        parse_mode: Some(ParseMode::Html),
+        ..Default::default()
+    };
+    assert_eq!(serde_json::to_value(params).unwrap()["parse_mode"], "HTML");
+}
+
+#[test]
+fn escape_html() {
+    assert_eq!(
+        escape::html(r#"<a href="x">Tom & Jerry</a>"#),
+        "&lt;a href=&quot;x&quot;&gt;Tom &amp; Jerry&lt;/a&gt;"
+    );
+}
+
+#[test]
+fn escape_markdown_v2() {
+    assert_eq!(
+        escape::markdown_v2("1.5 * (2 + 3) = 7.5! [_x_] \\"),
+        "1\\.5 \\* \\(2 \\+ 3\\) \\= 7\\.5\\! \\[\\_x\\_\\] \\\\"
+    );
+    assert_eq!(escape::markdown_v2_code("a `b` \\ c."), "a \\`b\\` \\\\ c.");
+    assert_eq!(
+        escape::markdown_v2_url("https://x.y/(a)"),
+        "https://x.y/(a\\)"
+    );
+}
+
+#[test]
+fn render_html() {
+    assert_eq!(
+        sample().to_html(),
+        "Order #42 <b>shipped <i>today</i></b>, see \
+         <a href=\"https://example.com/track?id=(42)\">the tracker</a> 🚚"
+    );
+
+    let text = Text::new()
+        .underline("u")
+        .strikethrough("s")
+        .spoiler("x")
+        .code("a < b")
+        .pre("fn main() {}", Some("rust"))
+        .pre("plain", None)
+        .mention("Ann", 123)
+        .custom_emoji("👍", "5368324170671202286")
+        .blockquote("quoted");
+
+    assert_eq!(
+        text.to_html(),
+        "<u>u</u><s>s</s><tg-spoiler>x</tg-spoiler><code>a &lt; b</code>\
+         <pre><code class=\"language-rust\">fn main() {}</code></pre><pre>plain</pre>\
+         <a href=\"tg://user?id=123\">Ann</a>\
+         <tg-emoji emoji-id=\"5368324170671202286\">👍</tg-emoji><blockquote>quoted</blockquote>"
+    );
+}
+
+#[test]
+fn render_markdown_v2() {
+    assert_eq!(
+        sample().to_markdown_v2(),
+        "Order \\#42 *shipped _today_*, see \
+         [the tracker](https://example.com/track?id=(42\\)) 🚚"
+    );
+
+    let text = Text::new()
+        .strikethrough("s")
+        .spoiler("x")
+        .code("a`b")
+        .pre("let x = 1;", Some("rust"))
+        .mention("Ann", 123)
+        .custom_emoji("👍", "5368324170671202286");
+
+    assert_eq!(
+        text.to_markdown_v2(),
+        "~s~||x||`a\\`b````rust\nlet x = 1;\n```[Ann](tg://user?id=123)\
+         ![👍](tg://emoji?id=5368324170671202286)"
+    );
+}
+
+#[test]
+fn markdown_v2_adjacent_markers() {
+    let text = Text::new().underline(Text::new().italic("both"));
+    assert_eq!(text.to_markdown_v2(), "__\r_both_\r__");
+
+    let text = Text::new().bold("a").bold("b");
+    assert_eq!(text.to_markdown_v2(), "*a*\r*b*");
+
+    let text = Text::new().text("snake_").italic("case");
+    assert_eq!(text.to_markdown_v2(), "snake\\__case_");
+}
+
+#[test]
+fn markdown_v2_blockquote() {
+    let text = Text::new()
+        .text("Quote:\n")
+        .blockquote(Text::new().text("first line\n").bold("second"));
+
+    assert_eq!(text.to_markdown_v2(), "Quote:\n>first line\n>*second*");
+}
+
+#[test]
+fn parse_markdown_v2() {
+    assert_eq!(
+        Text::from_markdown_v2(&sample().to_markdown_v2()).unwrap(),
+        sample()
+    );
+
+    let text = Text::new()
+        .underline(Text::new().italic("both"))
+        .text("snake_")
+        .italic("case")
+        .strikethrough("s")
+        .spoiler("x")
+        .code("a`b")
+        .pre("let x = 1;", Some("rust"))
+        .mention("Ann", 123)
+        .custom_emoji("👍", "5368324170671202286")
+        .text("\n")
+        .blockquote(Text::new().text("first line\n").bold("second"));
+    assert_eq!(
+        Text::from_markdown_v2(&text.to_markdown_v2()).unwrap(),
+        text
+    );
+}
+
+#[test]
+fn parse_markdown_v2_errors() {
+    let reason = |markdown| match Text::from_markdown_v2(markdown) {
+        Err(crate::errors::Error::Validation { field, reason }) if field == "text" => reason,
+        result => panic!("unexpected result {:?}", result),
+    };
+
+    assert_eq!(
+        reason("Total: 1.5"),
+        "character '.' at 8 is reserved and must be escaped"
+    );
+    assert_eq!(
+        reason("*bold _both*_"),
+        "entity at 6 is not closed before the entity at 0"
+    );
+    assert_eq!(reason("a ~strike"), "entity at 2 is not closed");
+    assert_eq!(
+        reason("[link *bold](u)*"),
+        "entity at 6 is not closed before the entity at 0"
+    );
+    assert_eq!(reason("[link]"), "link at 0 has no url");
+}
+
+#[test]
+fn parse_legacy_markdown() {
+    let text = Text::from_markdown("*bold* _it_ `a_b` [x](tg://user?id=7) 1.5 \\_").unwrap();
+
+    assert_eq!(
+        text,
+        Text::new()
+            .bold("bold")
+            .text(" ")
+            .italic("it")
+            .text(" ")
+            .code("a_b")
+            .text(" ")
+            .mention("x", 7)
+            .text(" 1.5 _")
+    );
+}
+
+#[test]
+fn render_entities() {
+    let (text, entities) = sample().to_entities();
+
+    assert_eq!(text, "Order #42 shipped today, see the tracker 🚚");
+    assert_eq!(
+        serde_json::to_value(&entities).unwrap(),
+        json!([
+            {"type": "bold", "offset": 10, "length": 13},
+            {"type": "italic", "offset": 18, "length": 5},
+            {"type": "text_link", "offset": 29, "length": 11, "url": "https://example.com/track?id=(42)"},
+        ])
+    );
+}
+
+#[test]
+fn entities_utf16_offsets() {
+    let text = Text::new()
+        .text("🚚 ")
+        .custom_emoji("👍", "1")
+        .bold("ok")
+        .pre("x", Some("rust"))
+        .mention("Ann", 123)
+        .italic("");
+    let (text, entities) = text.to_entities();
+
+    assert_eq!(text, "🚚 👍okxAnn");
+    assert_eq!(
+        serde_json::to_value(&entities).unwrap(),
+        json!([
+            {"type": "custom_emoji", "offset": 3, "length": 2, "custom_emoji_id": "1"},
+            {"type": "bold", "offset": 5, "length": 2},
+            {"type": "pre", "offset": 7, "length": 1, "language": "rust"},
+            {"type": "text_link", "offset": 8, "length": 3, "url": "tg://user?id=123"},
+        ])
+    );
+}
+
+fn received(text: &str, entities: serde_json::Value) -> Text {
+    let entities = serde_json::from_value::<Vec<MessageEntity>>(entities).unwrap();
+    Text::from_entities(text, &entities)
+}
+
+#[test]
+fn entities_round_trip() {
+    let (text, entities) = sample().to_entities();
+
+    assert_eq!(Text::from_entities(&text, &entities), sample());
+}
+
+#[test]
+fn render_received_nested() {
+    let text = received(
+        "👋 Hello bold italic world",
+        json!([
+            {"type": "bold", "offset": 3, "length": 17},
+            {"type": "italic", "offset": 14, "length": 6},
+            {"type": "underline", "offset": 14, "length": 12},
+        ]),
+    );
+
+    assert_eq!(
+        text.to_html(),
+        "👋 <b>Hello bold <u><i>italic</i></u></b><u> world</u>"
+    );
+    assert_eq!(
+        text.to_markdown_v2(),
+        "👋 *Hello bold __\r_italic_\r__*__ world__"
+    );
+}
+
+#[test]
+fn render_received_links() {
+    let text = received(
+        "Ask Ann at example.com or #support 👍",
+        json!([
+            {"type": "text_mention", "offset": 4, "length": 3, "user": {"id": 42, "is_bot": false, "first_name": "Ann"}},
+            {"type": "text_link", "offset": 11, "length": 11, "url": "https://example.com/a_(b)"},
+            {"type": "hashtag", "offset": 26, "length": 8},
+            {"type": "custom_emoji", "offset": 35, "length": 2, "custom_emoji_id": "5368324170671202286"},
+        ]),
+    );
+
+    assert_eq!(
+        text.to_html(),
+        "Ask <a href=\"tg://user?id=42\">Ann</a> at \
+         <a href=\"https://example.com/a_(b)\">example.com</a> or #support \
+         <tg-emoji emoji-id=\"5368324170671202286\">👍</tg-emoji>"
+    );
+    assert_eq!(
+        text.to_markdown_v2(),
+        "Ask [Ann](tg://user?id=42) at [example\\.com](https://example.com/a_(b\\)) or \\#support \
+         ![👍](tg://emoji?id=5368324170671202286)"
+    );
+}
+
+#[test]
+fn render_received_code() {
+    let text = received(
+        "Run:\nfn main() { println!(\"<hi>\"); }\nor `cargo run`",
+        json!([
+            {"type": "pre", "offset": 5, "length": 31, "language": "rust"},
+            {"type": "bold", "offset": 10, "length": 4},
+            {"type": "code", "offset": 41, "length": 9},
+            {"type": "italic", "offset": 100, "length": 3},
+        ]),
+    );
+
+    assert_eq!(
+        text.to_html(),
+        "Run:\n<pre><code class=\"language-rust\">fn main() { println!(&quot;&lt;hi&gt;&quot;); }</code></pre>\n\
+         or `<code>cargo run</code>`"
+    );
+    assert_eq!(
+        text.to_markdown_v2(),
+        "Run:\n```rust\nfn main() { println!(\"<hi>\"); }\n```\nor \\``cargo run`\\`"
+    );
+}
+
+#[test]
+fn render_received_message() {
+    let message = serde_json::from_value::<Message>(json!({
+        "message_id": 1,
+        "date": 0,
+        "chat": {"id": 1, "type": "private"},
+        "caption": "Photo by me",
+        "caption_entities": [{"type": "spoiler", "offset": 9, "length": 2}]
+    }))
+    .unwrap();
+
+    assert_eq!(
+        message.formatted_text().unwrap().to_html(),
+        "Photo by <tg-spoiler>me</tg-spoiler>"
+    );
+}
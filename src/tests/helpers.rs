@@ -1,11 +1,41 @@
 use crate::api::responses::result::ResponseResult;
 use crate::clients::r#async::Async;
+use crate::clients::retry::Retry;
 use crate::clients::sync::Sync;
-use crate::config::Config;
+use crate::clients::transport::memory::MemoryTransport;
+use crate::config::{Config, Mode};
 use crate::errors::Error;
 use crate::Client;
 use mockito::{Mock, ServerGuard};
 use serde::de::DeserializeOwned;
+use std::sync::Arc;
+
+pub const TOKEN: &str = "0000000000:XXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX";
+
+/// Client answering from the transport, without retries.
+pub fn memory_client(transport: Arc<MemoryTransport>) -> Client {
+    Client::builder(TOKEN)
+        .transport(transport)
+        .retry(Retry::new())
+        .build()
+        .unwrap()
+}
+
+/// Sync client of a Bot API server at `url` running in `mode`.
+pub fn sync_for(url: &str, mode: Mode) -> Sync {
+    let config = Config {
+        url: url.to_string(),
+        mode,
+        token: TOKEN.into(),
+        ..Default::default()
+    };
+    Sync::new(&config).unwrap()
+}
+
+/// Sync client of the mock server.
+pub fn mock_sync(server: &ServerGuard) -> Sync {
+    sync_for(&server.url(), Mode::Cloud)
+}
 
 pub struct Mocked {
     pub client: Client,
@@ -17,29 +47,44 @@ impl Mocked {
     fn mock_api(server: &ServerGuard, token: &str) -> Client {
         let config = Config {
             url: server.url(),
-            token: token.to_string(),
+            token: token.into(),
             ..Default::default()
         };
 
         Client {
-            sync: Sync::new(&config),
-            r#async: Async::new(&config),
+            sync: Sync::new(&config).unwrap(),
+            r#async: Async::new(&config).unwrap(),
             config,
         }
     }
 
-    fn mock_server(server: &mut ServerGuard, token: &str, method: &str, response: &str) -> Mock {
+    fn mock_server(
+        server: &mut ServerGuard,
+        token: &str,
+        method: &str,
+        status: usize,
+        response: &str,
+    ) -> Mock {
         server
             .mock("POST", format!("/bot{}/{}", token, method).as_str())
             .match_header("content-type", "application/json")
+            .with_status(status)
             .with_body(response)
             .create()
     }
 
     pub fn new(server: &mut ServerGuard, method: &str, response: &str) -> Self {
-        let token = "0000000000:XXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX";
-        let mocked_client = Self::mock_api(server, token);
-        let mocked_server = Self::mock_server(server, token, method, response);
+        Self::with_status(server, method, 200, response)
+    }
+
+    pub fn with_status(
+        server: &mut ServerGuard,
+        method: &str,
+        status: usize,
+        response: &str,
+    ) -> Self {
+        let mocked_client = Self::mock_api(server, TOKEN);
+        let mocked_server = Self::mock_server(server, TOKEN, method, status, response);
 
         Self {
             client: mocked_client,
@@ -0,0 +1,242 @@
+use crate::api::enums::chat_uid::ChatUId;
+use crate::api::enums::message_or_bool::MessageOrBool;
+use crate::api::method::Method;
+use crate::api::params::delete_message::DeleteMessage;
+use crate::api::params::edit_message_text::EditMessageText;
+use crate::api::params::get_me::GetMe;
+use crate::api::params::get_update::GetUpdate;
+use crate::api::params::send_message::SendMessage;
+use crate::api::types::message::Message;
+use crate::api::types::message_id::MessageId;
+use crate::api::types::user::User;
+use crate::clients::transport::memory::MemoryTransport;
+use crate::clients::transport::{Body, Part};
+use crate::errors::Error;
+use crate::tests::helpers::{memory_client, TOKEN};
+use crate::Client;
+use mockito::Matcher;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::fs;
+use std::sync::Arc;
+
+#[test]
+fn method_names() {
+    assert_eq!(SendMessage::NAME, "sendMessage");
+    assert_eq!(GetUpdate::NAME, "getUpdates");
+    assert_eq!(DeleteMessage::NAME, "deleteMessage");
+    assert_eq!(EditMessageText::NAME, "editMessageText");
+}
+
+#[test]
+fn execute_method() {
+    let response = fs::read_to_string("src/tests/responses/send_message_success.json").unwrap();
+    let transport = Arc::new(MemoryTransport::new());
+    transport.reply("sendMessage", 200, &response);
+    let client = memory_client(transport.clone());
+
+    let message: Message = client
+        .execute(&SendMessage {
+            chat_id: ChatUId::from(147951145),
+            text: String::from("asdasd"),
+            ..Default::default()
+        })
+        .unwrap();
+
+    assert_eq!(message.message_id, MessageId::from(494));
+    assert_eq!(transport.requests()[0].method, "sendMessage");
+    assert_eq!(
+        transport.requests()[0].body,
+        Body::Json(json!({"chat_id": 147951145, "text": "asdasd"}))
+    );
+}
+
+#[test]
+fn execute_unwrapped_method() {
+    let transport = Arc::new(MemoryTransport::new());
+    transport.reply("deleteMessage", 200, r#"{"ok": true, "result": true}"#);
+    let client = memory_client(transport.clone());
+
+    let deleted = client
+        .sync
+        .execute(&DeleteMessage {
+            message_id: MessageId::from(494),
+            chat_id: ChatUId::from(147951145),
+        })
+        .unwrap();
+
+    assert!(deleted);
+    assert_eq!(
+        transport.requests()[0].body,
+        Body::Json(json!({"message_id": 494, "chat_id": 147951145}))
+    );
+}
+
+#[test]
+fn execute_message_or_bool() {
+    let transport = Arc::new(MemoryTransport::new());
+    transport.reply("editMessageText", 200, r#"{"ok": true, "result": true}"#);
+    let client = memory_client(transport);
+
+    let edited = client
+        .execute(&EditMessageText {
+            text: String::from("edited"),
+            chat_id: None,
+            message_id: None,
+            inline_message_id: Some(String::from("AAAA")),
+            parse_mode: None,
+            entities: None,
+            link_preview_options: None,
+            reply_markup: None,
+        })
+        .unwrap();
+
+    assert_eq!(edited, MessageOrBool::Bool(true));
+}
+
+// The blocking client of `Client` can't be dropped inside a runtime, so the runtime is driven here.
+fn block_on<F: std::future::Future>(future: F) -> F::Output {
+    tokio::runtime::Runtime::new().unwrap().block_on(future)
+}
+
+#[test]
+fn execute_async() {
+    let response = fs::read_to_string("src/tests/responses/get_me_success.json").unwrap();
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("POST", format!("/bot{}/getMe", TOKEN).as_str())
+        .with_status(200)
+        .with_body(&response)
+        .create();
+    let client = Client::builder(TOKEN).url(&server.url()).build().unwrap();
+
+    let user = block_on(client.r#async.execute(&GetMe {})).unwrap();
+
+    assert!(matches!(user, User { is_bot: true, .. }));
+    mock.assert();
+}
+
+#[test]
+fn execute_async_error() {
+    let response = fs::read_to_string("src/tests/responses/get_me_error.json").unwrap();
+    let mut server = mockito::Server::new();
+    server
+        .mock("POST", format!("/bot{}/getMe", TOKEN).as_str())
+        .with_status(401)
+        .with_body(&response)
+        .create();
+    let client = Client::builder(TOKEN).url(&server.url()).build().unwrap();
+
+    let error = block_on(client.r#async.execute(&GetMe {})).unwrap_err();
+
+    assert_eq!(error.error_code(), Some(401));
+}
+
+#[test]
+fn call_raw_method() {
+    let transport = Arc::new(MemoryTransport::new());
+    transport.reply(
+        "getStarBalance",
+        200,
+        r#"{"ok": true, "result": {"amount": 42}}"#,
+    );
+    let client = memory_client(transport.clone());
+
+    let result = client
+        .call_raw("getStarBalance", json!({"currency": "XTR"}))
+        .unwrap();
+
+    assert_eq!(result, json!({"amount": 42}));
+    assert_eq!(transport.requests()[0].method, "getStarBalance");
+    assert_eq!(
+        transport.requests()[0].body,
+        Body::Json(json!({"currency": "XTR"}))
+    );
+}
+
+#[test]
+fn call_typed_method() {
+    #[derive(Serialize)]
+    struct Params {
+        chat_id: i64,
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Balance {
+        amount: i64,
+    }
+
+    let transport = Arc::new(MemoryTransport::new());
+    transport.reply(
+        "getStarBalance",
+        200,
+        r#"{"ok": true, "result": {"amount": 42}}"#,
+    );
+    let client = memory_client(transport);
+
+    let balance: Balance = client
+        .call("getStarBalance", &Params { chat_id: 1 })
+        .unwrap();
+
+    assert_eq!(balance, Balance { amount: 42 });
+}
+
+#[test]
+fn call_raw_error() {
+    let transport = Arc::new(MemoryTransport::new());
+    transport.reply(
+        "getStarBalance",
+        404,
+        r#"{"ok": false, "error_code": 404, "description": "Not Found"}"#,
+    );
+    let client = memory_client(transport);
+
+    let error = client.call_raw("getStarBalance", json!({})).unwrap_err();
+
+    assert!(matches!(error, Error::NotFound(_)));
+}
+
+#[test]
+fn call_multipart_method() {
+    let response = fs::read_to_string("src/tests/responses/send_message_success.json").unwrap();
+    let transport = Arc::new(MemoryTransport::new());
+    transport.reply("sendPaidMedia", 200, &response);
+    let client = memory_client(transport.clone());
+    let parts = vec![
+        Part::Text {
+            name: String::from("chat_id"),
+            value: String::from("147951145"),
+        },
+        Part::File {
+            name: String::from("photo"),
+            file_name: String::from("photo.jpg"),
+            content: b"jpeg".to_vec(),
+        },
+    ];
+
+    let message: Message = client
+        .sync
+        .call_multipart("sendPaidMedia", parts.clone())
+        .unwrap();
+
+    assert_eq!(message.message_id, MessageId::from(494));
+    assert_eq!(transport.requests()[0].body, Body::Multipart(parts));
+}
+
+#[test]
+fn call_raw_async() {
+    let response = fs::read_to_string("src/tests/responses/get_me_success.json").unwrap();
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("POST", format!("/bot{}/getMe", TOKEN).as_str())
+        .match_body(Matcher::Json(json!({"extra": true})))
+        .with_status(200)
+        .with_body(&response)
+        .create();
+    let client = Client::builder(TOKEN).url(&server.url()).build().unwrap();
+
+    let result = block_on(client.r#async.call_raw("getMe", json!({"extra": true}))).unwrap();
+
+    assert_eq!(result["is_bot"], json!(true));
+    mock.assert();
+}
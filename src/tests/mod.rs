@@ -1,9 +1,31 @@
 #[cfg(test)]
+mod auth;
+#[cfg(test)]
 mod client;
 #[cfg(test)]
 mod clients;
 #[cfg(test)]
 mod config;
 #[cfg(test)]
+mod deep_link;
+#[cfg(test)]
+mod entities;
+#[cfg(test)]
+mod enums;
+#[cfg(test)]
 mod errors;
+#[cfg(test)]
+mod format;
 mod helpers;
+#[cfg(test)]
+mod method;
+#[cfg(test)]
+mod passport;
+#[cfg(test)]
+mod registry;
+#[cfg(test)]
+mod split;
+#[cfg(test)]
+mod token;
+#[cfg(test)]
+mod validate;
@@ -0,0 +1,307 @@
+use crate::api::enums::passport_element_error::PassportElementError;
+use crate::api::params::set_passport_data_errors::SetPassportDataErrors;
+use crate::api::requests::sync::Requests;
+use crate::api::types::file_credentials::FileCredentials;
+use crate::api::types::passport_data::PassportData;
+use crate::api::types::passport_element_error_front_side::PassportElementErrorFrontSide;
+use crate::clients::transport::memory::MemoryTransport;
+use crate::clients::transport::Body;
+use crate::errors::Error;
+use crate::passport::{decrypt, decrypt_file, ElementData, PassportKey};
+use crate::tests::helpers::memory_client;
+use aes::cipher::block_padding::NoPadding;
+use aes::cipher::{BlockEncryptMut, KeyIvInit};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use rsa::pkcs1::DecodeRsaPrivateKey;
+use rsa::rand_core::{CryptoRng, Error as RandError, RngCore};
+use rsa::{Oaep, RsaPrivateKey, RsaPublicKey};
+use serde_json::json;
+use sha1::Sha1;
+use sha2::{Digest, Sha256, Sha512};
+use std::fs;
+use std::sync::Arc;
+
+/// Deterministic generator for the OAEP padding, good enough for a test.
+struct Counter(u8);
+
+impl RngCore for Counter {
+    fn next_u32(&mut self) -> u32 {
+        self.next_u64() as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut bytes = [0; 8];
+        self.fill_bytes(&mut bytes);
+        u64::from_le_bytes(bytes)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for byte in dest {
+            self.0 = self.0.wrapping_add(1);
+            *byte = self.0;
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), RandError> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl CryptoRng for Counter {}
+
+fn pem() -> String {
+    fs::read_to_string("src/tests/keys/passport.pem").unwrap()
+}
+
+/// Encrypts the data the way Telegram does, returns it with its hash.
+fn encrypt(data: &[u8], secret: &[u8]) -> (Vec<u8>, Vec<u8>) {
+    let padding = 32 + (16 - data.len() % 16) % 16;
+    let mut padded = vec![padding as u8; padding];
+    padded.extend_from_slice(data);
+    let hash = Sha256::digest(&padded).to_vec();
+
+    let secret_hash = Sha512::new()
+        .chain_update(secret)
+        .chain_update(&hash)
+        .finalize();
+    let length = padded.len();
+    cbc::Encryptor::<aes::Aes256>::new(secret_hash[..32].into(), secret_hash[32..48].into())
+        .encrypt_padded_mut::<NoPadding>(&mut padded, length)
+        .unwrap();
+
+    (padded, hash)
+}
+
+fn base64(data: &[u8]) -> String {
+    STANDARD.encode(data)
+}
+
+/// Passport with personal details, a driver license with a front side and a phone number.
+fn passport() -> (PassportData, Vec<u8>, FileCredentials) {
+    let details_secret = [1u8; 32];
+    let (details, details_hash) = encrypt(
+        json!({
+            "first_name": "Ann",
+            "last_name": "Lee",
+            "birth_date": "01.02.1990",
+            "gender": "female",
+            "country_code": "GB",
+            "residence_country_code": "GB"
+        })
+        .to_string()
+        .as_bytes(),
+        &details_secret,
+    );
+    let license_secret = [2u8; 32];
+    let (license, license_hash) = encrypt(
+        br#"{"document_no":"LEE123","expiry_date":"01.01.2030"}"#,
+        &license_secret,
+    );
+    let file_secret = [3u8; 32];
+    let (file, file_hash) = encrypt(b"\xFF\xD8jpeg", &file_secret);
+
+    let credentials = json!({
+        "secure_data": {
+            "personal_details": {"data": {"data_hash": base64(&details_hash), "secret": base64(&details_secret)}},
+            "driver_license": {
+                "data": {"data_hash": base64(&license_hash), "secret": base64(&license_secret)},
+                "front_side": {"file_hash": base64(&file_hash), "secret": base64(&file_secret)}
+            }
+        },
+        "nonce": "request-42"
+    });
+    let secret = [4u8; 32];
+    let (credentials, credentials_hash) = encrypt(credentials.to_string().as_bytes(), &secret);
+    let public = RsaPublicKey::from(RsaPrivateKey::from_pkcs1_pem(&pem()).unwrap());
+    let encrypted_secret = public
+        .encrypt(&mut Counter(0), Oaep::new::<Sha1>(), &secret)
+        .unwrap();
+
+    let file_credentials = FileCredentials {
+        file_hash: base64(&file_hash),
+        secret: base64(&file_secret),
+    };
+    let passport = serde_json::from_value(json!({
+        "data": [
+            {"type": "personal_details", "data": base64(&details), "hash": "x"},
+            {
+                "type": "driver_license",
+                "data": base64(&license),
+                "front_side": {"file_id": "f", "file_unique_id": "u", "file_size": 6, "file_date": 0},
+                "hash": "y"
+            },
+            {"type": "phone_number", "phone_number": "441234567890", "hash": "z"}
+        ],
+        "credentials": {
+            "data": base64(&credentials),
+            "hash": base64(&credentials_hash),
+            "secret": base64(&encrypted_secret)
+        }
+    }))
+    .unwrap();
+
+    (passport, file, file_credentials)
+}
+
+fn invalid_field(error: Error) -> String {
+    match error {
+        Error::Passport { field, .. } => field,
+        error => panic!("unexpected error {:?}", error),
+    }
+}
+
+#[test]
+fn decrypt_passport() {
+    let (passport, file, file_credentials) = passport();
+    let key = PassportKey::from_pem(&pem()).unwrap();
+
+    let decrypted = decrypt(&passport, &key).unwrap();
+
+    assert_eq!(decrypted.nonce, "request-42");
+    assert!(decrypted.check_nonce("request-42").is_ok());
+    assert_eq!(
+        invalid_field(decrypted.check_nonce("request-43").unwrap_err()),
+        "nonce"
+    );
+
+    let details = decrypted.personal_details().unwrap();
+    assert_eq!(details.first_name, "Ann");
+    assert_eq!(details.birth_date, "01.02.1990");
+    assert_eq!(details.middle_name, None);
+
+    let license = decrypted.element("driver_license").unwrap();
+    match &license.data {
+        Some(ElementData::IdDocument(document)) => {
+            assert_eq!(document.document_no, "LEE123");
+            assert_eq!(document.expiry_date.as_deref(), Some("01.01.2030"));
+        }
+        data => panic!("unexpected data {:?}", data),
+    }
+    let front_side = license.credentials.as_ref().unwrap().front_side.as_ref();
+    assert_eq!(front_side, Some(&file_credentials));
+    assert_eq!(
+        decrypt_file(&file, front_side.unwrap()).unwrap(),
+        b"\xFF\xD8jpeg"
+    );
+
+    let phone = decrypted.element("phone_number").unwrap();
+    assert_eq!(phone.phone_number.as_deref(), Some("441234567890"));
+    assert_eq!(phone.data, None);
+}
+
+#[test]
+fn decrypt_passport_tampered() {
+    let (mut passport, mut file, file_credentials) = passport();
+    let key = PassportKey::from_pem(&pem()).unwrap();
+
+    file[20] ^= 1;
+    assert_eq!(
+        invalid_field(decrypt_file(&file, &file_credentials).unwrap_err()),
+        "data"
+    );
+
+    let mut data = STANDARD
+        .decode(passport.data[0].data.as_ref().unwrap())
+        .unwrap();
+    data[0] ^= 1;
+    passport.data[0].data = Some(base64(&data));
+    assert_eq!(invalid_field(decrypt(&passport, &key).unwrap_err()), "data");
+
+    let mut secret = STANDARD.decode(&passport.credentials.secret).unwrap();
+    secret[0] ^= 1;
+    passport.credentials.secret = base64(&secret);
+    assert_eq!(
+        invalid_field(decrypt(&passport, &key).unwrap_err()),
+        "secret"
+    );
+}
+
+#[test]
+fn passport_key() {
+    assert!(matches!(
+        PassportKey::from_pem("not a key"),
+        Err(Error::Config { .. })
+    ));
+}
+
+#[test]
+fn passport_data_errors() {
+    let (passport, _, file_credentials) = passport();
+    let decrypted = decrypt(&passport, &PassportKey::from_pem(&pem()).unwrap()).unwrap();
+    let details = decrypted.element("personal_details").unwrap();
+    let license = decrypted.element("driver_license").unwrap();
+    let phone = decrypted.element("phone_number").unwrap();
+    let details_hash = &details
+        .credentials
+        .as_ref()
+        .unwrap()
+        .data
+        .as_ref()
+        .unwrap()
+        .data_hash;
+
+    assert_eq!(
+        invalid_field(license.selfie_error("Blurry").unwrap_err()),
+        "selfie"
+    );
+    let params = SetPassportDataErrors {
+        user_id: 42,
+        errors: vec![
+            details
+                .data_field_error("birth_date", "Doesn't match the document")
+                .unwrap(),
+            license.front_side_error("Blurry").unwrap(),
+            phone.unspecified_error("Unreachable"),
+        ],
+    };
+
+    let transport = Arc::new(MemoryTransport::new());
+    transport.reply("setPassportDataErrors", 200, r#"{"ok":true,"result":true}"#);
+    let client = memory_client(transport.clone());
+    assert!(client.sync.set_passport_data_errors(&params).unwrap());
+
+    let body = match &transport.requests()[0].body {
+        Body::Json(body) => body.clone(),
+        body => panic!("unexpected body {:?}", body),
+    };
+    assert_eq!(
+        body,
+        json!({
+            "user_id": 42,
+            "errors": [
+                {"type": "personal_details", "source": "data", "field_name": "birth_date", "data_hash": details_hash, "message": "Doesn't match the document"},
+                {"type": "driver_license", "source": "front_side", "file_hash": file_credentials.file_hash, "message": "Blurry"},
+                {"type": "phone_number", "source": "unspecified", "element_hash": "z", "message": "Unreachable"}
+            ]
+        })
+    );
+}
+
+#[test]
+fn passport_data_errors_source() {
+    let error =
+        PassportElementError::PassportElementErrorFrontSide(PassportElementErrorFrontSide {
+            kind: String::from("passport"),
+            file_hash: String::from("aGFzaA=="),
+            source: String::from("selfie"),
+            message: String::from("Blurry"),
+        });
+    let params = SetPassportDataErrors {
+        user_id: 42,
+        errors: vec![error],
+    };
+
+    let transport = Arc::new(MemoryTransport::new());
+    let client = memory_client(transport.clone());
+
+    match client.sync.set_passport_data_errors(&params).unwrap_err() {
+        Error::Validation { field, reason } => {
+            assert_eq!(field, "errors[0].source");
+            assert_eq!(reason, "must be front_side, got selfie");
+        }
+        error => panic!("unexpected error {:?}", error),
+    }
+    assert!(transport.requests().is_empty());
+}
@@ -0,0 +1,147 @@
+use crate::api::enums::chat_uid::ChatUId;
+use crate::api::params::send_message::SendMessage;
+use crate::api::requests::sync::Requests;
+use crate::clients::limiter::{Clock, Limiter, Limits, VirtualClock};
+use crate::config::Config;
+use crate::errors::Error;
+use crate::registry::BotRegistry;
+use std::fs;
+use std::sync::Arc;
+use std::time::Duration;
+
+const FIRST: &str = "111111:AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA";
+const SECOND: &str = "222222:BBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBB";
+const FIRST_SECRET: &str = "first_secret";
+const SECOND_SECRET: &str = "second_secret";
+
+fn registry(url: &str) -> BotRegistry {
+    let config = Config {
+        url: url.to_string(),
+        ..Default::default()
+    };
+    BotRegistry::new(config).unwrap()
+}
+
+#[test]
+fn register_bots_by_id() {
+    let registry = registry("http://localhost:8081");
+
+    registry.register(FIRST, FIRST_SECRET).unwrap();
+    registry.register(SECOND, SECOND_SECRET).unwrap();
+    registry.register(FIRST, FIRST_SECRET).unwrap();
+
+    assert_eq!(registry.ids(), vec![111111, 222222]);
+    assert_eq!(registry.get(222222).unwrap().config.token.expose(), SECOND);
+    assert!(registry.get(333333).is_none());
+
+    registry.remove(111111);
+    assert_eq!(registry.len(), 1);
+}
+
+#[test]
+fn register_invalid_token() {
+    let registry = registry("http://localhost:8081");
+
+    assert!(matches!(
+        registry.register("111111:short", FIRST_SECRET),
+        Err(Error::Config { field, .. }) if field == "token"
+    ));
+    assert!(matches!(
+        registry.register(FIRST, "not a secret!"),
+        Err(Error::Config { field, .. }) if field == "secret_token"
+    ));
+    assert!(registry.is_empty());
+}
+
+#[test]
+fn requests_use_the_bot_token() {
+    let response = fs::read_to_string("src/tests/responses/get_me_success.json").unwrap();
+    let mut server = mockito::Server::new();
+    let first = server
+        .mock("POST", format!("/bot{}/getMe", FIRST).as_str())
+        .with_body(&response)
+        .create();
+    let second = server
+        .mock("POST", format!("/bot{}/getMe", SECOND).as_str())
+        .with_body(&response)
+        .expect(2)
+        .create();
+    let limiter = Arc::new(Limiter::new(Limits::default()));
+    let registry = registry(&server.url()).limiter(limiter.clone());
+
+    registry.register(FIRST, FIRST_SECRET).unwrap();
+    registry.register(SECOND, SECOND_SECRET).unwrap();
+
+    registry.get(111111).unwrap().sync.get_me().unwrap();
+    registry.get(222222).unwrap().sync.get_me().unwrap();
+    registry.get(222222).unwrap().sync.get_me().unwrap();
+
+    let shared = registry.get(111111).unwrap();
+    assert!(Arc::ptr_eq(shared.sync.limiter().unwrap(), &limiter));
+    first.assert();
+    second.assert();
+}
+
+#[test]
+fn shared_limiter_keeps_bots_apart() {
+    let response = fs::read_to_string("src/tests/responses/send_message_success.json").unwrap();
+    let mut server = mockito::Server::new();
+    let first = server
+        .mock("POST", format!("/bot{}/sendMessage", FIRST).as_str())
+        .with_body(&response)
+        .create();
+    let second = server
+        .mock("POST", format!("/bot{}/sendMessage", SECOND).as_str())
+        .with_body(&response)
+        .create();
+    let clock = Arc::new(VirtualClock::new());
+    let limiter = Arc::new(Limiter::with_clock(Limits::default(), clock.clone()));
+    let registry = registry(&server.url()).limiter(limiter.clone());
+    let params = SendMessage {
+        chat_id: ChatUId::from(147951145),
+        text: String::from("Hello World"),
+        ..Default::default()
+    };
+
+    registry
+        .register(FIRST, FIRST_SECRET)
+        .unwrap()
+        .sync
+        .send_message(&params)
+        .unwrap();
+    registry
+        .register(SECOND, SECOND_SECRET)
+        .unwrap()
+        .sync
+        .send_message(&params)
+        .unwrap();
+
+    // The same private chat allows one message per second, but per bot.
+    assert_eq!(clock.now(), Duration::ZERO);
+    first.assert();
+    second.assert();
+}
+
+#[test]
+fn route_webhooks() {
+    let registry = registry("http://localhost:8081");
+    registry.register(FIRST, FIRST_SECRET).unwrap();
+    registry.register(SECOND, SECOND_SECRET).unwrap();
+
+    let route = |path: &str, secret: Option<&str>| {
+        registry
+            .route(path, secret)
+            .and_then(|client| client.config.token.bot_id())
+    };
+
+    assert_eq!(route("/webhook/111111", Some(FIRST_SECRET)), Some(111111));
+    assert_eq!(route("/webhook/111111/", Some(FIRST_SECRET)), Some(111111));
+    assert_eq!(route("/webhook/222222", Some(SECOND_SECRET)), Some(222222));
+    assert_eq!(route("/webhook", Some(SECOND_SECRET)), Some(222222));
+    assert_eq!(route("/webhook/111111", None), None);
+    assert_eq!(route("/webhook/111111", Some(SECOND_SECRET)), None);
+    assert_eq!(route("/webhook/222222", Some("wrong")), None);
+    assert_eq!(route("/webhook/222222", Some("second_secre")), None);
+    assert_eq!(route("/webhook/333333", Some("wrong")), None);
+    assert_eq!(route("/webhook", None), None);
+}
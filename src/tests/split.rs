@@ -0,0 +1,143 @@
+use crate::api::enums::message_entity_kind::MessageEntityKind;
+use crate::api::types::message_entity::MessageEntity;
+use crate::format::split::{
+    split, split_caption, split_html, split_html_caption, CAPTION_LIMIT, TEXT_LIMIT,
+};
+use crate::format::utf16;
+
+fn bold(offset: i64, length: i64) -> MessageEntity {
+    MessageEntity::new(MessageEntityKind::Bold, offset, length)
+}
+
+#[test]
+fn short_text_kept() {
+    let parts = split("  Hello  ", &[bold(2, 5)], 4096);
+
+    assert_eq!(parts, vec![(String::from("Hello"), vec![bold(0, 5)])]);
+    assert!(split("", &[], 4096).is_empty());
+}
+
+#[test]
+fn split_on_paragraphs() {
+    let text = "First paragraph.\n\nSecond one, a bit longer.\nSame paragraph.";
+    let parts = split(text, &[], 45);
+
+    assert_eq!(
+        parts,
+        vec![
+            (String::from("First paragraph."), vec![]),
+            (
+                String::from("Second one, a bit longer.\nSame paragraph."),
+                vec![]
+            ),
+        ]
+    );
+}
+
+#[test]
+fn split_on_sentences_and_words() {
+    let parts = split("One. Two three four. Five", &[], 22);
+    let texts: Vec<&str> = parts.iter().map(|(text, _)| text.as_str()).collect();
+    assert_eq!(texts, vec!["One. Two three four.", "Five"]);
+
+    let parts = split("alpha beta gamma", &[], 12);
+    let texts: Vec<&str> = parts.iter().map(|(text, _)| text.as_str()).collect();
+    assert_eq!(texts, vec!["alpha beta", "gamma"]);
+
+    let parts = split("abcdefgh", &[], 3);
+    let texts: Vec<&str> = parts.iter().map(|(text, _)| text.as_str()).collect();
+    assert_eq!(texts, vec!["abc", "def", "gh"]);
+}
+
+#[test]
+fn split_outside_entities() {
+    // The blank line at 10 is inside the bold entity, the word boundary at 6 is not.
+    let text = "Intro text\n\nbold part";
+    let parts = split(text, &[bold(6, 15)], 16);
+
+    assert_eq!(
+        parts,
+        vec![
+            (String::from("Intro"), vec![]),
+            (String::from("text\n\nbold part"), vec![bold(0, 15)]),
+        ]
+    );
+}
+
+#[test]
+fn split_long_entity() {
+    let text = "👍 aaaa bbbb cccc";
+    let parts = split(text, &[bold(0, utf16::len(text))], 8);
+
+    assert_eq!(
+        parts,
+        vec![
+            (String::from("👍 aaaa"), vec![bold(0, 7)]),
+            (String::from("bbbb"), vec![bold(0, 4)]),
+            (String::from("cccc"), vec![bold(0, 4)]),
+        ]
+    );
+}
+
+#[test]
+fn split_utf16_limit() {
+    let text = "👍👍👍";
+    let parts = split(text, &[], 4);
+    let texts: Vec<&str> = parts.iter().map(|(text, _)| text.as_str()).collect();
+
+    assert_eq!(texts, vec!["👍👍", "👍"]);
+}
+
+#[test]
+fn split_html_outside_tags() {
+    let html = "<b>Hello</b> world &amp; all.\n\n<i>Second</i> paragraph";
+    let parts = split_html(html, 20);
+
+    assert_eq!(
+        parts,
+        vec!["<b>Hello</b> world &amp; all.", "<i>Second</i> paragraph"]
+    );
+}
+
+#[test]
+fn split_html_reopens_tags() {
+    let html = "<pre><code class=\"language-rust\">let a = 1;\nlet b = 2;</code></pre>";
+    let parts = split_html(html, 12);
+
+    assert_eq!(
+        parts,
+        vec![
+            "<pre><code class=\"language-rust\">let a = 1;\n</code></pre>",
+            "<pre><code class=\"language-rust\">let b = 2;</code></pre>",
+        ]
+    );
+}
+
+#[test]
+fn split_html_never_cuts_references() {
+    let parts = split_html("&lt;&lt;&lt;&lt;", 3);
+
+    assert_eq!(parts, vec!["&lt;&lt;&lt;", "&lt;"]);
+}
+
+#[test]
+fn split_caption_then_messages() {
+    let text = format!("{}\n\n{}", "a".repeat(1000), "b".repeat(5000));
+    let parts = split_caption(&text, &[bold(0, 1000)]);
+
+    assert_eq!(parts.len(), 3);
+    assert_eq!(parts[0], ("a".repeat(1000), vec![bold(0, 1000)]));
+    assert_eq!(utf16::len(&parts[1].0), TEXT_LIMIT);
+    assert_eq!(parts[2].0, "b".repeat(5000 - TEXT_LIMIT as usize));
+
+    let html = format!("<b>{}</b>", "c".repeat(CAPTION_LIMIT as usize + 1));
+    let parts = split_html_caption(&html);
+
+    assert_eq!(
+        parts,
+        vec![
+            format!("<b>{}</b>", "c".repeat(CAPTION_LIMIT as usize)),
+            String::from("<b>c</b>"),
+        ]
+    );
+}
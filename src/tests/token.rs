@@ -0,0 +1,74 @@
+use crate::api::requests::sync::Requests;
+use crate::config::token::BotToken;
+use crate::config::Config;
+use crate::errors::Error;
+use crate::Client;
+
+const TOKEN: &str = "123456:ABCDEFGHIJKLMNOPQRSTUVWXYZ_abcdefgh";
+
+#[test]
+fn new_with_valid_token() {
+    let token = BotToken::new(TOKEN).unwrap();
+
+    assert_eq!(token.expose(), TOKEN);
+    assert_eq!(token.bot_id(), Some(123456));
+}
+
+#[test]
+fn new_with_invalid_token() {
+    for token in [
+        "",
+        "123456",
+        "123456:short",
+        ":ABCDEFGHIJKLMNOPQRSTUVWXYZ_abcdefgh",
+        "12a456:ABCDEFGHIJKLMNOPQRSTUVWXYZ_abcdefgh",
+        "123456:ABCDEFGHIJKLMNOPQRSTUVWXYZ_abcdefg!",
+    ] {
+        match BotToken::new(token) {
+            Err(Error::Config { field, .. }) => assert_eq!(field, "token"),
+            result => panic!("unexpected {:?} for {}", result, token),
+        }
+    }
+}
+
+#[test]
+fn redacted_in_debug_and_display() {
+    let token = BotToken::from(TOKEN);
+
+    assert_eq!(format!("{}", token), "123456:***");
+    assert_eq!(format!("{:?}", token), "123456:***");
+    assert_eq!(format!("{}", BotToken::from("secret")), "***");
+}
+
+#[test]
+fn redacted_in_clients_debug() {
+    let client = Client::new(TOKEN).unwrap();
+    let debug = format!("{:?}", client);
+
+    assert!(!debug.contains(TOKEN));
+    assert!(debug.contains("https://api.telegram.org/bot123456:***/"));
+}
+
+#[test]
+fn redacted_in_request_errors() {
+    let config = Config {
+        token: TOKEN.into(),
+        url: String::from("http://127.0.0.1:1"),
+        ..Default::default()
+    };
+    let client = Client::from_config(config).unwrap();
+
+    let error = client.sync.get_me().unwrap_err();
+
+    assert!(matches!(error, Error::Request(_)));
+    assert!(!error.to_string().contains(TOKEN));
+    assert!(!format!("{:?}", error).contains(TOKEN));
+    assert!(error.to_string().contains("123456:***"));
+}
+
+#[test]
+fn build_with_invalid_token() {
+    let result = Client::builder("123456:short").build();
+
+    assert!(matches!(result, Err(Error::Config { field, .. }) if field == "token"));
+}
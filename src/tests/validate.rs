@@ -0,0 +1,273 @@
+use crate::api::enums::chat_uid::ChatUId;
+use crate::api::enums::file_input::FileInput;
+use crate::api::enums::media_input::MediaInput;
+use crate::api::enums::parse_mode::ParseMode;
+use crate::api::params::send_media_group::SendMediaGroup;
+use crate::api::params::send_message::SendMessage;
+use crate::api::params::send_photo::SendPhoto;
+use crate::api::params::send_poll::SendPoll;
+use crate::api::params::set_my_commands::SetMyCommands;
+use crate::api::types::bot_command::BotCommand;
+use crate::api::types::input_media_photo::InputMediaPhoto;
+use crate::api::validate::Validate;
+use crate::clients::transport::memory::MemoryTransport;
+use crate::clients::transport::Part;
+use crate::errors::Error;
+use crate::tests::helpers::memory_client;
+use serde_json::json;
+use std::sync::Arc;
+
+fn invalid(result: Result<(), Error>) -> (String, String) {
+    match result {
+        Err(Error::Validation { field, reason }) => (field, reason),
+        result => panic!("unexpected result {:?}", result),
+    }
+}
+
+fn poll(options: &[&str]) -> SendPoll {
+    SendPoll {
+        kind: None,
+        chat_id: ChatUId::from(1),
+        question: String::from("Lunch?"),
+        options: options.iter().map(|option| option.to_string()).collect(),
+        allows_multiple_answers: None,
+        message_thread_id: None,
+        is_anonymous: None,
+        correct_option_id: None,
+        explanation: None,
+        explanation_parse_mode: None,
+        explanation_entities: None,
+        open_period: None,
+        close_date: None,
+        is_closed: None,
+        disable_notification: None,
+        protect_content: None,
+        reply_parameters: None,
+        reply_markup: None,
+    }
+}
+
+fn commands(commands: &[(&str, &str)]) -> SetMyCommands {
+    SetMyCommands {
+        commands: commands
+            .iter()
+            .map(|(command, description)| BotCommand {
+                command: command.to_string(),
+                description: description.to_string(),
+            })
+            .collect(),
+        scope: None,
+        language_code: None,
+    }
+}
+
+#[test]
+fn valid_params() {
+    let params = SendMessage {
+        text: String::from("hello"),
+        ..Default::default()
+    };
+
+    assert!(params.validate().is_ok());
+    assert!(poll(&["Pizza", "Salad"]).validate().is_ok());
+    assert!(commands(&[("start", "Start the bot"), ("help_2", "Help")])
+        .validate()
+        .is_ok());
+}
+
+#[test]
+fn message_text_length() {
+    let params = SendMessage {
+        text: "a".repeat(4097),
+        ..Default::default()
+    };
+    let (field, reason) = invalid(params.validate());
+
+    assert_eq!(field, "text");
+    assert_eq!(reason, "must be 1 to 4096 characters long, got 4097");
+
+    // The markup of a parse mode doesn't count towards the limit.
+    let params = SendMessage {
+        text: "a".repeat(4097),
+        parse_mode: Some(ParseMode::Html),
+        ..Default::default()
+    };
+    assert!(params.validate().is_ok());
+}
+
+#[test]
+fn callback_data_length() {
+    let params = SendMessage {
+        text: String::from("hello"),
+        reply_markup: Some(
+            serde_json::from_value(json!({"inline_keyboard": [
+                [{"text": "Ok", "callback_data": "ok"}],
+                [{"text": "Yes", "callback_data": "yes"}, {"text": "No", "callback_data": "й".repeat(33)}]
+            ]}))
+            .unwrap(),
+        ),
+        ..Default::default()
+    };
+    let (field, reason) = invalid(params.validate());
+
+    assert_eq!(field, "reply_markup.inline_keyboard[1][1].callback_data");
+    assert_eq!(reason, "must be 1 to 64 bytes long, got 66");
+}
+
+#[test]
+fn caption_length() {
+    let params = SendPhoto {
+        chat_id: ChatUId::from(1),
+        photo: FileInput::String(String::from("file_id")),
+        message_thread_id: None,
+        caption: Some("👍".repeat(513)),
+        parse_mode: None,
+        caption_entities: None,
+        has_spoiler: None,
+        disable_notification: None,
+        protect_content: None,
+        reply_parameters: None,
+        reply_markup: None,
+    };
+    let (field, reason) = invalid(params.validate());
+
+    assert_eq!(field, "caption");
+    assert_eq!(reason, "must be 0 to 1024 characters long, got 1026");
+}
+
+#[test]
+fn poll_options() {
+    let (field, reason) = invalid(poll(&["Pizza"]).validate());
+    assert_eq!(field, "options");
+    assert_eq!(reason, "must have 2 to 10 items, got 1");
+
+    let (field, _) = invalid(poll(&["Pizza", ""]).validate());
+    assert_eq!(field, "options[1]");
+}
+
+#[test]
+fn command_names() {
+    let (field, _) = invalid(commands(&[("start", "Start"), ("Help", "Help")]).validate());
+    assert_eq!(field, "commands[1].command");
+
+    let (field, reason) = invalid(commands(&[(&"a".repeat(33), "Long")]).validate());
+    assert_eq!(field, "commands[0].command");
+    assert_eq!(reason, "must be 1 to 32 characters long, got 33");
+
+    let (field, _) = invalid(commands(&[("start", "")]).validate());
+    assert_eq!(field, "commands[0].description");
+}
+
+#[test]
+fn execute_validates_before_sending() {
+    let transport = Arc::new(MemoryTransport::new());
+    let client = memory_client(transport.clone());
+
+    let error = client.execute(&poll(&["Pizza"])).unwrap_err();
+
+    assert!(matches!(error, Error::Validation { .. }));
+    assert_eq!(
+        error.to_string(),
+        "Validation Error: options must have 2 to 10 items, got 1"
+    );
+    assert!(transport.requests().is_empty());
+}
+
+#[test]
+fn media_group_captions() {
+    let photo = |caption: &str| {
+        MediaInput::InputMediaPhoto(InputMediaPhoto {
+            kind: String::from("photo"),
+            media: String::from("file_id"),
+            caption: Some(caption.to_string()),
+            parse_mode: None,
+            caption_entities: None,
+            has_spoiler: None,
+        })
+    };
+    let params = SendMediaGroup {
+        chat_id: ChatUId::from(1),
+        media: vec![photo("First"), photo(&"a".repeat(1025))],
+        message_thread_id: None,
+        disable_notification: None,
+        protect_content: None,
+        reply_parameters: None,
+    };
+    let (field, reason) = invalid(params.validate());
+
+    assert_eq!(field, "media[1].caption");
+    assert_eq!(reason, "must be 0 to 1024 characters long, got 1025");
+}
+
+#[test]
+fn untyped_calls_validated() {
+    let transport = Arc::new(MemoryTransport::new());
+    let client = memory_client(transport.clone());
+    let field = |error: Error| match error {
+        Error::Validation { field, .. } => field,
+        error => panic!("unexpected error {:?}", error),
+    };
+
+    let error = client
+        .sync
+        .call_raw("sendMessage", json!({"chat_id": 1, "text": ""}))
+        .unwrap_err();
+    assert_eq!(field(error), "text");
+
+    let error = client
+        .sync
+        .call::<_, serde_json::Value>(
+            "sendMessage",
+            &json!({
+                "chat_id": 1,
+                "text": "hello",
+                "reply_markup": {"inline_keyboard": [[{"text": "Ok", "callback_data": ""}]]}
+            }),
+        )
+        .unwrap_err();
+    assert_eq!(
+        field(error),
+        "reply_markup.inline_keyboard[0][0].callback_data"
+    );
+
+    let media = json!([
+        {"type": "photo", "media": "file_id"},
+        {"type": "photo", "media": "file_id", "caption": "a".repeat(1025)}
+    ]);
+    let error = client
+        .sync
+        .call_multipart::<serde_json::Value>(
+            "sendMediaGroup",
+            vec![
+                Part::Text {
+                    name: String::from("chat_id"),
+                    value: String::from("1"),
+                },
+                Part::Text {
+                    name: String::from("media"),
+                    value: media.to_string(),
+                },
+            ],
+        )
+        .unwrap_err();
+    assert_eq!(field(error), "media[1].caption");
+
+    assert!(transport.requests().is_empty());
+}
+
+#[test]
+fn untyped_markup_not_counted() {
+    let transport = Arc::new(MemoryTransport::new());
+    transport.reply("sendPhoto", 200, r#"{"ok":true,"result":true}"#);
+    let client = memory_client(transport.clone());
+
+    let body = json!({
+        "chat_id": 1,
+        "photo": "file_id",
+        "caption": format!("<b>{}</b>", "a".repeat(1020)),
+        "parse_mode": "HTML"
+    });
+
+    assert!(client.sync.call_raw("sendPhoto", body).is_ok());
+    assert_eq!(transport.requests().len(), 1);
+}